@@ -0,0 +1,170 @@
+/*
+ * Hachimi plugin API
+ *
+ * Generated from plugin_sdk/src/api_table.rs by `hachimi-gen-header`. Do not edit.
+ *
+ * Plugins export `hachimi_init_v3` and receive a `HachimiGetApiFn` which resolves
+ * each function below by name (the part between `hachimi_` and `_fn`), e.g.
+ *
+ *     hachimi_gui_ui_label_fn ui_label = (hachimi_gui_ui_label_fn)get_api("gui_ui_label");
+ *
+ * Functions that are unavailable in the running Hachimi version resolve to NULL.
+ *
+ * Plugins may also export `hachimi_shutdown`, which is called before the plugin is unloaded
+ * for a reload. Hooks, menu items, windows and callbacks it registered are removed by Hachimi.
+ */
+
+#ifndef HACHIMI_PLUGIN_H
+#define HACHIMI_PLUGIN_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define HACHIMI_PLUGIN_API_VERSION 6
+
+typedef enum HachimiInitResult {
+    HACHIMI_INIT_RESULT_ERROR = 0,
+    HACHIMI_INIT_RESULT_OK = 1
+} HachimiInitResult;
+
+typedef enum HachimiLogLevel {
+    HACHIMI_LOG_ERROR = 1,
+    HACHIMI_LOG_WARN = 2,
+    HACHIMI_LOG_INFO = 3,
+    HACHIMI_LOG_DEBUG = 4,
+    HACHIMI_LOG_TRACE = 5
+} HachimiLogLevel;
+
+typedef struct Hachimi Hachimi;
+typedef struct Interceptor Interceptor;
+typedef struct Il2CppImage Il2CppImage;
+typedef struct Il2CppClass Il2CppClass;
+typedef struct Il2CppObject Il2CppObject;
+typedef struct Il2CppString Il2CppString;
+typedef struct Il2CppArray Il2CppArray;
+typedef struct Il2CppThread Il2CppThread;
+typedef struct MethodInfo MethodInfo;
+typedef struct FieldInfo FieldInfo;
+
+typedef void* (*HachimiGetApiFn)(const char* name);
+typedef HachimiInitResult (*HachimiInitV3Fn)(HachimiGetApiFn get_api, int32_t version);
+typedef void (*HachimiShutdownFn)(void);
+
+typedef void (*HachimiGuiMenuCallback)(void* userdata);
+typedef void (*HachimiGuiUiCallback)(void* ui, void* userdata);
+typedef void (*HachimiGuiTableCellCallback)(void* ui, size_t row, size_t column, void* userdata);
+typedef void (*HachimiGameInitializedCallback)(void* userdata);
+typedef void (*HachimiPresentCallback)(void* swapchain, void* userdata);
+typedef void (*HachimiConfigChangedCallback)(void* userdata);
+typedef bool (*HachimiIpcCommandCallback)(const char* args_json, void* response, void* userdata);
+
+/* Since API version 2 */
+typedef const Hachimi* (*hachimi_hachimi_instance_fn)(void);
+typedef const Interceptor* (*hachimi_hachimi_get_interceptor_fn)(const Hachimi* self);
+typedef void* (*hachimi_interceptor_hook_fn)(const Interceptor* self, void* orig_addr, void* hook_addr);
+typedef void* (*hachimi_interceptor_hook_vtable_fn)(const Interceptor* self, void** vtable, size_t vtable_index, void* hook_addr);
+typedef void* (*hachimi_interceptor_get_trampoline_addr_fn)(const Interceptor* self, void* hook_addr);
+typedef void* (*hachimi_interceptor_unhook_fn)(const Interceptor* self, void* hook_addr);
+typedef void* (*hachimi_il2cpp_resolve_symbol_fn)(const char* name);
+typedef const Il2CppImage* (*hachimi_il2cpp_get_assembly_image_fn)(const char* assembly_name);
+typedef Il2CppClass* (*hachimi_il2cpp_get_class_fn)(const Il2CppImage* image, const char* name_space, const char* class_name);
+typedef const MethodInfo* (*hachimi_il2cpp_get_method_fn)(Il2CppClass* klass, const char* name, int32_t args_count);
+typedef const MethodInfo* (*hachimi_il2cpp_get_method_overload_fn)(Il2CppClass* klass, const char* name, const uint32_t* params, size_t param_count);
+typedef void* (*hachimi_il2cpp_get_method_addr_fn)(Il2CppClass* klass, const char* name, int32_t args_count);
+typedef void* (*hachimi_il2cpp_get_method_overload_addr_fn)(Il2CppClass* klass, const char* name, const uint32_t* params, size_t param_count);
+typedef const MethodInfo* (*hachimi_il2cpp_get_method_cached_fn)(Il2CppClass* klass, const char* name, int32_t args_count);
+typedef void* (*hachimi_il2cpp_get_method_addr_cached_fn)(Il2CppClass* klass, const char* name, int32_t args_count);
+typedef Il2CppClass* (*hachimi_il2cpp_find_nested_class_fn)(Il2CppClass* klass, const char* name);
+typedef void* (*hachimi_il2cpp_resolve_icall_fn)(const char* name);
+typedef const MethodInfo* (*hachimi_il2cpp_class_get_methods_fn)(Il2CppClass* klass, void** iter);
+typedef FieldInfo* (*hachimi_il2cpp_get_field_from_name_fn)(Il2CppClass* klass, const char* name);
+typedef void (*hachimi_il2cpp_get_field_value_fn)(Il2CppObject* obj, FieldInfo* field, void* out_value);
+typedef void (*hachimi_il2cpp_set_field_value_fn)(Il2CppObject* obj, FieldInfo* field, const void* value);
+typedef void (*hachimi_il2cpp_get_static_field_value_fn)(FieldInfo* field, void* out_value);
+typedef void (*hachimi_il2cpp_set_static_field_value_fn)(FieldInfo* field, const void* value);
+typedef Il2CppObject* (*hachimi_il2cpp_object_new_fn)(const Il2CppClass* klass);
+typedef void* (*hachimi_il2cpp_unbox_fn)(Il2CppObject* obj);
+typedef Il2CppThread* (*hachimi_il2cpp_get_main_thread_fn)(void);
+typedef Il2CppThread** (*hachimi_il2cpp_get_attached_threads_fn)(size_t* out_size);
+typedef void (*hachimi_il2cpp_schedule_on_thread_fn)(Il2CppThread* thread, void (*callback)(void));
+typedef Il2CppArray* (*hachimi_il2cpp_create_array_fn)(Il2CppClass* element_type, size_t length);
+typedef Il2CppObject* (*hachimi_il2cpp_get_singleton_like_instance_fn)(Il2CppClass* klass);
+typedef void (*hachimi_log_fn)(int32_t level, const char* target, const char* message);
+typedef bool (*hachimi_gui_register_menu_item_fn)(const char* label, HachimiGuiMenuCallback callback, void* userdata);
+typedef bool (*hachimi_gui_register_menu_section_fn)(HachimiGuiUiCallback callback, void* userdata);
+typedef bool (*hachimi_gui_show_notification_fn)(const char* message);
+typedef bool (*hachimi_gui_ui_heading_fn)(void* ui, const char* text);
+typedef bool (*hachimi_gui_ui_label_fn)(void* ui, const char* text);
+typedef bool (*hachimi_gui_ui_small_fn)(void* ui, const char* text);
+typedef bool (*hachimi_gui_ui_separator_fn)(void* ui);
+typedef bool (*hachimi_gui_ui_button_fn)(void* ui, const char* text);
+typedef bool (*hachimi_gui_ui_small_button_fn)(void* ui, const char* text);
+typedef bool (*hachimi_gui_ui_checkbox_fn)(void* ui, const char* text, bool* value);
+typedef bool (*hachimi_gui_ui_text_edit_singleline_fn)(void* ui, char* buffer, size_t buffer_len);
+typedef bool (*hachimi_gui_ui_horizontal_fn)(void* ui, HachimiGuiUiCallback callback, void* userdata);
+typedef bool (*hachimi_gui_ui_grid_fn)(void* ui, const char* id, size_t columns, float spacing_x, float spacing_y, HachimiGuiUiCallback callback, void* userdata);
+typedef bool (*hachimi_gui_ui_end_row_fn)(void* ui);
+typedef bool (*hachimi_gui_ui_colored_label_fn)(void* ui, uint8_t r, uint8_t g, uint8_t b, uint8_t a, const char* text);
+typedef bool (*hachimi_gui_register_menu_item_icon_fn)(const char* label, const char* icon_uri, const uint8_t* icon_ptr, size_t icon_len);
+typedef bool (*hachimi_gui_register_menu_section_with_icon_fn)(const char* title, const char* icon_uri, const uint8_t* icon_ptr, size_t icon_len, HachimiGuiUiCallback callback, void* userdata);
+
+/* Since API version 3 */
+typedef int32_t (*hachimi_gui_new_window_id_fn)(void);
+typedef bool (*hachimi_gui_show_window_fn)(int32_t id, const char* title, HachimiGuiUiCallback contents_callback, HachimiGuiUiCallback bottom_callback, void* userdata);
+typedef void (*hachimi_gui_close_window_fn)(int32_t id);
+typedef uint64_t (*hachimi_android_dex_load_fn)(const uint8_t* dex_ptr, size_t dex_len, const char* class_name);
+typedef bool (*hachimi_android_dex_unload_fn)(uint64_t handle);
+typedef bool (*hachimi_android_dex_call_static_noargs_fn)(uint64_t handle, const char* method, const char* sig);
+typedef bool (*hachimi_android_dex_call_static_string_fn)(uint64_t handle, const char* method, const char* sig, const char* arg);
+typedef void (*hachimi_il2cpp_runtime_object_init_fn)(Il2CppObject* object);
+typedef Il2CppString* (*hachimi_il2cpp_string_new_fn)(const char* text);
+typedef uint16_t* (*hachimi_il2cpp_string_chars_fn)(Il2CppString* s);
+typedef int32_t (*hachimi_il2cpp_string_length_fn)(Il2CppString* s);
+typedef bool (*hachimi_gui_ui_combo_menu_fn)(void* ui, const char* id, int32_t* selected_index, const char* const* items, size_t item_count, char* search_term, size_t search_term_len);
+typedef bool (*hachimi_hachimi_register_on_game_initialized_fn)(HachimiGameInitializedCallback callback, void* userdata);
+typedef bool (*hachimi_hachimi_register_present_callback_fn)(HachimiPresentCallback callback, void* userdata);
+typedef float (*hachimi_gui_get_menu_width_fn)(void);
+typedef void (*hachimi_gui_set_menu_width_fn)(float width);
+typedef const char* (*hachimi_hachimi_get_base_dir_fn)(void);
+typedef const char* (*hachimi_hachimi_get_data_path_fn)(void);
+
+/* Since API version 4 */
+typedef bool (*hachimi_gui_ui_slider_f32_fn)(void* ui, const char* text, float* value, float min, float max);
+typedef bool (*hachimi_gui_ui_slider_i32_fn)(void* ui, const char* text, int32_t* value, int32_t min, int32_t max);
+typedef bool (*hachimi_gui_ui_drag_value_f32_fn)(void* ui, float* value, float speed, float min, float max);
+typedef bool (*hachimi_gui_ui_drag_value_i32_fn)(void* ui, int32_t* value, float speed, int32_t min, int32_t max);
+typedef bool (*hachimi_gui_ui_text_edit_multiline_fn)(void* ui, char* buffer, size_t buffer_len, size_t desired_rows);
+typedef bool (*hachimi_gui_ui_collapsing_fn)(void* ui, const char* title, bool default_open, HachimiGuiUiCallback callback, void* userdata);
+typedef bool (*hachimi_gui_ui_scroll_area_fn)(void* ui, const char* id, float max_height, bool horizontal, HachimiGuiUiCallback callback, void* userdata);
+typedef bool (*hachimi_gui_ui_progress_bar_fn)(void* ui, float progress, const char* text);
+typedef bool (*hachimi_gui_set_image_rgba_fn)(const char* id, const uint8_t* rgba, size_t width, size_t height);
+typedef bool (*hachimi_gui_remove_image_fn)(const char* id);
+typedef bool (*hachimi_gui_ui_image_fn)(void* ui, const char* id, float width, float height);
+typedef bool (*hachimi_gui_ui_table_fn)(void* ui, const char* id, const char* const* headers, size_t column_count, size_t row_count, float row_height, HachimiGuiTableCellCallback callback, void* userdata);
+typedef bool (*hachimi_gui_ui_tooltip_fn)(void* ui, const char* text, HachimiGuiUiCallback callback, void* userdata);
+
+/* Since API version 5 */
+typedef bool (*hachimi_localized_data_get_localize_text_fn)(const char* key, char* buffer, size_t buffer_len, size_t* out_len);
+typedef bool (*hachimi_localized_data_get_text_data_fn)(int32_t category, int32_t index, char* buffer, size_t buffer_len, size_t* out_len);
+typedef bool (*hachimi_localized_data_get_character_system_text_fn)(int32_t character_id, int32_t voice_id, char* buffer, size_t buffer_len, size_t* out_len);
+typedef bool (*hachimi_hachimi_get_active_tl_dir_fn)(char* buffer, size_t buffer_len, size_t* out_len);
+typedef bool (*hachimi_hachimi_get_language_fn)(char* buffer, size_t buffer_len, size_t* out_len);
+typedef bool (*hachimi_hachimi_get_config_json_fn)(char* buffer, size_t buffer_len, size_t* out_len);
+typedef bool (*hachimi_template_eval_fn)(const char* input, char* buffer, size_t buffer_len, size_t* out_len);
+typedef bool (*hachimi_hachimi_register_on_config_changed_fn)(HachimiConfigChangedCallback callback, void* userdata);
+
+/* Since API version 6 */
+typedef bool (*hachimi_ipc_register_command_fn)(const char* name, HachimiIpcCommandCallback callback, bool main_thread, void* userdata);
+typedef bool (*hachimi_ipc_unregister_command_fn)(const char* name);
+typedef bool (*hachimi_ipc_response_write_fn)(void* response, const char* json);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* HACHIMI_PLUGIN_H */
@@ -0,0 +1,50 @@
+/*
+ * Minimal C plugin using the generated header.
+ *
+ *     cc -shared -fPIC -I../../../include -o libhachimi_sample_c.so sample_plugin.c
+ */
+
+#include "hachimi_plugin.h"
+
+#include <stdio.h>
+
+static hachimi_log_fn log_fn;
+static hachimi_gui_ui_heading_fn ui_heading;
+static hachimi_gui_ui_slider_f32_fn ui_slider_f32;
+static hachimi_gui_ui_progress_bar_fn ui_progress_bar;
+
+static float speed = 1.0f;
+
+static void menu_section(void* ui, void* userdata) {
+    (void)userdata;
+    ui_heading(ui, "Sample C plugin");
+    ui_slider_f32(ui, "Speed", &speed, 0.25f, 2.0f);
+    if (ui_progress_bar) {
+        ui_progress_bar(ui, (speed - 0.25f) / 1.75f, "");
+    }
+}
+
+#if defined(_WIN32)
+__declspec(dllexport)
+#else
+__attribute__((visibility("default")))
+#endif
+HachimiInitResult hachimi_init_v3(HachimiGetApiFn get_api, int32_t version) {
+    hachimi_gui_register_menu_section_fn register_menu_section;
+    char message[64];
+
+    log_fn = (hachimi_log_fn)get_api("log");
+    ui_heading = (hachimi_gui_ui_heading_fn)get_api("gui_ui_heading");
+    ui_slider_f32 = (hachimi_gui_ui_slider_f32_fn)get_api("gui_ui_slider_f32");
+    ui_progress_bar = (hachimi_gui_ui_progress_bar_fn)get_api("gui_ui_progress_bar");
+    register_menu_section = (hachimi_gui_register_menu_section_fn)get_api("gui_register_menu_section");
+
+    if (!log_fn || !ui_heading || !ui_slider_f32 || !register_menu_section) {
+        return HACHIMI_INIT_RESULT_ERROR;
+    }
+
+    snprintf(message, sizeof(message), "Loaded with API version %d", (int)version);
+    log_fn(HACHIMI_LOG_INFO, "sample_plugin_c", message);
+    register_menu_section(menu_section, NULL);
+    return HACHIMI_INIT_RESULT_OK;
+}
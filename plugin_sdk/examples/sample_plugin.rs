@@ -0,0 +1,64 @@
+//! A minimal plugin built on the SDK. Build it with `cargo build --example sample_plugin`;
+//! the resulting library can be listed in `load_libraries` like any other plugin.
+
+use std::sync::Mutex;
+
+use hachimi_plugin_sdk::{self as sdk, Api, InitResult, LogLevel};
+
+struct State {
+    enabled: bool,
+    speed: f32,
+    clicks: i32
+}
+
+static STATE: Mutex<State> = Mutex::new(State { enabled: true, speed: 1.0, clicks: 0 });
+
+fn init(api: &'static Api) -> InitResult {
+    sdk::log(LogLevel::Info, "sample_plugin", &format!("Loaded with API version {}", api.host_version));
+    for name in api.missing() {
+        sdk::log(LogLevel::Debug, "sample_plugin", &format!("Host does not provide {}", name));
+    }
+
+    sdk::register_menu_item("Sample plugin", || {
+        sdk::show_notification("Hello from the sample plugin");
+    });
+
+    sdk::register_menu_section(|ui| {
+        let mut state = STATE.lock().unwrap();
+        ui.heading("Sample plugin");
+        ui.checkbox(&mut state.enabled, "Enabled");
+        ui.slider_f32(&mut state.speed, 0.25, 2.0, "Speed");
+        ui.horizontal(|ui| {
+            if ui.button("Click me") {
+                state.clicks += 1;
+            }
+            ui.label(&format!("Clicked {} times", state.clicks));
+        });
+        ui.collapsing("Details", false, |ui| {
+            ui.table("sample_table", &["Key", "Value"], 2, 0.0, |ui, row, column| {
+                let text = match (row, column) {
+                    (0, 0) => "enabled".to_owned(),
+                    (0, _) => state.enabled.to_string(),
+                    (_, 0) => "speed".to_owned(),
+                    (_, _) => format!("{:.2}", state.speed)
+                };
+                ui.label(&text);
+            });
+        });
+    });
+
+    // curl -X POST -H "Content-Type: application/json" \
+    //   -d '{"type": "Plugin", "command": "sample.get_state"}' http://127.0.0.1:50433
+    sdk::register_ipc_command("sample.get_state", false, |_args| {
+        let state = STATE.lock().unwrap();
+        Ok(format!(r#"{{"enabled": {}, "speed": {}, "clicks": {}}}"#, state.enabled, state.speed, state.clicks))
+    });
+
+    InitResult::Ok
+}
+
+fn shutdown() {
+    sdk::log(LogLevel::Info, "sample_plugin", "Unloading");
+}
+
+sdk::hachimi_plugin!(init, required_version = 4, shutdown = shutdown);
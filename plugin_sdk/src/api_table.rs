@@ -0,0 +1,504 @@
+// The plugin API table. This is the single source of truth for every function that
+// plugins can resolve through `HachimiGetApiFn`: Hachimi includes it to build
+// `hachimi_get_api` (and to check each implementation's signature against it), and
+// the SDK includes it to build its typed loader and to generate `hachimi_plugin.h`.
+//
+// Append new functions at the end with `since` set to the new API version and bump
+// `version`. Never change the signature of an existing entry.
+hachimi_plugin_api! {
+    version = 6;
+
+    #[since = 2]
+    fn hachimi_instance() -> *const Hachimi;
+
+    #[since = 2]
+    fn hachimi_get_interceptor(this: *const Hachimi) -> *const Interceptor;
+
+    #[since = 2]
+    fn interceptor_hook(
+        this: *const Interceptor,
+        orig_addr: *mut c_void,
+        hook_addr: *mut c_void,
+    ) -> *mut c_void;
+
+    #[since = 2]
+    fn interceptor_hook_vtable(
+        this: *const Interceptor,
+        vtable: *mut *mut c_void,
+        vtable_index: usize,
+        hook_addr: *mut c_void,
+    ) -> *mut c_void;
+
+    #[since = 2]
+    fn interceptor_get_trampoline_addr(
+        this: *const Interceptor,
+        hook_addr: *mut c_void,
+    ) -> *mut c_void;
+
+    #[since = 2]
+    fn interceptor_unhook(this: *const Interceptor, hook_addr: *mut c_void) -> *mut c_void;
+
+    #[since = 2]
+    fn il2cpp_resolve_symbol(name: *const c_char) -> *mut c_void;
+
+    #[since = 2]
+    fn il2cpp_get_assembly_image(assembly_name: *const c_char) -> *const Il2CppImage;
+
+    #[since = 2]
+    fn il2cpp_get_class(
+        image: *const Il2CppImage,
+        namespace: *const c_char,
+        class_name: *const c_char,
+    ) -> *mut Il2CppClass;
+
+    #[since = 2]
+    fn il2cpp_get_method(
+        class: *mut Il2CppClass,
+        name: *const c_char,
+        args_count: i32,
+    ) -> *const MethodInfo;
+
+    #[since = 2]
+    fn il2cpp_get_method_overload(
+        class: *mut Il2CppClass,
+        name: *const c_char,
+        params: *const Il2CppTypeEnum,
+        param_count: usize,
+    ) -> *const MethodInfo;
+
+    #[since = 2]
+    fn il2cpp_get_method_addr(
+        class: *mut Il2CppClass,
+        name: *const c_char,
+        args_count: i32,
+    ) -> *mut c_void;
+
+    #[since = 2]
+    fn il2cpp_get_method_overload_addr(
+        class: *mut Il2CppClass,
+        name: *const c_char,
+        params: *const Il2CppTypeEnum,
+        param_count: usize,
+    ) -> *mut c_void;
+
+    #[since = 2]
+    fn il2cpp_get_method_cached(
+        class: *mut Il2CppClass,
+        name: *const c_char,
+        args_count: i32,
+    ) -> *const MethodInfo;
+
+    #[since = 2]
+    fn il2cpp_get_method_addr_cached(
+        class: *mut Il2CppClass,
+        name: *const c_char,
+        args_count: i32,
+    ) -> *mut c_void;
+
+    #[since = 2]
+    fn il2cpp_find_nested_class(
+        class: *mut Il2CppClass,
+        name: *const c_char,
+    ) -> *mut Il2CppClass;
+
+    #[since = 2]
+    fn il2cpp_resolve_icall(name: *const c_char) -> Il2CppMethodPointer;
+
+    #[since = 2]
+    fn il2cpp_class_get_methods(
+        klass: *mut Il2CppClass,
+        iter: *mut *mut c_void,
+    ) -> *const MethodInfo;
+
+    #[since = 2]
+    fn il2cpp_get_field_from_name(
+        class: *mut Il2CppClass,
+        name: *const c_char,
+    ) -> *mut FieldInfo;
+
+    #[since = 2]
+    fn il2cpp_get_field_value(
+        obj: *mut Il2CppObject,
+        field: *mut FieldInfo,
+        out_value: *mut c_void,
+    );
+
+    #[since = 2]
+    fn il2cpp_set_field_value(
+        obj: *mut Il2CppObject,
+        field: *mut FieldInfo,
+        value: *const c_void,
+    );
+
+    #[since = 2]
+    fn il2cpp_get_static_field_value(field: *mut FieldInfo, out_value: *mut c_void);
+
+    #[since = 2]
+    fn il2cpp_set_static_field_value(field: *mut FieldInfo, value: *const c_void);
+
+    #[since = 2]
+    fn il2cpp_object_new(klass: *const Il2CppClass) -> *mut Il2CppObject;
+
+    #[since = 2]
+    fn il2cpp_unbox(obj: *mut Il2CppObject) -> *mut c_void;
+
+    #[since = 2]
+    fn il2cpp_get_main_thread() -> *mut Il2CppThread;
+
+    #[since = 2]
+    fn il2cpp_get_attached_threads(out_size: *mut usize) -> *mut *mut Il2CppThread;
+
+    #[since = 2]
+    fn il2cpp_schedule_on_thread(thread: *mut Il2CppThread, callback: unsafe extern "C" fn());
+
+    #[since = 2]
+    fn il2cpp_create_array(
+        element_type: *mut Il2CppClass,
+        length: il2cpp_array_size_t,
+    ) -> *mut Il2CppArray;
+
+    #[since = 2]
+    fn il2cpp_get_singleton_like_instance(class: *mut Il2CppClass) -> *mut Il2CppObject;
+
+    #[since = 2]
+    fn log(level: i32, target: *const c_char, message: *const c_char);
+
+    #[since = 2]
+    fn gui_register_menu_item(
+        label: *const c_char,
+        callback: Option<GuiMenuCallback>,
+        userdata: *mut c_void,
+    ) -> bool;
+
+    #[since = 2]
+    fn gui_register_menu_section(
+        callback: Option<GuiMenuSectionCallback>,
+        userdata: *mut c_void,
+    ) -> bool;
+
+    #[since = 2]
+    fn gui_show_notification(message: *const c_char) -> bool;
+
+    #[since = 2]
+    fn gui_ui_heading(ui: *mut c_void, text: *const c_char) -> bool;
+
+    #[since = 2]
+    fn gui_ui_label(ui: *mut c_void, text: *const c_char) -> bool;
+
+    #[since = 2]
+    fn gui_ui_small(ui: *mut c_void, text: *const c_char) -> bool;
+
+    #[since = 2]
+    fn gui_ui_separator(ui: *mut c_void) -> bool;
+
+    #[since = 2]
+    fn gui_ui_button(ui: *mut c_void, text: *const c_char) -> bool;
+
+    #[since = 2]
+    fn gui_ui_small_button(ui: *mut c_void, text: *const c_char) -> bool;
+
+    #[since = 2]
+    fn gui_ui_checkbox(ui: *mut c_void, text: *const c_char, value: *mut bool) -> bool;
+
+    #[since = 2]
+    fn gui_ui_text_edit_singleline(
+        ui: *mut c_void,
+        buffer: *mut c_char,
+        buffer_len: usize,
+    ) -> bool;
+
+    #[since = 2]
+    fn gui_ui_horizontal(
+        ui: *mut c_void,
+        callback: Option<GuiUiCallback>,
+        userdata: *mut c_void,
+    ) -> bool;
+
+    #[since = 2]
+    fn gui_ui_grid(
+        ui: *mut c_void,
+        id: *const c_char,
+        columns: usize,
+        spacing_x: f32,
+        spacing_y: f32,
+        callback: Option<GuiUiCallback>,
+        userdata: *mut c_void,
+    ) -> bool;
+
+    #[since = 2]
+    fn gui_ui_end_row(ui: *mut c_void) -> bool;
+
+    #[since = 2]
+    fn gui_ui_colored_label(
+        ui: *mut c_void,
+        r: u8,
+        g: u8,
+        b: u8,
+        a: u8,
+        text: *const c_char,
+    ) -> bool;
+
+    #[since = 2]
+    fn gui_register_menu_item_icon(
+        label: *const c_char,
+        icon_uri: *const c_char,
+        icon_ptr: *const u8,
+        icon_len: usize,
+    ) -> bool;
+
+    #[since = 2]
+    fn gui_register_menu_section_with_icon(
+        title: *const c_char,
+        icon_uri: *const c_char,
+        icon_ptr: *const u8,
+        icon_len: usize,
+        callback: Option<GuiMenuSectionCallback>,
+        userdata: *mut c_void,
+    ) -> bool;
+
+    #[since = 3]
+    fn gui_new_window_id() -> i32;
+
+    #[since = 3]
+    fn gui_show_window(
+        id: i32,
+        title: *const c_char,
+        contents_callback: Option<GuiWindowCallback>,
+        bottom_callback: Option<GuiWindowCallback>,
+        userdata: *mut c_void,
+    ) -> bool;
+
+    #[since = 3]
+    fn gui_close_window(id: i32);
+
+    #[since = 3]
+    fn android_dex_load(dex_ptr: *const u8, dex_len: usize, class_name: *const c_char) -> u64;
+
+    #[since = 3]
+    fn android_dex_unload(handle: u64) -> bool;
+
+    #[since = 3]
+    fn android_dex_call_static_noargs(
+        handle: u64,
+        method: *const c_char,
+        sig: *const c_char,
+    ) -> bool;
+
+    #[since = 3]
+    fn android_dex_call_static_string(
+        handle: u64,
+        method: *const c_char,
+        sig: *const c_char,
+        arg: *const c_char,
+    ) -> bool;
+
+    #[since = 3]
+    fn il2cpp_runtime_object_init(object: *mut Il2CppObject);
+
+    #[since = 3]
+    fn il2cpp_string_new(text: *const c_char) -> *mut Il2CppString;
+
+    #[since = 3]
+    fn il2cpp_string_chars(s: *mut Il2CppString) -> *mut u16;
+
+    #[since = 3]
+    fn il2cpp_string_length(s: *mut Il2CppString) -> i32;
+
+    #[since = 3]
+    fn gui_ui_combo_menu(
+        ui: *mut c_void,
+        id: *const c_char,
+        selected_index: *mut i32,
+        items: *const *const c_char,
+        item_count: usize,
+        search_term: *mut c_char,
+        search_term_len: usize,
+    ) -> bool;
+
+    #[since = 3]
+    fn hachimi_register_on_game_initialized(
+        callback: Option<GameInitializedCallback>,
+        userdata: *mut c_void,
+    ) -> bool;
+
+    #[since = 3]
+    fn hachimi_register_present_callback(
+        callback: Option<PresentCallback>,
+        userdata: *mut c_void,
+    ) -> bool;
+
+    #[since = 3]
+    fn gui_get_menu_width() -> f32;
+
+    #[since = 3]
+    fn gui_set_menu_width(width: f32);
+
+    #[since = 3]
+    fn hachimi_get_base_dir() -> *const c_char;
+
+    #[since = 3]
+    fn hachimi_get_data_path() -> *const c_char;
+
+    #[since = 4]
+    fn gui_ui_slider_f32(
+        ui: *mut c_void,
+        text: *const c_char,
+        value: *mut f32,
+        min: f32,
+        max: f32,
+    ) -> bool;
+
+    #[since = 4]
+    fn gui_ui_slider_i32(
+        ui: *mut c_void,
+        text: *const c_char,
+        value: *mut i32,
+        min: i32,
+        max: i32,
+    ) -> bool;
+
+    #[since = 4]
+    fn gui_ui_drag_value_f32(
+        ui: *mut c_void,
+        value: *mut f32,
+        speed: f32,
+        min: f32,
+        max: f32,
+    ) -> bool;
+
+    #[since = 4]
+    fn gui_ui_drag_value_i32(
+        ui: *mut c_void,
+        value: *mut i32,
+        speed: f32,
+        min: i32,
+        max: i32,
+    ) -> bool;
+
+    #[since = 4]
+    fn gui_ui_text_edit_multiline(
+        ui: *mut c_void,
+        buffer: *mut c_char,
+        buffer_len: usize,
+        desired_rows: usize,
+    ) -> bool;
+
+    #[since = 4]
+    fn gui_ui_collapsing(
+        ui: *mut c_void,
+        title: *const c_char,
+        default_open: bool,
+        callback: Option<GuiUiCallback>,
+        userdata: *mut c_void,
+    ) -> bool;
+
+    #[since = 4]
+    fn gui_ui_scroll_area(
+        ui: *mut c_void,
+        id: *const c_char,
+        max_height: f32,
+        horizontal: bool,
+        callback: Option<GuiUiCallback>,
+        userdata: *mut c_void,
+    ) -> bool;
+
+    #[since = 4]
+    fn gui_ui_progress_bar(ui: *mut c_void, progress: f32, text: *const c_char) -> bool;
+
+    #[since = 4]
+    fn gui_set_image_rgba(
+        id: *const c_char,
+        rgba: *const u8,
+        width: usize,
+        height: usize,
+    ) -> bool;
+
+    #[since = 4]
+    fn gui_remove_image(id: *const c_char) -> bool;
+
+    #[since = 4]
+    fn gui_ui_image(ui: *mut c_void, id: *const c_char, width: f32, height: f32) -> bool;
+
+    #[since = 4]
+    fn gui_ui_table(
+        ui: *mut c_void,
+        id: *const c_char,
+        headers: *const *const c_char,
+        column_count: usize,
+        row_count: usize,
+        row_height: f32,
+        callback: Option<GuiTableCellCallback>,
+        userdata: *mut c_void,
+    ) -> bool;
+
+    #[since = 4]
+    fn gui_ui_tooltip(
+        ui: *mut c_void,
+        text: *const c_char,
+        callback: Option<GuiUiCallback>,
+        userdata: *mut c_void,
+    ) -> bool;
+
+    #[since = 5]
+    fn localized_data_get_localize_text(
+        key: *const c_char,
+        buffer: *mut c_char,
+        buffer_len: usize,
+        out_len: *mut usize,
+    ) -> bool;
+
+    #[since = 5]
+    fn localized_data_get_text_data(
+        category: i32,
+        index: i32,
+        buffer: *mut c_char,
+        buffer_len: usize,
+        out_len: *mut usize,
+    ) -> bool;
+
+    #[since = 5]
+    fn localized_data_get_character_system_text(
+        character_id: i32,
+        voice_id: i32,
+        buffer: *mut c_char,
+        buffer_len: usize,
+        out_len: *mut usize,
+    ) -> bool;
+
+    #[since = 5]
+    fn hachimi_get_active_tl_dir(buffer: *mut c_char, buffer_len: usize, out_len: *mut usize) -> bool;
+
+    #[since = 5]
+    fn hachimi_get_language(buffer: *mut c_char, buffer_len: usize, out_len: *mut usize) -> bool;
+
+    #[since = 5]
+    fn hachimi_get_config_json(buffer: *mut c_char, buffer_len: usize, out_len: *mut usize) -> bool;
+
+    #[since = 5]
+    fn template_eval(
+        input: *const c_char,
+        buffer: *mut c_char,
+        buffer_len: usize,
+        out_len: *mut usize,
+    ) -> bool;
+
+    #[since = 5]
+    fn hachimi_register_on_config_changed(
+        callback: Option<ConfigChangedCallback>,
+        userdata: *mut c_void,
+    ) -> bool;
+
+    #[since = 6]
+    fn ipc_register_command(
+        name: *const c_char,
+        callback: Option<IpcCommandCallback>,
+        main_thread: bool,
+        userdata: *mut c_void,
+    ) -> bool;
+
+    #[since = 6]
+    fn ipc_unregister_command(name: *const c_char) -> bool;
+
+    #[since = 6]
+    fn ipc_response_write(response: *mut c_void, json: *const c_char) -> bool;
+}
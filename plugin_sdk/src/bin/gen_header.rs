@@ -0,0 +1,40 @@
+//! Usage:
+//!   hachimi-gen-header                 print the header to stdout
+//!   hachimi-gen-header <path>          write the header to <path>
+//!   hachimi-gen-header --check <path>  exit with an error if <path> is out of date
+
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let header = hachimi_plugin_sdk::header::generate();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        [] => {
+            print!("{}", header);
+            ExitCode::SUCCESS
+        }
+        ["--check", path] => match std::fs::read_to_string(path) {
+            Ok(existing) if existing.replace("\r\n", "\n") == header => ExitCode::SUCCESS,
+            Ok(_) => {
+                eprintln!("{} is out of date, regenerate it with hachimi-gen-header", path);
+                ExitCode::FAILURE
+            }
+            Err(e) => {
+                eprintln!("Failed to read {}: {}", path, e);
+                ExitCode::FAILURE
+            }
+        },
+        [path] => match std::fs::write(path, header) {
+            Ok(_) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("Failed to write {}: {}", path, e);
+                ExitCode::FAILURE
+            }
+        },
+        _ => {
+            eprintln!("Usage: hachimi-gen-header [--check] [path]");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Generates `hachimi_plugin.h` from the API table.
+
+use std::fmt::Write;
+
+use crate::{API_ENTRIES, VERSION};
+
+const PREAMBLE: &str = r#"/*
+ * Hachimi plugin API
+ *
+ * Generated from plugin_sdk/src/api_table.rs by `hachimi-gen-header`. Do not edit.
+ *
+ * Plugins export `hachimi_init_v3` and receive a `HachimiGetApiFn` which resolves
+ * each function below by name (the part between `hachimi_` and `_fn`), e.g.
+ *
+ *     hachimi_gui_ui_label_fn ui_label = (hachimi_gui_ui_label_fn)get_api("gui_ui_label");
+ *
+ * Functions that are unavailable in the running Hachimi version resolve to NULL.
+ *
+ * Plugins may also export `hachimi_shutdown`, which is called before the plugin is unloaded
+ * for a reload. Hooks, menu items, windows and callbacks it registered are removed by Hachimi.
+ */
+
+#ifndef HACHIMI_PLUGIN_H
+#define HACHIMI_PLUGIN_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+"#;
+
+const TYPES: &str = r#"
+typedef enum HachimiInitResult {
+    HACHIMI_INIT_RESULT_ERROR = 0,
+    HACHIMI_INIT_RESULT_OK = 1
+} HachimiInitResult;
+
+typedef enum HachimiLogLevel {
+    HACHIMI_LOG_ERROR = 1,
+    HACHIMI_LOG_WARN = 2,
+    HACHIMI_LOG_INFO = 3,
+    HACHIMI_LOG_DEBUG = 4,
+    HACHIMI_LOG_TRACE = 5
+} HachimiLogLevel;
+
+typedef struct Hachimi Hachimi;
+typedef struct Interceptor Interceptor;
+typedef struct Il2CppImage Il2CppImage;
+typedef struct Il2CppClass Il2CppClass;
+typedef struct Il2CppObject Il2CppObject;
+typedef struct Il2CppString Il2CppString;
+typedef struct Il2CppArray Il2CppArray;
+typedef struct Il2CppThread Il2CppThread;
+typedef struct MethodInfo MethodInfo;
+typedef struct FieldInfo FieldInfo;
+
+typedef void* (*HachimiGetApiFn)(const char* name);
+typedef HachimiInitResult (*HachimiInitV3Fn)(HachimiGetApiFn get_api, int32_t version);
+typedef void (*HachimiShutdownFn)(void);
+
+typedef void (*HachimiGuiMenuCallback)(void* userdata);
+typedef void (*HachimiGuiUiCallback)(void* ui, void* userdata);
+typedef void (*HachimiGuiTableCellCallback)(void* ui, size_t row, size_t column, void* userdata);
+typedef void (*HachimiGameInitializedCallback)(void* userdata);
+typedef void (*HachimiPresentCallback)(void* swapchain, void* userdata);
+typedef void (*HachimiConfigChangedCallback)(void* userdata);
+typedef bool (*HachimiIpcCommandCallback)(const char* args_json, void* response, void* userdata);
+"#;
+
+const POSTAMBLE: &str = r#"
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* HACHIMI_PLUGIN_H */
+"#;
+
+/// Maps a Rust type from the API table (as produced by `stringify!`) to C.
+/// Panics on types the header doesn't know about, so new ones can't slip through unnoticed.
+fn c_type(ty: &str) -> String {
+    let ty = ty.trim();
+    if let Some(inner) = ty.strip_prefix("*const ") {
+        let inner = c_type(inner);
+        return if inner.ends_with('*') {
+            format!("{} const*", inner)
+        }
+        else {
+            format!("const {}*", inner)
+        };
+    }
+    if let Some(inner) = ty.strip_prefix("*mut ") {
+        return format!("{}*", c_type(inner));
+    }
+
+    match ty {
+        "c_void" => "void",
+        "c_char" => "char",
+        "bool" => "bool",
+        "u8" => "uint8_t",
+        "u16" => "uint16_t",
+        "i32" => "int32_t",
+        "u64" => "uint64_t",
+        "usize" | "il2cpp_array_size_t" => "size_t",
+        "f32" => "float",
+        "Il2CppMethodPointer" => "void*",
+        "Il2CppTypeEnum" => "uint32_t",
+        "Hachimi" | "Interceptor" | "Il2CppImage" | "Il2CppClass" | "Il2CppObject" | "Il2CppString" |
+        "Il2CppArray" | "Il2CppThread" | "MethodInfo" | "FieldInfo" => ty,
+        "Option<GuiMenuCallback>" => "HachimiGuiMenuCallback",
+        "Option<GuiMenuSectionCallback>" | "Option<GuiUiCallback>" | "Option<GuiWindowCallback>" => "HachimiGuiUiCallback",
+        "Option<GuiTableCellCallback>" => "HachimiGuiTableCellCallback",
+        "Option<GameInitializedCallback>" => "HachimiGameInitializedCallback",
+        "Option<PresentCallback>" => "HachimiPresentCallback",
+        "Option<ConfigChangedCallback>" => "HachimiConfigChangedCallback",
+        "Option<IpcCommandCallback>" => "HachimiIpcCommandCallback",
+        _ => panic!("no C mapping for plugin API type `{}`", ty)
+    }.to_owned()
+}
+
+/// Argument names that are fine in Rust but reserved in C or C++.
+fn c_arg_name(name: &str) -> &str {
+    match name {
+        "this" => "self",
+        "class" => "klass",
+        "namespace" => "name_space",
+        _ => name
+    }
+}
+
+fn c_arg(name: &str, ty: &str) -> String {
+    let name = c_arg_name(name);
+    if ty.trim().starts_with("unsafe extern \"C\" fn()") {
+        return format!("void (*{})(void)", name);
+    }
+    format!("{} {}", c_type(ty), name)
+}
+
+pub fn generate() -> String {
+    let mut out = String::new();
+    out.push_str(PREAMBLE);
+    writeln!(out, "\n#define HACHIMI_PLUGIN_API_VERSION {}", VERSION).unwrap();
+    out.push_str(TYPES);
+
+    let mut since = 0;
+    for entry in API_ENTRIES {
+        if entry.since != since {
+            since = entry.since;
+            writeln!(out, "\n/* Since API version {} */", since).unwrap();
+        }
+
+        let ret = entry.ret.map(c_type).unwrap_or_else(|| "void".to_owned());
+        let args = if entry.args.is_empty() {
+            "void".to_owned()
+        }
+        else {
+            entry.args.iter()
+                .map(|(name, ty)| c_arg(name, ty))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        writeln!(out, "typedef {} (*hachimi_{}_fn)({});", ret, entry.name, args).unwrap();
+    }
+
+    out.push_str(POSTAMBLE);
+    out
+}
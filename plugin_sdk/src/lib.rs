@@ -0,0 +1,392 @@
+//! Typed bindings for the Hachimi plugin API.
+//!
+//! The function table is shared with Hachimi itself through `api_table.rs`, so the
+//! loader, the safe wrappers and `hachimi_plugin.h` can never drift from the host.
+#![allow(non_camel_case_types)]
+
+use std::{ffi::{c_char, c_uint, c_void, CStr, CString}, fmt, sync::OnceLock};
+
+pub mod header;
+mod ui;
+pub use ui::{Ui, set_image_rgba, remove_image};
+
+macro_rules! opaque_types {
+    ($($name:ident),* $(,)?) => {
+        $(
+            #[repr(C)]
+            pub struct $name {
+                _private: [u8; 0]
+            }
+        )*
+    };
+}
+
+opaque_types!(
+    Hachimi, Interceptor,
+    Il2CppImage, Il2CppClass, Il2CppObject, Il2CppString, Il2CppArray, Il2CppThread,
+    MethodInfo, FieldInfo
+);
+
+pub type Il2CppMethodPointer = usize;
+pub type Il2CppTypeEnum = c_uint;
+pub type il2cpp_array_size_t = usize;
+
+pub type HachimiGetApiFn = extern "C" fn(name: *const c_char) -> *mut c_void;
+pub type HachimiInitV3Fn = extern "C" fn(get_api: HachimiGetApiFn, version: i32) -> InitResult;
+pub type GuiMenuCallback = extern "C" fn(userdata: *mut c_void);
+pub type GuiMenuSectionCallback = extern "C" fn(ui: *mut c_void, userdata: *mut c_void);
+pub type GuiUiCallback = extern "C" fn(ui: *mut c_void, userdata: *mut c_void);
+pub type GameInitializedCallback = unsafe extern "C" fn(userdata: *mut c_void);
+pub type PresentCallback = unsafe extern "C" fn(swapchain: *mut c_void, userdata: *mut c_void);
+pub type GuiWindowCallback = extern "C" fn(ui: *mut c_void, userdata: *mut c_void);
+pub type ConfigChangedCallback = unsafe extern "C" fn(userdata: *mut c_void);
+pub type IpcCommandCallback = unsafe extern "C" fn(
+    args_json: *const c_char, response: *mut c_void, userdata: *mut c_void
+) -> bool;
+pub type GuiTableCellCallback = extern "C" fn(ui: *mut c_void, row: usize, column: usize, userdata: *mut c_void);
+
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum InitResult {
+    Error,
+    Ok
+}
+
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum LogLevel {
+    Error = 1,
+    Warn = 2,
+    Info = 3,
+    Debug = 4,
+    Trace = 5
+}
+
+// The host passes these across the ABI boundary by value; keep them the size the header promises.
+const _: () = assert!(std::mem::size_of::<InitResult>() == 4);
+const _: () = assert!(std::mem::size_of::<Option<GuiUiCallback>>() == std::mem::size_of::<*mut c_void>());
+const _: () = assert!(std::mem::size_of::<Il2CppMethodPointer>() == std::mem::size_of::<*mut c_void>());
+
+/// The first host API version that hands plugins a `HachimiGetApiFn`.
+pub const MIN_HOST_VERSION: i32 = 3;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ApiEntry {
+    pub name: &'static str,
+    pub since: i32,
+    pub args: &'static [(&'static str, &'static str)],
+    pub ret: Option<&'static str>
+}
+
+macro_rules! hachimi_plugin_api {
+    (
+        version = $version:literal;
+        $(
+            #[since = $since:literal]
+            fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)?;
+        )*
+    ) => {
+        /// The API version this SDK was generated from.
+        pub const VERSION: i32 = $version;
+
+        pub static API_ENTRIES: &[ApiEntry] = &[
+            $(
+                ApiEntry {
+                    name: stringify!($name),
+                    since: $since,
+                    args: &[$((stringify!($arg), stringify!($ty))),*],
+                    ret: hachimi_plugin_api!(@ret $($ret)?)
+                },
+            )*
+        ];
+
+        /// Function pointers resolved from the host. Functions the host doesn't provide are `None`.
+        #[derive(Clone, Copy)]
+        pub struct Api {
+            pub host_version: i32,
+            $(pub $name: Option<unsafe extern "C" fn($($ty),*) $(-> $ret)?>,)*
+        }
+
+        impl Api {
+            /// # Safety
+            /// `get_api` must be the function passed to `hachimi_init_v3`.
+            pub unsafe fn load(get_api: HachimiGetApiFn, host_version: i32) -> Api {
+                Api {
+                    host_version,
+                    $(
+                        $name: std::mem::transmute::<*mut c_void, Option<unsafe extern "C" fn($($ty),*) $(-> $ret)?>>(
+                            get_api(concat!(stringify!($name), "\0").as_ptr() as *const c_char)
+                        ),
+                    )*
+                }
+            }
+
+            /// Names of the functions this SDK knows about that the host didn't provide.
+            pub fn missing(&self) -> Vec<&'static str> {
+                let mut missing = Vec::new();
+                $(
+                    if self.$name.is_none() {
+                        missing.push(stringify!($name));
+                    }
+                )*
+                missing
+            }
+        }
+    };
+
+    (@ret) => { None };
+    (@ret $ret:ty) => { Some(stringify!($ret)) };
+}
+
+include!("api_table.rs");
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum VersionError {
+    /// The host is older than the oldest API version this SDK can talk to.
+    HostTooOld { host: i32, required: i32 }
+}
+
+impl fmt::Display for VersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VersionError::HostTooOld { host, required } => write!(
+                f, "Hachimi plugin API version {} is too old, version {} or newer is required", host, required
+            )
+        }
+    }
+}
+
+impl std::error::Error for VersionError {}
+
+/// Picks the API version both sides understand. `required` is the lowest version the plugin can work with.
+pub fn negotiate_version(host_version: i32, required: i32) -> Result<i32, VersionError> {
+    let required = required.max(MIN_HOST_VERSION);
+    if host_version < required {
+        return Err(VersionError::HostTooOld { host: host_version, required });
+    }
+    Ok(host_version.min(VERSION))
+}
+
+static API: OnceLock<Api> = OnceLock::new();
+
+/// Resolves the API table once. Call this from `hachimi_init_v3` (the `hachimi_plugin!` macro does it for you).
+///
+/// # Safety
+/// `get_api` must be the function passed to `hachimi_init_v3`.
+pub unsafe fn init(get_api: HachimiGetApiFn, host_version: i32, required: i32) -> Result<&'static Api, VersionError> {
+    let version = negotiate_version(host_version, required)?;
+    Ok(API.get_or_init(|| Api::load(get_api, version)))
+}
+
+/// The API table resolved by [`init`].
+///
+/// # Panics
+/// Panics if called before [`init`].
+pub fn api() -> &'static Api {
+    API.get().expect("hachimi_plugin_sdk::init has not been called")
+}
+
+/// Declares the `hachimi_init_v3` export. The init function receives the resolved [`Api`].
+///
+/// Pass `shutdown = path` to also export `hachimi_shutdown`, which Hachimi calls before unloading
+/// the plugin when it's reloaded. Hooks, menu items, windows and callbacks are removed by the host.
+///
+/// ```ignore
+/// hachimi_plugin_sdk::hachimi_plugin!(init, required_version = 4, shutdown = shutdown);
+/// fn init(api: &'static Api) -> InitResult { InitResult::Ok }
+/// fn shutdown() {}
+/// ```
+#[macro_export]
+macro_rules! hachimi_plugin {
+    ($init:path) => {
+        $crate::hachimi_plugin!($init, required_version = $crate::MIN_HOST_VERSION);
+    };
+    ($init:path, shutdown = $shutdown:path) => {
+        $crate::hachimi_plugin!($init, required_version = $crate::MIN_HOST_VERSION, shutdown = $shutdown);
+    };
+    ($init:path, required_version = $required:expr, shutdown = $shutdown:path) => {
+        $crate::hachimi_plugin!($init, required_version = $required);
+
+        #[no_mangle]
+        pub extern "C" fn hachimi_shutdown() {
+            $shutdown()
+        }
+    };
+    ($init:path, required_version = $required:expr) => {
+        #[no_mangle]
+        pub extern "C" fn hachimi_init_v3(get_api: $crate::HachimiGetApiFn, version: i32) -> $crate::InitResult {
+            match unsafe { $crate::init(get_api, version, $required) } {
+                Ok(api) => $init(api),
+                Err(_) => $crate::InitResult::Error
+            }
+        }
+    };
+}
+
+fn to_cstring(s: &str) -> CString {
+    CString::new(s.replace('\0', "")).unwrap()
+}
+
+/// Sends a message to Hachimi's log.
+pub fn log(level: LogLevel, target: &str, message: &str) {
+    let Some(log) = api().log else { return; };
+    let target = to_cstring(target);
+    let message = to_cstring(message);
+    unsafe { log(level as i32, target.as_ptr(), message.as_ptr()) }
+}
+
+/// Shows a toast notification in the Hachimi GUI.
+pub fn show_notification(message: &str) -> bool {
+    let Some(show_notification) = api().gui_show_notification else { return false; };
+    let message = to_cstring(message);
+    unsafe { show_notification(message.as_ptr()) }
+}
+
+unsafe fn host_str(ptr: *const c_char) -> Option<String> {
+    if ptr.is_null() {
+        return None;
+    }
+    Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+}
+
+/// Hachimi's data directory.
+pub fn base_dir() -> Option<String> {
+    let get_base_dir = api().hachimi_get_base_dir?;
+    unsafe { host_str(get_base_dir()) }
+}
+
+/// Hachimi's localized data path.
+pub fn data_path() -> Option<String> {
+    let get_data_path = api().hachimi_get_data_path?;
+    unsafe { host_str(get_data_path()) }
+}
+
+extern "C" fn menu_item_trampoline<F: FnMut() + 'static>(userdata: *mut c_void) {
+    let f = unsafe { &mut *(userdata as *mut F) };
+    f();
+}
+
+/// Adds an item to the Hachimi menu. The closure lives for the rest of the process.
+pub fn register_menu_item<F: FnMut() + 'static>(label: &str, f: F) -> bool {
+    let Some(register) = api().gui_register_menu_item else { return false; };
+    let label = to_cstring(label);
+    let userdata = Box::into_raw(Box::new(f)) as *mut c_void;
+    unsafe { register(label.as_ptr(), Some(menu_item_trampoline::<F>), userdata) }
+}
+
+/// Adds a section to the Hachimi menu. The closure lives for the rest of the process.
+pub fn register_menu_section<F: FnMut(&mut Ui) + 'static>(f: F) -> bool {
+    let Some(register) = api().gui_register_menu_section else { return false; };
+    let userdata = Box::into_raw(Box::new(f)) as *mut c_void;
+    unsafe { register(Some(ui::ui_trampoline::<F>), userdata) }
+}
+
+/// Calls a host function that fills a caller-provided string buffer, growing the buffer if the value didn't fit.
+fn read_host_string(read: impl Fn(*mut c_char, usize, *mut usize) -> bool) -> Option<String> {
+    let mut buffer = vec![0u8; 256];
+    loop {
+        let mut len = 0usize;
+        if !read(buffer.as_mut_ptr() as *mut c_char, buffer.len(), &mut len) {
+            return None;
+        }
+        if len < buffer.len() {
+            buffer.truncate(len);
+            return Some(String::from_utf8_lossy(&buffer).into_owned());
+        }
+        buffer.resize(len + 1, 0);
+    }
+}
+
+/// Looks up `localize_dict` in the active translation.
+pub fn localize_text(key: &str) -> Option<String> {
+    let f = api().localized_data_get_localize_text?;
+    let key = to_cstring(key);
+    read_host_string(|buf, len, out_len| unsafe { f(key.as_ptr(), buf, len, out_len) })
+}
+
+/// Looks up `text_data_dict` in the active translation.
+pub fn text_data(category: i32, index: i32) -> Option<String> {
+    let f = api().localized_data_get_text_data?;
+    read_host_string(|buf, len, out_len| unsafe { f(category, index, buf, len, out_len) })
+}
+
+/// Looks up `character_system_text_dict` in the active translation.
+pub fn character_system_text(character_id: i32, voice_id: i32) -> Option<String> {
+    let f = api().localized_data_get_character_system_text?;
+    read_host_string(|buf, len, out_len| unsafe { f(character_id, voice_id, buf, len, out_len) })
+}
+
+/// The folder of the selected translation repo, if any.
+pub fn active_tl_dir() -> Option<String> {
+    let f = api().hachimi_get_active_tl_dir?;
+    read_host_string(|buf, len, out_len| unsafe { f(buf, len, out_len) })
+}
+
+/// Hachimi's UI language as a locale string, e.g. `en` or `zh-tw`.
+pub fn language() -> Option<String> {
+    let f = api().hachimi_get_language?;
+    read_host_string(|buf, len, out_len| unsafe { f(buf, len, out_len) })
+}
+
+/// The current config, serialized the same way as `config.json`.
+pub fn config_json() -> Option<String> {
+    let f = api().hachimi_get_config_json?;
+    read_host_string(|buf, len, out_len| unsafe { f(buf, len, out_len) })
+}
+
+/// Evaluates a localization template (e.g. `$(plural 2 'day' 'days')`) with Hachimi's filters.
+pub fn eval_template(input: &str) -> Option<String> {
+    let f = api().template_eval?;
+    let input = to_cstring(input);
+    read_host_string(|buf, len, out_len| unsafe { f(input.as_ptr(), buf, len, out_len) })
+}
+
+unsafe extern "C" fn config_changed_trampoline<F: FnMut() + 'static>(userdata: *mut c_void) {
+    let f = &mut *(userdata as *mut F);
+    f();
+}
+
+/// Calls `f` after Hachimi's config is saved or reloaded. The closure lives for the rest of the process.
+pub fn on_config_changed<F: FnMut() + 'static>(f: F) -> bool {
+    let Some(register) = api().hachimi_register_on_config_changed else { return false; };
+    let userdata = Box::into_raw(Box::new(f)) as *mut c_void;
+    unsafe { register(Some(config_changed_trampoline::<F>), userdata) }
+}
+
+unsafe extern "C" fn ipc_command_trampoline<F>(args_json: *const c_char, response: *mut c_void, userdata: *mut c_void) -> bool
+where
+    F: FnMut(&str) -> Result<String, String> + Send + 'static
+{
+    let f = &mut *(userdata as *mut F);
+    let args = CStr::from_ptr(args_json).to_string_lossy();
+    let (ok, body) = match f(&args) {
+        Ok(json) => (true, json),
+        Err(message) => (false, message)
+    };
+    if let Some(write) = api().ipc_response_write {
+        let body = to_cstring(&body);
+        write(response, body.as_ptr());
+    }
+    ok
+}
+
+/// Registers a command on Hachimi's IPC server, invoked with `{"type": "Plugin", "command": name, "args": ...}`.
+///
+/// `f` receives the `args` JSON and returns either the result as JSON or an error message.
+/// With `main_thread`, it runs on the game's main thread and the request waits for it to finish.
+pub fn register_ipc_command<F>(name: &str, main_thread: bool, f: F) -> bool
+where
+    F: FnMut(&str) -> Result<String, String> + Send + 'static
+{
+    let Some(register) = api().ipc_register_command else { return false; };
+    let name = to_cstring(name);
+    let userdata = Box::into_raw(Box::new(f)) as *mut c_void;
+    unsafe { register(name.as_ptr(), Some(ipc_command_trampoline::<F>), main_thread, userdata) }
+}
+
+/// Removes a command registered with [`register_ipc_command`].
+pub fn unregister_ipc_command(name: &str) -> bool {
+    let Some(unregister) = api().ipc_unregister_command else { return false; };
+    let name = to_cstring(name);
+    unsafe { unregister(name.as_ptr()) }
+}
@@ -0,0 +1,212 @@
+use std::ffi::c_void;
+
+use crate::{api, to_cstring};
+
+/// A borrowed `egui::Ui` handed to plugin callbacks by the host.
+pub struct Ui {
+    ptr: *mut c_void
+}
+
+pub(crate) extern "C" fn ui_trampoline<F: FnMut(&mut Ui)>(ui: *mut c_void, userdata: *mut c_void) {
+    let f = unsafe { &mut *(userdata as *mut F) };
+    f(&mut Ui { ptr: ui });
+}
+
+macro_rules! text_widget {
+    ($(#[$meta:meta])* $name:ident => $field:ident) => {
+        $(#[$meta])*
+        pub fn $name(&mut self, text: &str) -> bool {
+            let Some(f) = api().$field else { return false; };
+            let text = to_cstring(text);
+            unsafe { f(self.ptr, text.as_ptr()) }
+        }
+    };
+}
+
+impl Ui {
+    /// # Safety
+    /// `ptr` must be the `ui` pointer passed to a GUI callback, used only during that callback.
+    pub unsafe fn from_raw(ptr: *mut c_void) -> Ui {
+        Ui { ptr }
+    }
+
+    pub fn as_raw(&self) -> *mut c_void {
+        self.ptr
+    }
+
+    text_widget!(heading => gui_ui_heading);
+    text_widget!(label => gui_ui_label);
+    text_widget!(small => gui_ui_small);
+    text_widget!(
+        /// Returns true when clicked.
+        button => gui_ui_button
+    );
+    text_widget!(
+        /// Returns true when clicked.
+        small_button => gui_ui_small_button
+    );
+
+    pub fn separator(&mut self) {
+        if let Some(f) = api().gui_ui_separator {
+            unsafe { f(self.ptr); }
+        }
+    }
+
+    pub fn end_row(&mut self) {
+        if let Some(f) = api().gui_ui_end_row {
+            unsafe { f(self.ptr); }
+        }
+    }
+
+    pub fn colored_label(&mut self, rgba: [u8; 4], text: &str) -> bool {
+        let Some(f) = api().gui_ui_colored_label else { return false; };
+        let text = to_cstring(text);
+        unsafe { f(self.ptr, rgba[0], rgba[1], rgba[2], rgba[3], text.as_ptr()) }
+    }
+
+    /// Returns true when the value changed.
+    pub fn checkbox(&mut self, value: &mut bool, text: &str) -> bool {
+        let Some(f) = api().gui_ui_checkbox else { return false; };
+        let text = to_cstring(text);
+        unsafe { f(self.ptr, text.as_ptr(), value) }
+    }
+
+    /// Returns true when the value changed.
+    pub fn slider_f32(&mut self, value: &mut f32, min: f32, max: f32, text: &str) -> bool {
+        let Some(f) = api().gui_ui_slider_f32 else { return false; };
+        let text = to_cstring(text);
+        unsafe { f(self.ptr, text.as_ptr(), value, min, max) }
+    }
+
+    /// Returns true when the value changed.
+    pub fn slider_i32(&mut self, value: &mut i32, min: i32, max: i32, text: &str) -> bool {
+        let Some(f) = api().gui_ui_slider_i32 else { return false; };
+        let text = to_cstring(text);
+        unsafe { f(self.ptr, text.as_ptr(), value, min, max) }
+    }
+
+    /// Returns true when the value changed.
+    pub fn drag_value_f32(&mut self, value: &mut f32, speed: f32, min: f32, max: f32) -> bool {
+        let Some(f) = api().gui_ui_drag_value_f32 else { return false; };
+        unsafe { f(self.ptr, value, speed, min, max) }
+    }
+
+    /// Returns true when the value changed.
+    pub fn drag_value_i32(&mut self, value: &mut i32, speed: f32, min: i32, max: i32) -> bool {
+        let Some(f) = api().gui_ui_drag_value_i32 else { return false; };
+        unsafe { f(self.ptr, value, speed, min, max) }
+    }
+
+    /// An empty `text` shows the percentage instead.
+    pub fn progress_bar(&mut self, progress: f32, text: &str) {
+        if let Some(f) = api().gui_ui_progress_bar {
+            let text = to_cstring(text);
+            unsafe { f(self.ptr, progress, text.as_ptr()); }
+        }
+    }
+
+    /// Draws an image previously uploaded with [`set_image_rgba`]. A zero size uses the image's own size.
+    pub fn image(&mut self, id: &str, width: f32, height: f32) -> bool {
+        let Some(f) = api().gui_ui_image else { return false; };
+        let id = to_cstring(id);
+        unsafe { f(self.ptr, id.as_ptr(), width, height) }
+    }
+
+    pub fn horizontal(&mut self, mut add_contents: impl FnMut(&mut Ui)) -> bool {
+        let Some(f) = api().gui_ui_horizontal else { return false; };
+        unsafe { f(self.ptr, Some(trampoline_for(&add_contents)), &mut add_contents as *mut _ as *mut c_void) }
+    }
+
+    pub fn grid(&mut self, id: &str, columns: usize, spacing: [f32; 2], mut add_contents: impl FnMut(&mut Ui)) -> bool {
+        let Some(f) = api().gui_ui_grid else { return false; };
+        let id = to_cstring(id);
+        unsafe {
+            f(
+                self.ptr, id.as_ptr(), columns, spacing[0], spacing[1],
+                Some(trampoline_for(&add_contents)), &mut add_contents as *mut _ as *mut c_void
+            )
+        }
+    }
+
+    /// Returns true when the body is open.
+    pub fn collapsing(&mut self, title: &str, default_open: bool, mut add_contents: impl FnMut(&mut Ui)) -> bool {
+        let Some(f) = api().gui_ui_collapsing else { return false; };
+        let title = to_cstring(title);
+        unsafe {
+            f(
+                self.ptr, title.as_ptr(), default_open,
+                Some(trampoline_for(&add_contents)), &mut add_contents as *mut _ as *mut c_void
+            )
+        }
+    }
+
+    /// A `max_height` of zero lets the area grow to the available space.
+    pub fn scroll_area(&mut self, id: &str, max_height: f32, horizontal: bool, mut add_contents: impl FnMut(&mut Ui)) -> bool {
+        let Some(f) = api().gui_ui_scroll_area else { return false; };
+        let id = to_cstring(id);
+        unsafe {
+            f(
+                self.ptr, id.as_ptr(), max_height, horizontal,
+                Some(trampoline_for(&add_contents)), &mut add_contents as *mut _ as *mut c_void
+            )
+        }
+    }
+
+    /// Shows `text` while the pointer hovers anything drawn by `add_contents`. Returns true while hovered.
+    pub fn tooltip(&mut self, text: &str, mut add_contents: impl FnMut(&mut Ui)) -> bool {
+        let Some(f) = api().gui_ui_tooltip else { return false; };
+        let text = to_cstring(text);
+        unsafe {
+            f(
+                self.ptr, text.as_ptr(),
+                Some(trampoline_for(&add_contents)), &mut add_contents as *mut _ as *mut c_void
+            )
+        }
+    }
+
+    /// Calls `cell` once for every visible cell, with its row and column index.
+    pub fn table(
+        &mut self, id: &str, headers: &[&str], row_count: usize, row_height: f32,
+        mut cell: impl FnMut(&mut Ui, usize, usize)
+    ) -> bool {
+        let Some(f) = api().gui_ui_table else { return false; };
+        if headers.is_empty() { return false; }
+
+        let id = to_cstring(id);
+        let headers_c: Vec<_> = headers.iter().map(|h| to_cstring(h)).collect();
+        let header_ptrs: Vec<_> = headers_c.iter().map(|h| h.as_ptr()).collect();
+        unsafe {
+            f(
+                self.ptr, id.as_ptr(), header_ptrs.as_ptr(), headers.len(), row_count, row_height,
+                Some(cell_trampoline_for(&cell)), &mut cell as *mut _ as *mut c_void
+            )
+        }
+    }
+}
+
+fn trampoline_for<F: FnMut(&mut Ui)>(_: &F) -> extern "C" fn(*mut c_void, *mut c_void) {
+    ui_trampoline::<F>
+}
+
+extern "C" fn cell_trampoline<F: FnMut(&mut Ui, usize, usize)>(ui: *mut c_void, row: usize, column: usize, userdata: *mut c_void) {
+    let f = unsafe { &mut *(userdata as *mut F) };
+    f(&mut Ui { ptr: ui }, row, column);
+}
+
+fn cell_trampoline_for<F: FnMut(&mut Ui, usize, usize)>(_: &F) -> extern "C" fn(*mut c_void, usize, usize, *mut c_void) {
+    cell_trampoline::<F>
+}
+
+/// Uploads (or replaces) an RGBA8 image that [`Ui::image`] can draw by `id`.
+pub fn set_image_rgba(id: &str, rgba: &[u8], width: usize, height: usize) -> bool {
+    let Some(f) = api().gui_set_image_rgba else { return false; };
+    if rgba.len() < width * height * 4 { return false; }
+    let id = to_cstring(id);
+    unsafe { f(id.as_ptr(), rgba.as_ptr(), width, height) }
+}
+
+pub fn remove_image(id: &str) -> bool {
+    let Some(f) = api().gui_remove_image else { return false; };
+    let id = to_cstring(id);
+    unsafe { f(id.as_ptr()) }
+}
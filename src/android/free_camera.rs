@@ -0,0 +1,89 @@
+use std::sync::Mutex;
+
+use crate::core::{
+    free_camera::{self, FreeCameraAction},
+    Hachimi,
+};
+
+/// Pixels of two-finger travel that make up one move step.
+const PIXELS_PER_STEP: f32 = 20.0;
+/// Maximum travel in pixels for a two-finger touch to still count as a tap.
+const TAP_SLOP: f32 = 24.0;
+
+#[derive(Default)]
+struct TouchGesture {
+    pointer_count: usize,
+    max_pointers: usize,
+    travel: f32,
+    centroid: Option<(f32, f32)>,
+    span: f32,
+}
+
+static GESTURE: Mutex<TouchGesture> = Mutex::new(TouchGesture {
+    pointer_count: 0,
+    max_pointers: 0,
+    travel: 0.0,
+    centroid: None,
+    span: 0.0,
+});
+
+fn two_finger_metrics(pointers: &[(f32, f32)]) -> ((f32, f32), f32) {
+    let (ax, ay) = pointers[0];
+    let (bx, by) = pointers[1];
+    (((ax + bx) / 2.0, (ay + by) / 2.0), ((ax - bx).powi(2) + (ay - by).powi(2)).sqrt())
+}
+
+/// Feeds a touch event into the free camera. `pointers` holds the positions of every pointer
+/// still down after the event, in pixels.
+///
+/// One finger drags to look, two fingers drag to move and pinch to move forward/back, and a
+/// two-finger tap toggles live pause.
+pub fn on_touch(phase: egui::TouchPhase, pointers: &[(f32, f32)]) {
+    let config = Hachimi::instance().config.load();
+    let mut gesture = GESTURE.lock().unwrap();
+
+    if phase == egui::TouchPhase::Start && gesture.pointer_count == 0 {
+        *gesture = TouchGesture::default();
+    }
+
+    if pointers.len() != gesture.pointer_count {
+        gesture.pointer_count = pointers.len();
+        gesture.max_pointers = gesture.max_pointers.max(pointers.len());
+        free_camera::on_pointer_button(pointers.len() == 1);
+        gesture.centroid = None;
+    }
+
+    match pointers.len() {
+        1 => {
+            let (x, y) = pointers[0];
+            free_camera::on_pointer_move(x, y, config.free_camera.touch_look_speed / 100.0);
+        },
+        2 => {
+            let (centroid, span) = two_finger_metrics(pointers);
+            if let Some((last_x, last_y)) = gesture.centroid {
+                let dx = centroid.0 - last_x;
+                let dy = centroid.1 - last_y;
+                let dspan = span - gesture.span;
+                gesture.travel += dx.abs() + dy.abs() + dspan.abs();
+
+                let k = config.free_camera.touch_move_speed / PIXELS_PER_STEP;
+                free_camera::translate(dspan * k, -dx * k, dy * k);
+            }
+            gesture.centroid = Some(centroid);
+            gesture.span = span;
+        },
+        _ => (),
+    }
+
+    if matches!(phase, egui::TouchPhase::End | egui::TouchPhase::Cancel) && pointers.is_empty() {
+        if phase == egui::TouchPhase::End && gesture.max_pointers == 2 && gesture.travel < TAP_SLOP {
+            free_camera::trigger_action(FreeCameraAction::ToggleLivePause);
+        }
+        *gesture = TouchGesture::default();
+    }
+}
+
+/// Applies the free camera preset bound to an Android keycode. Returns whether a preset matched.
+pub fn apply_preset_hotkey(key_code: i32) -> bool {
+    u16::try_from(key_code).is_ok_and(free_camera::apply_preset_hotkey)
+}
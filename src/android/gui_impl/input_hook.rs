@@ -8,8 +8,8 @@ use jni::{
 };
 
 use crate::{
-    android::utils::{BACK_BUTTON_PRESSED, IS_IME_VISIBLE, get_activity, get_screen_dimensions},
-    core::{gui, Error, Gui, Hachimi},
+    android::{free_camera, utils::{BACK_BUTTON_PRESSED, IS_IME_VISIBLE, get_activity, get_screen_dimensions}},
+    core::{free_camera::is_game_input_capture_active, gui, live_utils, screenshot, Error, Gui, Hachimi},
     il2cpp::symbols::Thread
 };
 
@@ -18,6 +18,7 @@ use super::keymap;
 const ACTION_DOWN: jint = 0;
 const ACTION_UP: jint = 1;
 const ACTION_MOVE: jint = 2;
+const ACTION_CANCEL: jint = 3;
 const ACTION_POINTER_DOWN: jint = 5;
 const ACTION_POINTER_UP: jint = 6;
 const ACTION_HOVER_MOVE: jint = 7;
@@ -36,6 +37,8 @@ static VOLUME_UP_PRESSED: AtomicBool = AtomicBool::new(false);
 static VOLUME_DOWN_PRESSED: AtomicBool = AtomicBool::new(false);
 
 static POINTER_CAPTURED: AtomicBool = AtomicBool::new(false);
+/// Set while a touch gesture that started outside of the GUI is driving the free camera.
+static FREE_CAMERA_GESTURE: AtomicBool = AtomicBool::new(false);
 
 pub struct MultiTapState {
     pub count: AtomicUsize,
@@ -89,6 +92,7 @@ static SCREEN_HEIGHT: AtomicI32 = AtomicI32::new(0);
 
 type NativeInjectEventFn = extern "C" fn(env: JNIEnv, obj: JObject, input_event: JObject, extra_param: jint) -> jboolean;
 extern "C" fn nativeInjectEvent(mut env: JNIEnv, obj: JObject, input_event: JObject, extra_param: jint) -> jboolean {
+    hook_scope!(nativeInjectEvent);
     let action = env.call_method(&input_event, "getAction", "()I", &[])
         .unwrap()
         .i()
@@ -96,7 +100,9 @@ extern "C" fn nativeInjectEvent(mut env: JNIEnv, obj: JObject, input_event: JObj
     let action_masked = action & ACTION_MASK;
     let is_consuming = Gui::is_consuming_input_atomic();
 
-    if !is_consuming && (action_masked == ACTION_MOVE || action_masked == ACTION_HOVER_MOVE) {
+    if !is_consuming && !FREE_CAMERA_GESTURE.load(Ordering::Acquire) &&
+        (action_masked == ACTION_MOVE || action_masked == ACTION_HOVER_MOVE)
+    {
         return get_orig_fn!(nativeInjectEvent, NativeInjectEventFn)(env, obj, input_event, extra_param);
     }
 
@@ -149,6 +155,22 @@ extern "C" fn nativeInjectEvent(mut env: JNIEnv, obj: JObject, input_event: JObj
                     }
                 }
 
+                if pressed && repeat_count == 0 && free_camera::apply_preset_hotkey(key_code) {
+                    return JNI_TRUE;
+                }
+
+                if pressed && repeat_count == 0 && !Gui::wants_input_atomic() &&
+                    u16::try_from(key_code).is_ok_and(live_utils::on_hotkey)
+                {
+                    return JNI_TRUE;
+                }
+
+                if pressed && repeat_count == 0 && !Gui::wants_input_atomic() &&
+                    u16::try_from(key_code).is_ok_and(screenshot::on_hotkey)
+                {
+                    return JNI_TRUE;
+                }
+
                 if pressed && key_code == Hachimi::instance().config.load().android.menu_open_key {
                     let Some(mut gui) = Gui::instance().map(|m| m.lock().unwrap()) else {
                         return get_orig_fn!(nativeInjectEvent, NativeInjectEventFn)(env, obj, input_event, extra_param);
@@ -272,6 +294,32 @@ extern "C" fn nativeInjectEvent(mut env: JNIEnv, obj: JObject, input_event: JObj
             }
         }
 
+        if action_masked == ACTION_DOWN {
+            let over_gui = is_consuming && is_over_gui(unsafe { env.unsafe_clone() }, real_x, real_y);
+            FREE_CAMERA_GESTURE.store(!over_gui && is_game_input_capture_active(), Ordering::Release);
+        }
+
+        if FREE_CAMERA_GESTURE.load(Ordering::Acquire) {
+            let (phase, lifted) = match action_masked {
+                ACTION_DOWN | ACTION_POINTER_DOWN => (egui::TouchPhase::Start, None),
+                ACTION_UP | ACTION_POINTER_UP => (egui::TouchPhase::End, Some(pointer_index)),
+                ACTION_CANCEL => (egui::TouchPhase::Cancel, None),
+                _ => (egui::TouchPhase::Move, None)
+            };
+            let pointers = if phase == egui::TouchPhase::Cancel {
+                Vec::new()
+            }
+            else {
+                read_pointers(&mut env, &input_event, lifted)
+            };
+            free_camera::on_touch(phase, &pointers);
+            if matches!(action_masked, ACTION_UP | ACTION_CANCEL) {
+                FREE_CAMERA_GESTURE.store(false, Ordering::Release);
+            }
+            // Let the game see the touch too so its own buttons keep working, same as clicks on Windows
+            return get_orig_fn!(nativeInjectEvent, NativeInjectEventFn)(env, obj, input_event, extra_param);
+        }
+
         if !is_consuming {
             return get_orig_fn!(nativeInjectEvent, NativeInjectEventFn)(env, obj, input_event, extra_param);
         }
@@ -377,6 +425,29 @@ extern "C" fn nativeInjectEvent(mut env: JNIEnv, obj: JObject, input_event: JObj
     get_orig_fn!(nativeInjectEvent, NativeInjectEventFn)(env, obj, input_event, extra_param)
 }
 
+fn is_over_gui(env: JNIEnv, x: f32, y: f32) -> bool {
+    let Some(gui) = Gui::instance().map(|m| m.lock().unwrap()) else {
+        return false;
+    };
+    let ppp = get_ppp(env, &gui);
+    gui.context.layer_id_at(egui::Pos2 { x: x / ppp, y: y / ppp })
+        .is_some_and(|layer| layer.order != egui::Order::Background)
+}
+
+/// Reads the positions of every pointer in a MotionEvent, leaving out `skip` (a pointer that
+/// is being lifted).
+fn read_pointers(env: &mut JNIEnv, event: &JObject, skip: Option<jint>) -> Vec<(f32, f32)> {
+    let count = env.call_method(event, "getPointerCount", "()I", &[]).unwrap().i().unwrap();
+    (0..count)
+        .filter(|i| Some(*i) != skip)
+        .map(|i| {
+            let x = env.call_method(event, "getX", "(I)F", &[i.into()]).unwrap().f().unwrap();
+            let y = env.call_method(event, "getY", "(I)F", &[i.into()]).unwrap().f().unwrap();
+            (x, y)
+        })
+        .collect()
+}
+
 fn get_ppp(mut env: JNIEnv, gui: &Gui) -> f32 {
     // SAFETY: view doesn't live past the lifetime of this function
     let Some(view) = get_view(unsafe { env.unsafe_clone() }) else {
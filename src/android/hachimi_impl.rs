@@ -1,9 +1,12 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::core::Hachimi;
 
 use super::gui_impl::keymap;
 
+pub use super::plugin_loader::{load_plugin, unload_plugin, module_base};
+
 pub fn is_il2cpp_lib(filename: &str) -> bool {
     filename.ends_with("libil2cpp.so")
 }
@@ -15,7 +18,7 @@ pub fn is_criware_lib(filename: &str) -> bool {
 pub fn on_hooking_finished(_hachimi: &Hachimi) {
 }
 
-#[derive(Deserialize, Serialize, Clone)]
+#[derive(Deserialize, Serialize, Clone, JsonSchema)]
 pub struct Config {
     #[serde(default = "Config::default_menu_open_key")]
     pub menu_open_key: i32,
@@ -8,5 +8,6 @@ pub mod game_impl;
 pub mod log_impl;
 pub mod hachimi_impl;
 pub mod gui_impl;
+pub mod free_camera;
 pub mod symbols_impl;
 pub mod interceptor_impl;
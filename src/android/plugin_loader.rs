@@ -5,7 +5,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use crate::core::{plugin_api::Plugin, Hachimi};
+use crate::core::{plugin_api::{Plugin, PluginInit}, Hachimi};
 
 pub fn load_libraries() -> Vec<Plugin> {
     let mut plugins = Vec::new();
@@ -20,7 +20,7 @@ pub fn load_libraries() -> Vec<Plugin> {
                 if loaded.contains(&display) {
                     continue;
                 }
-                if let Some(plugin) = try_load_library(&display) {
+                if let Some(plugin) = load_plugin(&display) {
                     loaded.insert(display);
                     plugins.push(plugin);
                 }
@@ -33,7 +33,7 @@ pub fn load_libraries() -> Vec<Plugin> {
             if loaded.contains(name) {
                 continue;
             }
-            if let Some(plugin) = try_load_library(name) {
+            if let Some(plugin) = load_plugin(name) {
                 loaded.insert(name.clone());
                 plugins.push(plugin);
             }
@@ -43,7 +43,7 @@ pub fn load_libraries() -> Vec<Plugin> {
     plugins
 }
 
-fn try_load_library(name_or_path: &str) -> Option<Plugin> {
+pub fn load_plugin(name_or_path: &str) -> Option<Plugin> {
     let Ok(name_cstr) = CString::new(name_or_path) else {
         warn!("Invalid library name: {}", name_or_path);
         return None;
@@ -68,11 +68,11 @@ fn try_load_library(name_or_path: &str) -> Option<Plugin> {
     let init_enum = {
         let v3_addr = unsafe { libc::dlsym(handle, c"hachimi_init_v3".as_ptr()) };
         if !v3_addr.is_null() {
-            Some(crate::core::plugin_api::PluginInit::V3(unsafe { std::mem::transmute(v3_addr) }))
+            Some(PluginInit::V3(unsafe { std::mem::transmute(v3_addr) }))
         } else {
             let v2_addr = unsafe { libc::dlsym(handle, c"hachimi_init".as_ptr()) };
             if !v2_addr.is_null() {
-                Some(crate::core::plugin_api::PluginInit::V2(unsafe { std::mem::transmute(v2_addr) }))
+                Some(PluginInit::V2(unsafe { std::mem::transmute(v2_addr) }))
             } else {
                 None
             }
@@ -81,10 +81,18 @@ fn try_load_library(name_or_path: &str) -> Option<Plugin> {
 
     match init_enum {
         Some(init_fn) => {
+            let init_addr = match init_fn {
+                PluginInit::V2(f) => f as usize,
+                PluginInit::V3(f) => f as usize
+            };
+            let shutdown_addr = unsafe { libc::dlsym(handle, c"hachimi_shutdown".as_ptr()) };
             info!("Loaded library: {}", name_or_path);
             Some(Plugin {
                 name: name_or_path.to_string(),
                 init_fn,
+                shutdown_fn: if shutdown_addr.is_null() { None } else { Some(unsafe { std::mem::transmute(shutdown_addr) }) },
+                handle: handle as usize,
+                base: module_base(init_addr).unwrap_or(0)
             })
         }
         None => {
@@ -97,6 +105,21 @@ fn try_load_library(name_or_path: &str) -> Option<Plugin> {
     }
 }
 
+pub fn unload_plugin(plugin: &Plugin) {
+    if unsafe { libc::dlclose(plugin.handle as *mut libc::c_void) } != 0 {
+        warn!("Failed to unload library: {}", plugin.name);
+    }
+}
+
+/// Returns the base address of the shared object containing `addr`.
+pub fn module_base(addr: usize) -> Option<usize> {
+    let mut info: libc::Dl_info = unsafe { std::mem::zeroed() };
+    if unsafe { libc::dladdr(addr as *const libc::c_void, &mut info) } == 0 || info.dli_fbase.is_null() {
+        return None;
+    }
+    Some(info.dli_fbase as usize)
+}
+
 fn find_native_lib_dir() -> Option<PathBuf> {
     let maps = fs::read_to_string("/proc/self/maps").ok()?;
     for line in maps.lines() {
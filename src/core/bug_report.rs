@@ -0,0 +1,133 @@
+//! Bug report bundles: a zip with recent logs, the config with private values redacted and
+//! details about the install, so users don't have to dig these up themselves.
+
+use std::{fs, io::Write, path::PathBuf};
+
+use chrono::Local;
+use serde::Serialize;
+use serde_json::Value;
+use zip::{write::FileOptions, CompressionMethod, ZipWriter};
+
+use super::{hachimi::Config, log, tl_repo::LocalRepoInfo, Error, Hachimi};
+use crate::il2cpp::symbol_report;
+
+pub const REPORTS_DIR: &str = "bug_reports";
+const REDACTED: &str = "<redacted>";
+/// Fields that may hold URLs or paths with private info in them, values left at their defaults
+/// are kept.
+const REDACTED_SUFFIXES: &[&str] = &["_url", "_dir", "_path", "_index"];
+
+#[derive(Serialize)]
+struct ReportInfo {
+    hachimi_version: &'static str,
+    os: &'static str,
+    arch: &'static str,
+    package_name: String,
+    region: String,
+    created_at: String,
+    config_profile: Option<String>,
+    translation_repo: Option<TranslationRepoInfo>
+}
+
+#[derive(Serialize)]
+struct TranslationRepoInfo {
+    id: u32,
+    index: Option<String>,
+    name: Option<String>,
+    language: Option<String>,
+    maintainer: Option<String>
+}
+
+fn translation_repo_info(hachimi: &Hachimi, config: &Config) -> Option<TranslationRepoInfo> {
+    let id = config.selected_tl_repo_id?;
+    let index = hachimi.tl_repo_manager.lock().unwrap().find_by_id(id).map(|s| s.to_owned());
+    let local_info = LocalRepoInfo::load(id).ok().flatten();
+    let non_empty = |s: String| (!s.is_empty()).then_some(s);
+
+    Some(TranslationRepoInfo {
+        id,
+        index,
+        name: local_info.as_ref().and_then(|i| non_empty(i.name.clone())),
+        language: local_info.as_ref().and_then(|i| non_empty(i.language.clone())),
+        maintainer: local_info.as_ref().and_then(|i| non_empty(i.maintainer.clone()))
+    })
+}
+
+fn redact(value: &mut Value, defaults: Option<&Value>) {
+    let Value::Object(map) = value else {
+        return;
+    };
+    for (key, v) in map.iter_mut() {
+        let default = defaults.and_then(|d| d.get(key));
+        if v.is_object() {
+            redact(v, default);
+        }
+        else if REDACTED_SUFFIXES.iter().any(|suffix| key.ends_with(suffix)) && !v.is_null() && Some(&*v) != default {
+            *v = Value::String(REDACTED.to_owned());
+        }
+    }
+}
+
+fn redacted_config(config: &Config) -> Result<Value, Error> {
+    let mut value = serde_json::to_value(config)?;
+    let defaults = serde_json::to_value(Config::default())?;
+    redact(&mut value, Some(&defaults));
+    Ok(value)
+}
+
+fn recent_log() -> String {
+    let mut text = String::new();
+    for record in log::recent_records() {
+        text.push_str(&record.to_string());
+        text.push('\n');
+    }
+    text
+}
+
+/// Writes a bug report to the reports folder and returns its path.
+pub fn create() -> Result<PathBuf, Error> {
+    let hachimi = Hachimi::instance();
+    let config = hachimi.config.load();
+    let now = Local::now();
+
+    let info = ReportInfo {
+        hachimi_version: env!("HACHIMI_DISPLAY_VERSION"),
+        os: std::env::consts::OS,
+        arch: std::env::consts::ARCH,
+        package_name: hachimi.game.package_name.clone(),
+        region: hachimi.game.region.to_string(),
+        created_at: now.to_rfc3339(),
+        config_profile: config.config_profile.clone(),
+        translation_repo: translation_repo_info(&hachimi, &config)
+    };
+
+    let dir = hachimi.get_data_path(REPORTS_DIR);
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("bug_report_{}.zip", now.format("%Y%m%d_%H%M%S")));
+
+    let mut zip = ZipWriter::new(fs::File::create(&path)?);
+    let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    zip.start_file("info.json", options)?;
+    zip.write_all(serde_json::to_string_pretty(&info)?.as_bytes())?;
+
+    zip.start_file("config.json", options)?;
+    zip.write_all(serde_json::to_string_pretty(&redacted_config(&config)?)?.as_bytes())?;
+
+    zip.start_file(symbol_report::REPORT_FILENAME, options)?;
+    zip.write_all(serde_json::to_string_pretty(&symbol_report::report())?.as_bytes())?;
+
+    zip.start_file("recent.log", options)?;
+    zip.write_all(recent_log().as_bytes())?;
+
+    // The full log from this session, if file logging is on
+    if config.enable_file_logging {
+        if let Ok(contents) = fs::read(log::log_file_path()) {
+            zip.start_file("hachimi.log", options)?;
+            zip.write_all(&contents)?;
+        }
+    }
+
+    zip.finish()?;
+    Ok(path)
+}
@@ -0,0 +1,358 @@
+use std::{fs, path::{Path, PathBuf}};
+
+use serde::{Deserialize, Serialize};
+
+use super::Error;
+
+/// Keyframes closer than this (in seconds) are treated as the same keyframe.
+const KEYFRAME_EPSILON: f32 = 0.001;
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq)]
+pub struct CameraKeyframe {
+    /// Live playback time in seconds
+    pub time: f32,
+    pub position: [f32; 3],
+    /// Rotation quaternion as `[x, y, z, w]`, same order as Unity
+    pub rotation: [f32; 4],
+    pub fov: f32
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CameraSample {
+    pub position: [f32; 3],
+    pub rotation: [f32; 4],
+    pub fov: f32
+}
+
+/// Camera keyframes for a single live, sorted by time.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq)]
+pub struct CameraPath {
+    pub music_id: i32,
+    #[serde(default)]
+    pub keyframes: Vec<CameraKeyframe>
+}
+
+impl CameraPath {
+    pub fn new(music_id: i32) -> Self {
+        CameraPath { music_id, keyframes: Vec::new() }
+    }
+
+    /// Inserts a keyframe in time order, replacing any keyframe at the same time. Returns its index.
+    pub fn insert(&mut self, keyframe: CameraKeyframe) -> usize {
+        let index = self.keyframes.partition_point(|k| k.time < keyframe.time - KEYFRAME_EPSILON);
+        match self.keyframes.get_mut(index) {
+            Some(existing) if (existing.time - keyframe.time).abs() <= KEYFRAME_EPSILON => *existing = keyframe,
+            _ => self.keyframes.insert(index, keyframe)
+        }
+        index
+    }
+
+    /// Re-sorts the keyframes after their times were edited in place.
+    pub fn sort(&mut self) {
+        self.keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+    }
+
+    /// Interpolated camera at `time`. Positions follow a Catmull-Rom spline through the keyframes,
+    /// rotations are slerped and the FOV is linearly interpolated. Times outside the path are clamped.
+    pub fn sample(&self, time: f32) -> Option<CameraSample> {
+        let keys = &self.keyframes;
+        let first = keys.first()?;
+        let last = keys.last()?;
+        if keys.len() == 1 || time <= first.time {
+            return Some(first.into());
+        }
+        if time >= last.time {
+            return Some(last.into());
+        }
+
+        // keys[i] <= time < keys[i + 1]
+        let i = keys.partition_point(|k| k.time <= time) - 1;
+        let (k1, k2) = (&keys[i], &keys[i + 1]);
+        let duration = k2.time - k1.time;
+        if duration <= f32::EPSILON {
+            return Some(k2.into());
+        }
+        let t = (time - k1.time) / duration;
+
+        let m1 = self.tangent(i);
+        let m2 = self.tangent(i + 1);
+        let mut position = [0.0; 3];
+        for axis in 0..3 {
+            position[axis] = hermite(k1.position[axis], k2.position[axis], m1[axis] * duration, m2[axis] * duration, t);
+        }
+
+        Some(CameraSample {
+            position,
+            rotation: slerp(k1.rotation, k2.rotation, t),
+            fov: k1.fov + (k2.fov - k1.fov) * t
+        })
+    }
+
+    /// Velocity at keyframe `i` in units per second, using neighbouring keyframes (Catmull-Rom).
+    /// The first and last keyframes use one-sided differences.
+    fn tangent(&self, i: usize) -> [f32; 3] {
+        let keys = &self.keyframes;
+        let prev = &keys[i.saturating_sub(1)];
+        let next = &keys[(i + 1).min(keys.len() - 1)];
+        let dt = next.time - prev.time;
+        if dt <= f32::EPSILON {
+            return [0.0; 3];
+        }
+        std::array::from_fn(|axis| (next.position[axis] - prev.position[axis]) / dt)
+    }
+
+    pub fn duration(&self) -> f32 {
+        match (self.keyframes.first(), self.keyframes.last()) {
+            (Some(first), Some(last)) => last.time - first.time,
+            _ => 0.0
+        }
+    }
+
+    pub fn file_path(dir: &Path, music_id: i32) -> PathBuf {
+        dir.join(format!("{}.json", music_id))
+    }
+
+    /// Loads the path for `music_id` from `dir`, or None if there isn't one.
+    pub fn load(dir: &Path, music_id: i32) -> Result<Option<Self>, Error> {
+        let path = Self::file_path(dir, music_id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let mut camera_path = Self::from_json(&fs::read_to_string(path)?)?;
+        camera_path.music_id = music_id;
+        Ok(Some(camera_path))
+    }
+
+    pub fn save(&self, dir: &Path) -> Result<PathBuf, Error> {
+        fs::create_dir_all(dir)?;
+        let path = Self::file_path(dir, self.music_id);
+        fs::write(&path, self.to_json()?)?;
+        Ok(path)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let mut camera_path: CameraPath = serde_json::from_str(json)?;
+        camera_path.sort();
+        Ok(camera_path)
+    }
+
+    pub fn to_json(&self) -> Result<String, Error> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+impl From<&CameraKeyframe> for CameraSample {
+    fn from(keyframe: &CameraKeyframe) -> Self {
+        CameraSample {
+            position: keyframe.position,
+            rotation: keyframe.rotation,
+            fov: keyframe.fov
+        }
+    }
+}
+
+/// Cubic Hermite interpolation between `p1` and `p2` with tangents `m1` and `m2`.
+fn hermite(p1: f32, p2: f32, m1: f32, m2: f32, t: f32) -> f32 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    (2.0 * t3 - 3.0 * t2 + 1.0) * p1 +
+        (t3 - 2.0 * t2 + t) * m1 +
+        (-2.0 * t3 + 3.0 * t2) * p2 +
+        (t3 - t2) * m2
+}
+
+fn quat_dot(a: [f32; 4], b: [f32; 4]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3]
+}
+
+fn quat_normalized(q: [f32; 4]) -> [f32; 4] {
+    let len = quat_dot(q, q).sqrt();
+    if len <= f32::EPSILON {
+        return [0.0, 0.0, 0.0, 1.0];
+    }
+    q.map(|v| v / len)
+}
+
+/// Shortest-path spherical interpolation between two `[x, y, z, w]` quaternions.
+pub fn slerp(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    let a = quat_normalized(a);
+    let mut b = quat_normalized(b);
+    let mut dot = quat_dot(a, b);
+    if dot < 0.0 {
+        dot = -dot;
+        b = b.map(|v| -v);
+    }
+
+    // Nearly parallel, fall back to nlerp to avoid dividing by ~0
+    if dot > 0.9995 {
+        return quat_normalized(std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t));
+    }
+
+    let angle = dot.clamp(-1.0, 1.0).acos();
+    let sin_angle = angle.sin();
+    let wa = ((1.0 - t) * angle).sin() / sin_angle;
+    let wb = (t * angle).sin() / sin_angle;
+    quat_normalized(std::array::from_fn(|i| a[i] * wa + b[i] * wb))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IDENTITY: [f32; 4] = [0.0, 0.0, 0.0, 1.0];
+
+    fn keyframe(time: f32, position: [f32; 3], rotation: [f32; 4], fov: f32) -> CameraKeyframe {
+        CameraKeyframe { time, position, rotation, fov }
+    }
+
+    fn yaw(degrees: f32) -> [f32; 4] {
+        let half = degrees.to_radians() * 0.5;
+        [0.0, half.sin(), 0.0, half.cos()]
+    }
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{} != {}", a, b);
+    }
+
+    fn assert_quat_close(a: [f32; 4], b: [f32; 4]) {
+        // q and -q are the same rotation
+        assert!(quat_dot(a, b).abs() > 1.0 - 1e-5, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn empty_path_has_no_samples() {
+        assert_eq!(CameraPath::new(1001).sample(1.0), None);
+    }
+
+    #[test]
+    fn sample_clamps_to_ends() {
+        let mut path = CameraPath::new(1001);
+        path.insert(keyframe(1.0, [1.0, 2.0, 3.0], IDENTITY, 40.0));
+        path.insert(keyframe(3.0, [5.0, 2.0, 3.0], yaw(90.0), 60.0));
+
+        assert_eq!(path.sample(0.0).unwrap().position, [1.0, 2.0, 3.0]);
+        assert_eq!(path.sample(10.0).unwrap().position, [5.0, 2.0, 3.0]);
+        assert_eq!(path.sample(10.0).unwrap().fov, 60.0);
+    }
+
+    #[test]
+    fn sample_passes_through_keyframes() {
+        let mut path = CameraPath::new(1001);
+        path.insert(keyframe(0.0, [0.0, 0.0, 0.0], IDENTITY, 60.0));
+        path.insert(keyframe(1.0, [1.0, 3.0, 0.0], yaw(45.0), 50.0));
+        path.insert(keyframe(2.5, [4.0, 1.0, -2.0], yaw(120.0), 30.0));
+        path.insert(keyframe(4.0, [2.0, 0.0, 1.0], yaw(-30.0), 45.0));
+
+        for key in path.keyframes.clone() {
+            let sample = path.sample(key.time).unwrap();
+            for axis in 0..3 {
+                assert_close(sample.position[axis], key.position[axis]);
+            }
+            assert_quat_close(sample.rotation, key.rotation);
+            assert_close(sample.fov, key.fov);
+        }
+    }
+
+    #[test]
+    fn two_keyframes_interpolate_linearly() {
+        // With only two keyframes both tangents equal the segment's slope, so the spline is a line
+        let mut path = CameraPath::new(1001);
+        path.insert(keyframe(0.0, [0.0, 0.0, 0.0], IDENTITY, 40.0));
+        path.insert(keyframe(2.0, [4.0, -2.0, 8.0], IDENTITY, 60.0));
+
+        let sample = path.sample(0.5).unwrap();
+        assert_close(sample.position[0], 1.0);
+        assert_close(sample.position[1], -0.5);
+        assert_close(sample.position[2], 2.0);
+        assert_close(sample.fov, 45.0);
+    }
+
+    #[test]
+    fn spline_is_smooth_through_keyframes() {
+        let mut path = CameraPath::new(1001);
+        path.insert(keyframe(0.0, [0.0, 0.0, 0.0], IDENTITY, 60.0));
+        path.insert(keyframe(1.0, [1.0, 1.0, 0.0], IDENTITY, 60.0));
+        path.insert(keyframe(2.0, [2.0, 0.0, 0.0], IDENTITY, 60.0));
+
+        // Velocity just before and just after the middle keyframe should match
+        let h = 1e-3;
+        let before = (path.sample(1.0).unwrap().position[1] - path.sample(1.0 - h).unwrap().position[1]) / h;
+        let after = (path.sample(1.0 + h).unwrap().position[1] - path.sample(1.0).unwrap().position[1]) / h;
+        assert!((before - after).abs() < 0.05, "{} vs {}", before, after);
+
+        // The peak is at the middle keyframe, not a corner
+        assert!(path.sample(0.9).unwrap().position[1] < 1.0);
+        assert!(path.sample(1.1).unwrap().position[1] < 1.0);
+    }
+
+    #[test]
+    fn slerp_halfway() {
+        assert_quat_close(slerp(IDENTITY, yaw(90.0), 0.5), yaw(45.0));
+        assert_quat_close(slerp(yaw(-60.0), yaw(60.0), 0.25), yaw(-30.0));
+    }
+
+    #[test]
+    fn slerp_takes_shortest_path() {
+        // -q is the same rotation as q; interpolating towards it must not spin around
+        let target = yaw(90.0).map(|v| -v);
+        assert_quat_close(slerp(IDENTITY, target, 0.5), yaw(45.0));
+    }
+
+    #[test]
+    fn slerp_output_is_normalized() {
+        let q = slerp([0.0, 0.0, 0.0, 2.0], yaw(170.0), 0.3);
+        assert_close(quat_dot(q, q), 1.0);
+    }
+
+    #[test]
+    fn insert_keeps_order_and_replaces_same_time() {
+        let mut path = CameraPath::new(1001);
+        path.insert(keyframe(2.0, [2.0; 3], IDENTITY, 60.0));
+        path.insert(keyframe(0.5, [0.5; 3], IDENTITY, 60.0));
+        assert_eq!(path.insert(keyframe(1.0, [1.0; 3], IDENTITY, 60.0)), 1);
+        path.insert(keyframe(2.0, [9.0; 3], IDENTITY, 30.0));
+
+        let times: Vec<f32> = path.keyframes.iter().map(|k| k.time).collect();
+        assert_eq!(times, vec![0.5, 1.0, 2.0]);
+        assert_eq!(path.keyframes[2].position, [9.0; 3]);
+        assert_close(path.duration(), 1.5);
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let mut path = CameraPath::new(1001);
+        path.insert(keyframe(0.0, [0.1, 0.2, 0.3], IDENTITY, 60.0));
+        path.insert(keyframe(1.5, [1.0, 2.0, 3.0], yaw(30.0), 35.5));
+
+        let json = path.to_json().unwrap();
+        assert_eq!(CameraPath::from_json(&json).unwrap(), path);
+    }
+
+    #[test]
+    fn from_json_sorts_keyframes() {
+        let json = r#"{
+            "music_id": 1001,
+            "keyframes": [
+                { "time": 2.0, "position": [0, 0, 0], "rotation": [0, 0, 0, 1], "fov": 60 },
+                { "time": 1.0, "position": [0, 0, 0], "rotation": [0, 0, 0, 1], "fov": 60 }
+            ]
+        }"#;
+        let path = CameraPath::from_json(json).unwrap();
+        assert_eq!(path.keyframes[0].time, 1.0);
+        assert_eq!(path.keyframes[1].time, 2.0);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let dir = std::env::temp_dir().join(format!("hachimi_camera_path_test_{}", std::process::id()));
+        let mut path = CameraPath::new(1042);
+        path.insert(keyframe(3.0, [1.0, 2.0, 3.0], yaw(10.0), 50.0));
+
+        let file = path.save(&dir).unwrap();
+        assert_eq!(file, dir.join("1042.json"));
+        assert_eq!(CameraPath::load(&dir, 1042).unwrap(), Some(path));
+        assert_eq!(CameraPath::load(&dir, 1043).unwrap(), None);
+
+        _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -1,9 +1,13 @@
 use std::{
+    collections::VecDeque,
+    fmt::Write,
+    path::PathBuf,
     ptr::null_mut,
-    sync::Mutex
+    sync::Mutex,
+    time::{Instant, SystemTime, UNIX_EPOCH}
 };
 use crate::{
-    core::Hachimi,
+    core::{hachimi::CaptionConfig, Error, Hachimi},
     il2cpp::{
         ext::{Il2CppStringExt, StringExt},
         hook::{
@@ -17,6 +21,8 @@ use crate::{
     }
 };
 use once_cell::sync::Lazy;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 #[repr(C)]
 #[derive(Copy, Clone, Eq, PartialEq)]
@@ -30,6 +36,7 @@ pub struct CueInfo {
 pub struct CaptionData {
     pub text: String,
     pub cue_sheet: String,
+    pub cue_name: String,
     pub cue_id: i32,
     pub character_id: i32,
     pub voice_id: i32,
@@ -37,6 +44,189 @@ pub struct CaptionData {
 
 pub static CAPTION_REQUEST: Lazy<Mutex<Option<CaptionData>>> = Lazy::new(|| Mutex::new(None));
 
+/// A single id or an inclusive `[min, max]` range.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, JsonSchema)]
+#[serde(untagged)]
+pub enum IdMatch {
+    Single(i32),
+    Range(i32, i32)
+}
+
+impl IdMatch {
+    fn matches(&self, id: i32) -> bool {
+        match *self {
+            IdMatch::Single(v) => id == v,
+            IdMatch::Range(min, max) => id >= min && id <= max
+        }
+    }
+
+    fn any(list: &[IdMatch], id: i32) -> bool {
+        list.is_empty() || list.iter().any(|m| m.matches(id))
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptionRuleAction {
+    Allow,
+    Deny
+}
+
+/// Decides whether a voice line gets a caption. Rules are checked in order and the first one whose
+/// conditions all match wins; empty conditions match anything. Lines that match no rule are captioned.
+#[derive(Deserialize, Serialize, Clone, Debug, JsonSchema)]
+pub struct CaptionRule {
+    pub action: CaptionRuleAction,
+    /// Lets users override the action from the config editor (see `CaptionConfig::caption_categories`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Substring of the cue sheet name, e.g. `_home_`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cue_sheet: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cue_ids: Vec<IdMatch>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub voice_ids: Vec<IdMatch>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chara_ids: Vec<IdMatch>,
+    /// Current view id of the SceneManager.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub view_ids: Vec<IdMatch>
+}
+
+impl CaptionRule {
+    fn deny(category: Option<&str>, cue_sheet: Option<&str>) -> Self {
+        CaptionRule {
+            action: CaptionRuleAction::Deny,
+            category: category.map(str::to_owned),
+            cue_sheet: cue_sheet.map(str::to_owned),
+            cue_ids: Vec::new(),
+            voice_ids: Vec::new(),
+            chara_ids: Vec::new(),
+            view_ids: Vec::new()
+        }
+    }
+
+    fn matches(&self, line: &VoiceLine, view_id: &mut impl FnMut() -> Option<i32>) -> bool {
+        if let Some(pattern) = &self.cue_sheet {
+            if !line.cue_name.contains(pattern.as_str()) {
+                return false;
+            }
+        }
+        if !IdMatch::any(&self.cue_ids, line.cue_id) ||
+            !IdMatch::any(&self.voice_ids, line.voice_id) ||
+            !IdMatch::any(&self.chara_ids, line.chara_id)
+        {
+            return false;
+        }
+        // Only query the scene when a rule actually depends on it
+        if !self.view_ids.is_empty() {
+            let Some(view_id) = view_id() else { return false; };
+            if !IdMatch::any(&self.view_ids, view_id) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+struct VoiceLine<'a> {
+    cue_name: &'a str,
+    cue_id: i32,
+    voice_id: i32,
+    chara_id: i32
+}
+
+/// Built-in rules, used when neither the config nor the translation pack provides any.
+pub static DEFAULT_CAPTION_RULES: Lazy<Vec<CaptionRule>> = Lazy::new(|| {
+    let training_cues = vec![IdMatch::Range(0, 28), IdMatch::Single(39)];
+    vec![
+        CaptionRule::deny(Some("home"), Some("_home_")),
+        CaptionRule::deny(Some("title_call"), Some("_tc_")),
+        CaptionRule::deny(Some("title_call"), Some("_title_")),
+        CaptionRule::deny(None, Some("_kakao_")),
+        CaptionRule::deny(Some("gacha"), Some("_gacha_")),
+        CaptionRule { voice_ids: vec![IdMatch::Single(95001)], ..CaptionRule::deny(None, None) },
+        // Training lines that are already shown in a speech bubble
+        CaptionRule {
+            action: CaptionRuleAction::Allow,
+            cue_ids: vec![IdMatch::Single(8), IdMatch::Single(9), IdMatch::Single(12), IdMatch::Single(13)],
+            voice_ids: vec![IdMatch::Single(20025)],
+            chara_ids: vec![IdMatch::Range(i32::MIN, 8999)],
+            view_ids: vec![IdMatch::Single(5901)],
+            ..CaptionRule::deny(None, Some("_training_"))
+        },
+        CaptionRule {
+            cue_ids: training_cues.clone(),
+            voice_ids: vec![IdMatch::Range(2030, 2037), IdMatch::Range(93000, i32::MAX)],
+            ..CaptionRule::deny(Some("training"), Some("_training_"))
+        },
+        CaptionRule {
+            cue_ids: vec![IdMatch::Single(8), IdMatch::Single(9), IdMatch::Single(12), IdMatch::Single(13)],
+            ..CaptionRule::deny(Some("training"), Some("_training_"))
+        },
+        // Non-character voices (announcers etc.)
+        CaptionRule {
+            action: CaptionRuleAction::Allow,
+            chara_ids: vec![IdMatch::Range(9000, i32::MAX)],
+            voice_ids: vec![IdMatch::Single(95005), IdMatch::Single(95006), IdMatch::Single(70000)],
+            ..CaptionRule::deny(None, None)
+        },
+        CaptionRule { chara_ids: vec![IdMatch::Range(9000, i32::MAX)], ..CaptionRule::deny(None, None) }
+    ]
+});
+
+/// Rules from the config take priority over the translation pack's, which take priority over the defaults.
+fn with_caption_rules<R>(f: impl FnOnce(&[CaptionRule]) -> R) -> R {
+    let hachimi = Hachimi::instance();
+    let config = hachimi.config.load();
+    if let Some(rules) = &config.caption.caption_rules {
+        return f(rules);
+    }
+    let localized_data = hachimi.localized_data.load();
+    if let Some(rules) = &localized_data.caption_rules {
+        return f(rules);
+    }
+    f(&DEFAULT_CAPTION_RULES)
+}
+
+/// Categories used by the active rule set, with whether their lines are captioned by default.
+pub fn caption_rule_categories() -> Vec<(String, bool)> {
+    with_caption_rules(|rules| {
+        let mut categories: Vec<(String, bool)> = Vec::new();
+        for rule in rules {
+            let Some(category) = &rule.category else { continue; };
+            if !categories.iter().any(|(name, _)| name == category) {
+                categories.push((category.clone(), rule.action == CaptionRuleAction::Allow));
+            }
+        }
+        categories
+    })
+}
+
+fn is_caption_allowed(line: &VoiceLine) -> bool {
+    let config = Hachimi::instance().config.load();
+    let mut view_id_cache = None;
+    let mut view_id = || *view_id_cache.get_or_insert_with(|| {
+        let scene_manager = SceneManager::instance();
+        if scene_manager.is_null() { None } else { Some(SceneManager::GetCurrentViewId(scene_manager)) }
+    });
+
+    with_caption_rules(|rules| {
+        for rule in rules {
+            if !rule.matches(line, &mut view_id) {
+                continue;
+            }
+            let action = rule.category.as_ref()
+                .and_then(|c| config.caption.caption_categories.get(c))
+                .map(|&show| if show { CaptionRuleAction::Allow } else { CaptionRuleAction::Deny })
+                .unwrap_or(rule.action);
+            return action == CaptionRuleAction::Allow;
+        }
+        true
+    })
+}
+
 fn lookup_caption(chara_id: i32, cue_id: i32, cue_name: &str) -> Option<CaptionData> {
     let list = MasterCharacterSystemText::GetByCharaId(chara_id);
     if list.is_null() { return None; }
@@ -59,34 +249,16 @@ fn lookup_caption(chara_id: i32, cue_id: i32, cue_name: &str) -> Option<CaptionD
                     let orig_text = unsafe { (*text_ptr).as_utf16str().to_string() };
                     let clean_text = orig_text.replace("\n\n", " ").replace("\n", " ");
 
-                    if !cue_name.contains("_home_") && !cue_name.contains("_tc_") &&
-                        !cue_name.contains("_title_") && !cue_name.contains("_kakao_") &&
-                        !cue_name.contains("_gacha_") && voice_id != 95001 &&
-                        (chara_id < 9000 || voice_id == 95005 || voice_id == 95006 || voice_id == 70000)
-                    {
-                        let mut valid = true;
-                        if cue_name.contains("_training_") && (item_cue_id < 29 || item_cue_id == 39) {
-                            if !((voice_id >= 2030 && voice_id <= 2037) || voice_id >= 93000 || [8, 9, 12, 13].contains(&item_cue_id)) {
-                            } else if voice_id == 20025 {
-                                let scene_manager = SceneManager::instance();
-                                if scene_manager.is_null() { break; }
-                                if SceneManager::GetCurrentViewId(scene_manager) != 5901 {
-                                    valid = false;
-                                }
-                            } else {
-                                valid = false;
-                            }
-                        }
-
-                        if valid {
-                            return Some(CaptionData {
-                                text: clean_text,
-                                cue_sheet: item_cue_sheet,
-                                cue_id: item_cue_id,
-                                character_id: chara_id,
-                                voice_id,
-                            });
-                        }
+                    let line = VoiceLine { cue_name, cue_id: item_cue_id, voice_id, chara_id };
+                    if is_caption_allowed(&line) {
+                        return Some(CaptionData {
+                            text: clean_text,
+                            cue_sheet: item_cue_sheet,
+                            cue_name: String::new(),
+                            cue_id: item_cue_id,
+                            character_id: chara_id,
+                            voice_id,
+                        });
                     }
                     break;
                 }
@@ -106,10 +278,11 @@ pub fn process_caption_request() {
     };
     let Some(caption_data) = request else { return; };
 
-    let final_data = match lookup_caption(caption_data.character_id, caption_data.cue_id, &caption_data.cue_sheet) {
+    let mut final_data = match lookup_caption(caption_data.character_id, caption_data.cue_id, &caption_data.cue_sheet) {
         Some(c) => c,
         None => return,
     };
+    final_data.cue_name = caption_data.cue_name;
 
     let am = AudioManager::instance();
     let length = if !am.is_null() {
@@ -153,20 +326,270 @@ pub fn process_caption_request() {
         .cloned()
         .unwrap_or_else(|| final_data.text.clone());
 
+    push_history(CaptionHistoryEntry::new(&final_data, localized_text.clone(), length));
+    display_caption(&localized_text, length, final_data.character_id);
+}
+
+/// Style fields that override the base caption options; unset fields are left as they are.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct CaptionStyle {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_size: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outline_size: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outline_color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bg_alpha: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pos_x: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pos_y: Option<f32>
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CaptionStylePreset {
+    pub name: String,
+    #[serde(flatten)]
+    pub style: CaptionStyle
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct CaptionCharacterStyle {
+    /// Name of a preset from `CaptionConfig::caption_styles`, applied before the fields below.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preset: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outline_color: Option<String>,
+    /// Overrides `CaptionConfig::caption_show_speaker_name` for this character.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_name: Option<bool>
+}
+
+struct ResolvedCaptionStyle {
+    font_size: i32,
+    color: String,
+    outline_size: String,
+    outline_color: String,
+    bg_alpha: f32,
+    pos_x: f32,
+    pos_y: f32,
+    speaker_name: Option<String>
+}
+
+impl ResolvedCaptionStyle {
+    fn apply(&mut self, style: &CaptionStyle) {
+        if let Some(v) = style.font_size { self.font_size = v; }
+        if let Some(v) = &style.color { self.color.clone_from(v); }
+        if let Some(v) = &style.outline_size { self.outline_size.clone_from(v); }
+        if let Some(v) = &style.outline_color { self.outline_color.clone_from(v); }
+        if let Some(v) = style.bg_alpha { self.bg_alpha = v; }
+        if let Some(v) = style.pos_x { self.pos_x = v; }
+        if let Some(v) = style.pos_y { self.pos_y = v; }
+    }
+
+    fn apply_preset(&mut self, config: &CaptionConfig, name: &str) {
+        if let Some(preset) = config.caption_styles.iter().find(|p| p.name == name) {
+            self.apply(&preset.style);
+        }
+    }
+
+    /// Base options, then the active preset, then the character's preset and overrides.
+    fn resolve(config: &CaptionConfig, chara_id: i32) -> Self {
+        let mut style = ResolvedCaptionStyle {
+            font_size: config.caption_font_size,
+            color: config.caption_color.clone(),
+            outline_size: config.caption_outline_size.clone(),
+            outline_color: config.caption_outline_color.clone(),
+            bg_alpha: config.caption_bg_alpha,
+            pos_x: config.caption_pos_x,
+            pos_y: config.caption_pos_y,
+            speaker_name: None
+        };
+        if let Some(name) = &config.caption_style {
+            style.apply_preset(config, name);
+        }
+
+        let chara_style = config.caption_character_styles.get(&chara_id);
+        if let Some(chara_style) = chara_style {
+            if let Some(name) = &chara_style.preset {
+                style.apply_preset(config, name);
+            }
+            if let Some(v) = &chara_style.color { style.color.clone_from(v); }
+            if let Some(v) = &chara_style.outline_color { style.outline_color.clone_from(v); }
+        }
+
+        let show_name = chara_style.and_then(|s| s.show_name).unwrap_or(config.caption_show_speaker_name);
+        if show_name {
+            let chara_data = Hachimi::instance().chara_data.load();
+            if chara_data.exists(chara_id) {
+                style.speaker_name = Some(chara_data.get_name(chara_id));
+            }
+        }
+        style
+    }
+}
+
+fn display_caption(text: &str, length: f32, chara_id: i32) {
+    let config = Hachimi::instance().config.load();
+    display_caption_with_config(&config.caption, text, length, chara_id);
+}
+
+fn display_caption_with_config(config: &CaptionConfig, text: &str, length: f32, chara_id: i32) {
     Captions::init();
     Captions::set_display_time(length);
 
-    let config = Hachimi::instance().config.load();
+    let style = ResolvedCaptionStyle::resolve(config, chara_id);
     Captions::set_format(
-        config.caption.caption_font_size,
-        &config.caption.caption_color,
-        &config.caption.caption_outline_size,
-        &config.caption.caption_outline_color,
-        config.caption.caption_pos_x,
-        config.caption.caption_pos_y,
-        config.caption.caption_bg_alpha,
+        style.font_size,
+        &style.color,
+        &style.outline_size,
+        &style.outline_color,
+        style.pos_x,
+        style.pos_y,
+        style.bg_alpha,
     );
-    Captions::show(&localized_text, config.caption.caption_lines_char_count);
+    match style.speaker_name {
+        Some(name) => Captions::show(&format!("{}: {}", name, text), config.caption_lines_char_count),
+        None => Captions::show(text, config.caption_lines_char_count)
+    }
+}
+
+static PREVIEW_REQUEST: Mutex<Option<(CaptionConfig, i32, String)>> = Mutex::new(None);
+
+/// Shows `text` as a caption from `chara_id` using unsaved caption options.
+pub fn preview_caption(config: CaptionConfig, chara_id: i32, text: String) {
+    let already_scheduled = PREVIEW_REQUEST.lock().unwrap().replace((config, chara_id, text)).is_some();
+    // Only the latest request matters while dragging sliders
+    if already_scheduled {
+        return;
+    }
+    Thread::main_thread().schedule(|| {
+        let Some((config, chara_id, text)) = PREVIEW_REQUEST.lock().unwrap().take() else { return; };
+        display_caption_with_config(&config, &text, 3.0, chara_id);
+    });
+}
+
+const CAPTION_HISTORY_LEN: usize = 500;
+
+#[derive(Clone, Serialize)]
+pub struct CaptionHistoryEntry {
+    pub character_id: i32,
+    pub voice_id: i32,
+    pub cue_sheet: String,
+    pub cue_name: String,
+    pub cue_id: i32,
+    pub original_text: String,
+    pub text: String,
+    /// Unix time in milliseconds
+    pub timestamp: u64,
+    /// Seconds since the first caption of the session
+    pub session_time: f32,
+    pub length: f32
+}
+
+impl CaptionHistoryEntry {
+    fn new(data: &CaptionData, text: String, length: f32) -> Self {
+        CaptionHistoryEntry {
+            character_id: data.character_id,
+            voice_id: data.voice_id,
+            cue_sheet: data.cue_sheet.clone(),
+            cue_name: data.cue_name.clone(),
+            cue_id: data.cue_id,
+            original_text: data.text.clone(),
+            text,
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or_default(),
+            session_time: SESSION_START.elapsed().as_secs_f32(),
+            length
+        }
+    }
+}
+
+static SESSION_START: Lazy<Instant> = Lazy::new(Instant::now);
+static CAPTION_HISTORY: Lazy<Mutex<VecDeque<CaptionHistoryEntry>>> = Lazy::new(|| Mutex::new(VecDeque::new()));
+
+fn push_history(entry: CaptionHistoryEntry) {
+    let mut history = CAPTION_HISTORY.lock().unwrap();
+    if history.len() == CAPTION_HISTORY_LEN {
+        history.pop_front();
+    }
+    history.push_back(entry);
+}
+
+/// Recent captions, oldest first.
+pub fn caption_history() -> Vec<CaptionHistoryEntry> {
+    CAPTION_HISTORY.lock().unwrap().iter().cloned().collect()
+}
+
+pub fn clear_caption_history() {
+    CAPTION_HISTORY.lock().unwrap().clear();
+}
+
+static REPLAY_REQUEST: Mutex<Option<CaptionHistoryEntry>> = Mutex::new(None);
+
+/// Plays the entry's voice cue again and shows its caption.
+pub fn replay_caption(entry: CaptionHistoryEntry) {
+    *REPLAY_REQUEST.lock().unwrap() = Some(entry);
+    Thread::main_thread().schedule(|| {
+        let Some(entry) = REPLAY_REQUEST.lock().unwrap().take() else { return; };
+        if !AudioManager::play_voice(&entry.cue_sheet, &entry.cue_name, entry.cue_id) {
+            warn!("[captions] Failed to replay {} ({})", entry.cue_sheet, entry.cue_id);
+        }
+        display_caption(&entry.text, entry.length, entry.character_id);
+    });
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CaptionExportFormat {
+    Json,
+    Srt
+}
+
+impl CaptionExportFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            CaptionExportFormat::Json => "json",
+            CaptionExportFormat::Srt => "srt"
+        }
+    }
+}
+
+fn srt_timestamp(secs: f32) -> String {
+    let ms = (secs.max(0.0) * 1000.0).round() as u64;
+    format!("{:02}:{:02}:{:02},{:03}", ms / 3_600_000, ms / 60_000 % 60, ms / 1000 % 60, ms % 1000)
+}
+
+fn history_to_srt(entries: &[CaptionHistoryEntry]) -> String {
+    let mut out = String::new();
+    for (i, entry) in entries.iter().enumerate() {
+        _ = write!(out, "{}\n{} --> {}\n{}\n\n",
+            i + 1,
+            srt_timestamp(entry.session_time),
+            srt_timestamp(entry.session_time + entry.length),
+            entry.text
+        );
+    }
+    out
+}
+
+/// Writes the caption history to the `captions` folder in the data dir and returns the file's path.
+pub fn export_caption_history(format: CaptionExportFormat) -> Result<PathBuf, Error> {
+    let entries = caption_history();
+    let contents = match format {
+        CaptionExportFormat::Json => serde_json::to_string_pretty(&entries)?,
+        CaptionExportFormat::Srt => history_to_srt(&entries)
+    };
+
+    let dir = Hachimi::instance().get_data_path("captions");
+    std::fs::create_dir_all(&dir)?;
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+    let path = dir.join(format!("captions_{}.{}", timestamp, format.extension()));
+    std::fs::write(&path, contents)?;
+    Ok(path)
 }
 
 struct CaptionState {
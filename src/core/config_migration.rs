@@ -0,0 +1,54 @@
+//! Config schema versions. Each migration upgrades a config object by one version. Profile and
+//! region layers don't record a version and go through every migration, so they must be safe to
+//! run on an already upgraded config.
+
+use serde_json::{Map, Value};
+
+pub const CONFIG_VERSION: u32 = 1;
+const VERSION_KEY: &str = "version";
+
+type Migration = fn(&mut Map<String, Value>);
+
+/// `MIGRATIONS[n]` upgrades a config from version n to n + 1.
+const MIGRATIONS: &[Migration] = &[
+    migrate_freeform_window_keys
+];
+
+fn rename_key(map: &mut Map<String, Value>, from: &str, to: &str) {
+    if let Some(value) = map.remove(from) {
+        if !map.contains_key(to) {
+            map.insert(to.to_owned(), value);
+        }
+    }
+}
+
+/// v0 -> v1: the Windows freeform window options used to be camelCase.
+fn migrate_freeform_window_keys(map: &mut Map<String, Value>) {
+    rename_key(map, "freeFormWindow", "freeform_window");
+    rename_key(map, "freeFormUiScaleAuto", "freeform_ui_scale_auto");
+    rename_key(map, "freeFormUiScaleAutoRatio", "freeform_ui_scale_auto_ratio");
+}
+
+/// Upgrades a config object to `CONFIG_VERSION` and returns the version it was written with.
+/// The version key is removed, deserializing fills in the current one.
+pub fn migrate(value: &mut Value) -> u32 {
+    let Value::Object(map) = value else {
+        return CONFIG_VERSION;
+    };
+
+    let version = map.remove(VERSION_KEY)
+        .and_then(|v| v.as_u64())
+        .map(|v| v.min(u32::MAX as u64) as u32)
+        .unwrap_or(0);
+
+    if version > CONFIG_VERSION {
+        // Written by a newer version, unknown fields are ignored
+        eprintln!("Config version {} is newer than supported version {}", version, CONFIG_VERSION);
+        return version;
+    }
+
+    for migration in &MIGRATIONS[version as usize..] {
+        migration(map);
+    }
+    version
+}
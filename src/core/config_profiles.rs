@@ -0,0 +1,496 @@
+//! Layered config loading. The effective config is `config.json`, overlaid with the region's
+//! `config.<region>.json` and then the active profile from `config_profiles/<name>.json`. The
+//! upper layers only hold the keys they override.
+
+use std::{fs, path::{Path, PathBuf}, sync::Mutex};
+
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::{config_migration, config_schema::{self, SchemaWarning}, game::Region, gui, hachimi::Config, utils, Error};
+
+const BASE_FILENAME: &str = "config.json";
+pub const PROFILES_DIR: &str = "config_profiles";
+/// Only the base config picks the active profile.
+const PROFILE_KEY: &str = "config_profile";
+
+const EXPORT_FORMAT: &str = "hachimi_config_profile";
+const EXPORT_VERSION: u32 = 1;
+
+/// A profile written to a single file to be moved between installs.
+#[derive(Deserialize, Serialize)]
+struct ExportedProfile {
+    format: String,
+    version: u32,
+    name: String,
+    /// The complete config the profile resolved to when it was exported.
+    config: Value
+}
+
+fn region_filename(region: &Region) -> String {
+    format!("config.{}.json", region.to_string().to_lowercase())
+}
+
+fn profile_path(data_dir: &Path, name: &str) -> PathBuf {
+    data_dir.join(PROFILES_DIR).join(format!("{}.json", name))
+}
+
+pub fn is_valid_profile_name(name: &str) -> bool {
+    !name.trim().is_empty() && name.trim() == name &&
+        name.chars().all(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_' | '.')) &&
+        !name.starts_with('.')
+}
+
+fn check_profile_name(name: &str) -> Result<(), Error> {
+    if is_valid_profile_name(name) {
+        Ok(())
+    }
+    else {
+        Err(Error::RuntimeError(format!("Invalid config profile name: {}", name)))
+    }
+}
+
+/// A config file that couldn't be loaded as-is.
+#[derive(Debug, Clone)]
+pub struct ConfigLoadError {
+    pub path: PathBuf,
+    /// Dotted path of the field that was dropped, None if the whole file was skipped.
+    pub field: Option<String>,
+    pub message: String,
+    /// Copy of the file taken before anything could overwrite it.
+    pub backup_path: Option<PathBuf>
+}
+
+static LOAD_ERRORS: Mutex<Vec<ConfigLoadError>> = Mutex::new(Vec::new());
+
+/// Returns the errors from the last loads and clears them.
+pub fn take_load_errors() -> Vec<ConfigLoadError> {
+    std::mem::take(&mut *LOAD_ERRORS.lock().unwrap())
+}
+
+fn backup_file(path: &Path) -> Option<PathBuf> {
+    if fs::metadata(path).is_err() {
+        return None;
+    }
+    let filename = path.file_name()?.to_str()?;
+    let backup_path = path.with_file_name(format!("{}.{}.bak", filename, Local::now().format("%Y%m%d_%H%M%S")));
+    match fs::copy(path, &backup_path) {
+        Ok(_) => Some(backup_path),
+        Err(e) => {
+            eprintln!("Failed to back up {}: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+fn report_load_error(path: &Path, field: Option<String>, message: String) {
+    // Only back up once per file, the first failure already has the original contents
+    let mut errors = LOAD_ERRORS.lock().unwrap();
+    let backup_path = errors.iter()
+        .find(|e| e.path == path && e.backup_path.is_some())
+        .and_then(|e| e.backup_path.clone())
+        .or_else(|| backup_file(path));
+
+    match &field {
+        Some(field) => eprintln!("Failed to load {} (field {}): {}", path.display(), field, message),
+        None => eprintln!("Failed to load {}: {}", path.display(), message)
+    }
+    errors.push(ConfigLoadError { path: path.to_owned(), field, message, backup_path });
+    drop(errors);
+
+    gui::request_notification(gui::NotificationRequest::ConfigLoadError);
+}
+
+fn read_layer(path: &Path) -> Result<Option<Value>, Error> {
+    if fs::metadata(path).is_err() {
+        return Ok(None);
+    }
+
+    let json = fs::read_to_string(path)?;
+    let mut value: Value = serde_json::from_str(&json)?;
+    if !value.is_object() {
+        return Err(Error::RuntimeError(format!("{} does not contain a JSON object", path.display())));
+    }
+    config_migration::migrate(&mut value);
+    Ok(Some(value))
+}
+
+/// Reads an override layer, errors are reported and the layer is skipped.
+fn read_override_layer(path: &Path) -> Option<Value> {
+    match read_layer(path) {
+        Ok(mut value) => {
+            if let Some(Value::Object(map)) = value.as_mut() {
+                map.remove(PROFILE_KEY);
+            }
+            value
+        },
+        Err(e) => {
+            report_load_error(path, None, e.to_string());
+            None
+        }
+    }
+}
+
+fn wrap_path(path: &[String], leaf: Value) -> Value {
+    path.iter().rev().fold(leaf, |acc, key| {
+        let mut map = Map::new();
+        map.insert(key.clone(), acc);
+        Value::Object(map)
+    })
+}
+
+/// Finds the field that stops `value` from deserializing by trying each one on its own, every
+/// other field falls back to its default.
+fn find_invalid_field(value: &Value) -> Option<Vec<String>> {
+    fn search(path: &mut Vec<String>, value: &Value) -> Option<Vec<String>> {
+        let Value::Object(map) = value else {
+            return None;
+        };
+        for (key, v) in map {
+            path.push(key.clone());
+            if serde_json::from_value::<Config>(wrap_path(path, v.clone())).is_err() {
+                return search(path, v).or_else(|| Some(path.clone()));
+            }
+            path.pop();
+        }
+        None
+    }
+    search(&mut Vec::new(), value)
+}
+
+fn get_path<'a>(value: &'a Value, path: &[String]) -> Option<&'a Value> {
+    path.iter().try_fold(value, |v, key| v.get(key))
+}
+
+fn remove_path(value: &mut Value, path: &[String]) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+    let parent = parents.iter().try_fold(value, |v, key| v.get_mut(key));
+    if let Some(Value::Object(map)) = parent {
+        map.remove(last);
+    }
+}
+
+/// Tries to deserialize the merged layers. Fields that fail are reported against the topmost
+/// layer that sets them and dropped, so one bad value doesn't reset the whole config.
+fn deserialize_layers(mut value: Value, layers: &[(PathBuf, Value)]) -> Config {
+    loop {
+        let e = match serde_json::from_value::<Config>(value.clone()) {
+            Ok(config) => return config,
+            Err(e) => e
+        };
+
+        let Some(field) = find_invalid_field(&value) else {
+            let path = layers.first().map(|(p, _)| p.as_path()).unwrap_or(Path::new(BASE_FILENAME));
+            report_load_error(path, None, e.to_string());
+            return Config::default();
+        };
+
+        let path = layers.iter().rev()
+            .find(|(_, layer)| get_path(layer, &field).is_some())
+            .or(layers.first())
+            .map(|(p, _)| p.as_path())
+            .unwrap_or(Path::new(BASE_FILENAME));
+        let field_value = get_path(&value, &field).cloned().unwrap_or_default();
+        let message = serde_json::from_value::<Config>(wrap_path(&field, field_value))
+            .err()
+            .map(|e| e.to_string())
+            .unwrap_or_else(|| e.to_string());
+        report_load_error(path, Some(field.join(".")), message);
+        remove_path(&mut value, &field);
+    }
+}
+
+fn empty_object() -> Value {
+    Value::Object(Map::new())
+}
+
+/// Deep merges `layer` into `target`. Objects are merged key by key, anything else is replaced.
+fn merge(target: &mut Value, layer: Value) {
+    match (target, layer) {
+        (Value::Object(target), Value::Object(layer)) => {
+            for (key, value) in layer {
+                match target.get_mut(&key) {
+                    Some(existing) if existing.is_object() && value.is_object() => merge(existing, value),
+                    _ => { target.insert(key, value); }
+                }
+            }
+        },
+        (target, layer) => *target = layer
+    }
+}
+
+/// Returns the parts of `value` that differ from `below`, None if they're equal.
+fn diff(value: &Value, below: &Value) -> Option<Value> {
+    match (value, below) {
+        (Value::Object(value), Value::Object(below)) => {
+            let map: Map<String, Value> = value.iter()
+                .filter_map(|(key, v)| match below.get(key) {
+                    Some(b) => diff(v, b).map(|d| (key.clone(), d)),
+                    None => Some((key.clone(), v.clone()))
+                })
+                .collect();
+            (!map.is_empty()).then_some(Value::Object(map))
+        },
+        _ => (value != below).then(|| value.clone())
+    }
+}
+
+/// Copies the value at each of `paths`' leaves from `source` into `target`.
+fn copy_paths(target: &mut Value, paths: &Value, source: &Value) {
+    let (Value::Object(target), Value::Object(paths), Value::Object(source)) = (target, paths, source) else {
+        return;
+    };
+    for (key, path) in paths {
+        let Some(value) = source.get(key) else {
+            continue;
+        };
+        match target.get_mut(key) {
+            Some(existing) if path.is_object() && existing.is_object() => copy_paths(existing, path, value),
+            _ => { target.insert(key.clone(), value.clone()); }
+        }
+    }
+}
+
+/// Fills in every field a partial config leaves out.
+fn resolve(value: Value) -> Result<Value, Error> {
+    let config: Config = serde_json::from_value(value)?;
+    Ok(serde_json::to_value(config)?)
+}
+
+/// Base config overlaid with the region overrides.
+fn load_below_profile(data_dir: &Path, region: &Region) -> Value {
+    let mut value = read_layer(&data_dir.join(BASE_FILENAME)).ok().flatten().unwrap_or_else(empty_object);
+    if let Some(layer) = read_override_layer(&data_dir.join(region_filename(region))) {
+        merge(&mut value, layer);
+    }
+    value
+}
+
+pub fn load(data_dir: &Path, region: &Region) -> Result<Config, Error> {
+    let base_path = data_dir.join(BASE_FILENAME);
+    let base = match read_layer(&base_path) {
+        Ok(v) => v.unwrap_or_else(empty_object),
+        Err(e @ (Error::JsonParseError(_) | Error::RuntimeError(_))) => {
+            report_load_error(&base_path, None, e.to_string());
+            return Ok(Config::default());
+        },
+        Err(e) => return Err(e)
+    };
+
+    let mut layers = vec![(base_path, base)];
+
+    let region_path = data_dir.join(region_filename(region));
+    if let Some(layer) = read_override_layer(&region_path) {
+        layers.push((region_path, layer));
+    }
+
+    let profile = layers[0].1.get(PROFILE_KEY).and_then(|v| v.as_str()).map(|s| s.to_owned());
+    if let Some(name) = profile {
+        let path = profile_path(data_dir, &name);
+        if fs::metadata(&path).is_err() {
+            report_load_error(&path, None, format!("Config profile not found: {}", name));
+        }
+        else if let Some(layer) = read_override_layer(&path) {
+            layers.push((path, layer));
+        }
+    }
+
+    let mut value = empty_object();
+    for (_, layer) in &layers {
+        merge(&mut value, layer.clone());
+    }
+    Ok(deserialize_layers(value, &layers))
+}
+
+/// Checks each layer of the effective config against the config schema. Layers that can't be
+/// read are skipped, loading already reports them.
+pub fn validate_layers(data_dir: &Path, region: &Region) -> Vec<(PathBuf, Vec<SchemaWarning>)> {
+    let base_path = data_dir.join(BASE_FILENAME);
+    let base = read_layer(&base_path).ok().flatten();
+    let profile = base.as_ref()
+        .and_then(|v| v.get(PROFILE_KEY))
+        .and_then(|v| v.as_str())
+        .filter(|name| is_valid_profile_name(name))
+        .map(|name| profile_path(data_dir, name));
+
+    let mut paths = vec![data_dir.join(region_filename(region))];
+    paths.extend(profile);
+
+    base.map(|value| (base_path, value)).into_iter()
+        .chain(paths.into_iter().filter_map(|path| {
+            let value = read_layer(&path).ok().flatten()?;
+            Some((path, value))
+        }))
+        .map(|(path, value)| {
+            let warnings = config_schema::validate_config(&value);
+            (path, warnings)
+        })
+        .filter(|(_, warnings)| !warnings.is_empty())
+        .collect()
+}
+
+/// Writes `config` back to its layers. With a profile active, everything that differs from the
+/// base and region configs goes to the profile. Otherwise keys set by the region overrides are
+/// updated in place and the rest goes to the base config.
+pub fn save(data_dir: &Path, region: &Region, config: &Config) -> Result<(), Error> {
+    fs::create_dir_all(data_dir)?;
+    let effective = serde_json::to_value(config)?;
+    let base_path = data_dir.join(BASE_FILENAME);
+    let region_path = data_dir.join(region_filename(region));
+    let base = read_layer(&base_path).ok().flatten().unwrap_or_else(empty_object);
+
+    if let Some(name) = &config.config_profile {
+        check_profile_name(name)?;
+
+        let mut below = load_below_profile(data_dir, region);
+        merge(&mut below, serde_json::json!({ PROFILE_KEY: name }));
+        let below = resolve(below).unwrap_or_default();
+        let overrides = diff(&effective, &below).unwrap_or_else(empty_object);
+
+        fs::create_dir_all(data_dir.join(PROFILES_DIR))?;
+        utils::write_json_file(&overrides, profile_path(data_dir, name))?;
+
+        if base.get(PROFILE_KEY).and_then(|v| v.as_str()) != Some(name) {
+            let mut base = base;
+            merge(&mut base, serde_json::json!({ PROFILE_KEY: name }));
+            utils::write_json_file(&base, &base_path)?;
+        }
+        return Ok(());
+    }
+
+    let mut new_base = effective.clone();
+    if let Some(region_layer) = read_layer(&region_path).ok().flatten() {
+        let old_base = resolve(base).unwrap_or_default();
+        copy_paths(&mut new_base, &region_layer, &old_base);
+
+        let mut new_region = region_layer.clone();
+        copy_paths(&mut new_region, &region_layer, &effective);
+        if new_region != region_layer {
+            utils::write_json_file(&new_region, &region_path)?;
+        }
+    }
+    utils::write_json_file(&new_base, &base_path)?;
+
+    Ok(())
+}
+
+pub fn list_profiles(data_dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(data_dir.join(PROFILES_DIR)) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "json" {
+                return None;
+            }
+            path.file_stem()?.to_str().map(|s| s.to_owned())
+        })
+        .filter(|name| is_valid_profile_name(name))
+        .collect();
+    names.sort();
+    names
+}
+
+pub fn profile_exists(data_dir: &Path, name: &str) -> bool {
+    is_valid_profile_name(name) && fs::metadata(profile_path(data_dir, name)).is_ok()
+}
+
+/// Selects the profile applied on top of the base config, None to use the base config alone.
+pub fn set_active_profile(data_dir: &Path, name: Option<&str>) -> Result<(), Error> {
+    if let Some(name) = name {
+        if !profile_exists(data_dir, name) {
+            return Err(Error::RuntimeError(format!("Config profile not found: {}", name)));
+        }
+    }
+
+    let base_path = data_dir.join(BASE_FILENAME);
+    let mut base = read_layer(&base_path)?.unwrap_or_else(empty_object);
+    if let Value::Object(map) = &mut base {
+        match name {
+            Some(name) => { map.insert(PROFILE_KEY.to_owned(), Value::String(name.to_owned())); },
+            None => { map.remove(PROFILE_KEY); }
+        }
+    }
+
+    fs::create_dir_all(data_dir)?;
+    utils::write_json_file(&base, &base_path)
+}
+
+/// Creates an empty profile, which starts out identical to the base config.
+pub fn create_profile(data_dir: &Path, name: &str) -> Result<(), Error> {
+    check_profile_name(name)?;
+    if profile_exists(data_dir, name) {
+        return Err(Error::RuntimeError(format!("Config profile already exists: {}", name)));
+    }
+
+    fs::create_dir_all(data_dir.join(PROFILES_DIR))?;
+    utils::write_json_file(&empty_object(), profile_path(data_dir, name))
+}
+
+pub fn delete_profile(data_dir: &Path, name: &str) -> Result<(), Error> {
+    check_profile_name(name)?;
+    fs::remove_file(profile_path(data_dir, name))?;
+    Ok(())
+}
+
+/// Writes the complete config `name` resolves to, so it can be imported elsewhere without the
+/// base and region configs it was layered on.
+pub fn export_profile(data_dir: &Path, region: &Region, name: &str, dest: &Path) -> Result<(), Error> {
+    check_profile_name(name)?;
+    let path = profile_path(data_dir, name);
+    let Some(mut layer) = read_layer(&path)? else {
+        return Err(Error::RuntimeError(format!("Config profile not found: {}", name)));
+    };
+    if let Value::Object(map) = &mut layer {
+        map.remove(PROFILE_KEY);
+    }
+
+    let mut value = load_below_profile(data_dir, region);
+    merge(&mut value, layer);
+    let mut config = resolve(value)?;
+    if let Value::Object(map) = &mut config {
+        map.remove(PROFILE_KEY);
+    }
+
+    let exported = ExportedProfile {
+        format: EXPORT_FORMAT.to_owned(),
+        version: EXPORT_VERSION,
+        name: name.to_owned(),
+        config
+    };
+    utils::write_json_file(&exported, dest)
+}
+
+/// Imports an exported profile, stored as overrides on top of this install's base and region
+/// configs. Returns the profile's name.
+pub fn import_profile(data_dir: &Path, region: &Region, src: &Path, name: Option<&str>) -> Result<String, Error> {
+    let json = fs::read_to_string(src)?;
+    let exported: ExportedProfile = serde_json::from_str(&json)?;
+    if exported.format != EXPORT_FORMAT || exported.version > EXPORT_VERSION {
+        return Err(Error::RuntimeError(format!("{} is not a supported config profile", src.display())));
+    }
+
+    let name = name.unwrap_or(&exported.name).to_owned();
+    check_profile_name(&name)?;
+
+    let mut config = exported.config;
+    config_migration::migrate(&mut config);
+    if let Value::Object(map) = &mut config {
+        map.remove(PROFILE_KEY);
+    }
+    merge(&mut config, serde_json::json!({ PROFILE_KEY: name }));
+    let config = resolve(config)?;
+
+    let mut below = load_below_profile(data_dir, region);
+    merge(&mut below, serde_json::json!({ PROFILE_KEY: name }));
+    let below = resolve(below).unwrap_or_default();
+    let overrides = diff(&config, &below).unwrap_or_else(empty_object);
+
+    fs::create_dir_all(data_dir.join(PROFILES_DIR))?;
+    utils::write_json_file(&overrides, profile_path(data_dir, &name))?;
+    Ok(name)
+}
@@ -0,0 +1,112 @@
+//! JSON Schemas for the config files. They're written to `<data dir>/schemas` so editors can
+//! offer completion and the offline `hachimi-config-check` tool can check files without the game.
+
+use std::{fs, path::Path};
+
+use rust_i18n::t;
+use schemars::schema_for;
+use serde_json::Value;
+
+use super::{hachimi::{Config, LocalizedDataConfig}, Error};
+
+mod validate {
+    include!("../../tools/config_check/src/validate.rs");
+}
+pub use validate::SchemaWarning;
+
+pub const SCHEMAS_DIR: &str = "schemas";
+pub const CONFIG_SCHEMA_FILENAME: &str = "config.schema.json";
+pub const LOCALIZED_DATA_CONFIG_SCHEMA_FILENAME: &str = "localized_data_config.schema.json";
+
+/// Closes every object schema that lists its properties, so unknown fields are flagged by
+/// editors too, and drops `required` since layers and profiles only hold part of the config.
+fn finalize_schema(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            if map.contains_key("properties") && !map.contains_key("additionalProperties") {
+                map.insert("additionalProperties".to_owned(), Value::Bool(false));
+            }
+            map.remove("required");
+            for v in map.values_mut() {
+                finalize_schema(v);
+            }
+        }
+        Value::Array(items) => {
+            for v in items {
+                finalize_schema(v);
+            }
+        }
+        _ => ()
+    }
+}
+
+/// Lets files point at their schema with a root `$schema` key.
+fn add_schema_property(schema: &mut Value) {
+    if let Some(Value::Object(properties)) = schema.get_mut("properties") {
+        properties.insert("$schema".to_owned(), serde_json::json!({ "type": "string" }));
+    }
+}
+
+pub fn config_schema() -> Value {
+    let mut schema = serde_json::to_value(schema_for!(Config)).expect("config schema");
+
+    // Undocumented fields still have a label in the config editor
+    if let Some(Value::Object(properties)) = schema.get_mut("properties") {
+        for (name, property) in properties.iter_mut() {
+            let Value::Object(property) = property else { continue };
+            if property.contains_key("description") {
+                continue;
+            }
+            let key = format!("config_editor.{}", name);
+            let label = t!(key.as_str(), locale = "en");
+            if !label.ends_with(key.as_str()) {
+                property.insert("description".to_owned(), Value::String(label.into_owned()));
+            }
+        }
+    }
+
+    add_schema_property(&mut schema);
+    finalize_schema(&mut schema);
+    schema
+}
+
+pub fn localized_data_config_schema() -> Value {
+    let mut schema = serde_json::to_value(schema_for!(LocalizedDataConfig)).expect("localized data config schema");
+    add_schema_property(&mut schema);
+    finalize_schema(&mut schema);
+    schema
+}
+
+fn write_if_changed(path: &Path, schema: &Value) -> Result<(), Error> {
+    let contents = serde_json::to_string_pretty(schema)?;
+    if fs::read_to_string(path).is_ok_and(|v| v == contents) {
+        return Ok(());
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+/// Writes the schemas to `<data_dir>/schemas`, files are only touched when the schema changed.
+pub fn write_schemas(data_dir: &Path) -> Result<(), Error> {
+    let dir = data_dir.join(SCHEMAS_DIR);
+    fs::create_dir_all(&dir)?;
+    write_if_changed(&dir.join(CONFIG_SCHEMA_FILENAME), &config_schema())?;
+    write_if_changed(&dir.join(LOCALIZED_DATA_CONFIG_SCHEMA_FILENAME), &localized_data_config_schema())?;
+    Ok(())
+}
+
+/// Checks a config file (or a region/profile layer) for unknown fields and mistyped values.
+pub fn validate_config(value: &Value) -> Vec<SchemaWarning> {
+    validate::validate(value, &config_schema())
+}
+
+pub fn validate_localized_data_config(value: &Value) -> Vec<SchemaWarning> {
+    validate::validate(value, &localized_data_config_schema())
+}
+
+/// Logs each warning as `<file>: <warning>`.
+pub fn log_warnings(path: &Path, warnings: &[SchemaWarning]) {
+    for warning in warnings {
+        warn!("{}: {}", path.display(), warning);
+    }
+}
@@ -0,0 +1,154 @@
+//! Panic reporting. Panics inside `extern "C"` hooks abort the game, so the panic hook writes what
+//! it knows to `crash.json` before that happens and the report is offered to the user on the next
+//! launch.
+
+use std::{
+    backtrace::Backtrace,
+    cell::Cell,
+    fs,
+    panic::{self, PanicHookInfo},
+    path::{Path, PathBuf},
+    sync::Mutex,
+    thread
+};
+
+use chrono::Local;
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+
+use super::{gui, Error};
+use crate::il2cpp::hook::umamusume::SceneManager;
+
+pub const CRASH_FILENAME: &str = "crash.json";
+/// Reports that have been shown are moved here.
+pub const CRASHES_DIR: &str = "crashes";
+
+thread_local! {
+    static CURRENT_HOOK: Cell<Option<&'static str>> = const { Cell::new(None) };
+}
+
+/// Records the hook running on this thread while it's alive and restores the outer one when dropped,
+/// so nested hooks unwind correctly. Created by `hook_scope!`.
+pub struct HookScope(Option<&'static str>);
+
+impl HookScope {
+    #[inline]
+    pub fn enter(name: &'static str) -> HookScope {
+        HookScope(CURRENT_HOOK.with(|hook| hook.replace(Some(name))))
+    }
+}
+
+impl Drop for HookScope {
+    #[inline]
+    fn drop(&mut self) {
+        CURRENT_HOOK.with(|hook| hook.set(self.0));
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrashReport {
+    pub hachimi_version: String,
+    pub timestamp: String,
+    pub thread: Option<String>,
+    /// `new_hook!` name of the innermost hook running on the panicking thread.
+    pub hook: Option<String>,
+    /// Game view (SceneDefine.ViewId) that was active.
+    pub view_id: i32,
+    pub message: String,
+    pub location: Option<String>,
+    pub backtrace: String
+}
+
+static CRASH_PATH: OnceCell<PathBuf> = OnceCell::new();
+static PREVIOUS_CRASH: Mutex<Option<CrashReport>> = Mutex::new(None);
+
+fn panic_message(info: &PanicHookInfo) -> String {
+    if let Some(s) = info.payload().downcast_ref::<&str>() {
+        (*s).to_owned()
+    }
+    else if let Some(s) = info.payload().downcast_ref::<String>() {
+        s.clone()
+    }
+    else {
+        "Unknown panic payload".to_owned()
+    }
+}
+
+fn on_panic(info: &PanicHookInfo) {
+    let report = CrashReport {
+        hachimi_version: env!("HACHIMI_DISPLAY_VERSION").to_owned(),
+        timestamp: Local::now().to_rfc3339(),
+        thread: thread::current().name().map(|s| s.to_owned()),
+        hook: CURRENT_HOOK.with(|hook| hook.get()).map(|s| s.to_owned()),
+        view_id: SceneManager::current_view_id(),
+        message: panic_message(info),
+        location: info.location().map(|l| l.to_string()),
+        backtrace: Backtrace::force_capture().to_string()
+    };
+
+    error!(
+        "Panic in hook {} (view {}): {}{}",
+        report.hook.as_deref().unwrap_or("<none>"),
+        report.view_id,
+        report.message,
+        report.location.as_deref().map(|l| format!(" at {}", l)).unwrap_or_default()
+    );
+
+    let Some(path) = CRASH_PATH.get() else {
+        return;
+    };
+    match serde_json::to_string_pretty(&report) {
+        Ok(json) => if let Err(e) = fs::write(path, json) {
+            error!("Failed to write crash report: {}", e);
+        },
+        Err(e) => error!("Failed to serialize crash report: {}", e)
+    }
+}
+
+/// Moves a crash report left by the last session out of the way so it's only offered once.
+fn take_crash_file(data_dir: &Path) -> Result<Option<CrashReport>, Error> {
+    let path = data_dir.join(CRASH_FILENAME);
+    if fs::metadata(&path).is_err() {
+        return Ok(None);
+    }
+
+    let json = fs::read_to_string(&path)?;
+    let dir = data_dir.join(CRASHES_DIR);
+    fs::create_dir_all(&dir)?;
+    fs::rename(&path, dir.join(format!("crash_{}.json", Local::now().format("%Y%m%d_%H%M%S"))))?;
+
+    Ok(Some(serde_json::from_str(&json)?))
+}
+
+/// Installs the panic hook and queues the previous session's crash report, if there is one.
+pub fn init(data_dir: &Path) {
+    match take_crash_file(data_dir) {
+        Ok(Some(report)) => {
+            warn!("The previous session crashed in hook {}", report.hook.as_deref().unwrap_or("<none>"));
+            *PREVIOUS_CRASH.lock().unwrap() = Some(report);
+            gui::request_notification(gui::NotificationRequest::PreviousCrash);
+        },
+        Ok(None) => (),
+        Err(e) => warn!("Failed to read crash report: {}", e)
+    }
+
+    if CRASH_PATH.set(data_dir.join(CRASH_FILENAME)).is_err() {
+        return;
+    }
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        on_panic(info);
+        default_hook(info);
+    }));
+}
+
+pub fn take_previous_crash() -> Option<CrashReport> {
+    PREVIOUS_CRASH.lock().unwrap().take()
+}
+
+/// For panics that were caught and didn't take the game down.
+pub fn discard_pending() {
+    if let Some(path) = CRASH_PATH.get() {
+        _ = fs::remove_file(path);
+    }
+}
@@ -6,7 +6,7 @@ use std::{
     panic::{self, AssertUnwindSafe},
     sync::{atomic::{self, AtomicBool}, Arc, Mutex},
     thread,
-    time::Instant
+    time::{Duration, Instant}
 };
 
 use egui_scale::EguiScale;
@@ -21,6 +21,7 @@ use crate::il2cpp::{
         umamusume::{CameraData::ShadowResolution, CySpringController::SpringUpdateMode, Director, GameSystem, GraphicSettings::{GraphicsQuality, MsaaQuality}, Localize, GameDefine::BgSeason, SceneManager as UmaSceneManager},
         UnityEngine_CoreModule::{Application, Texture::AnisoLevel}
     },
+    symbol_report,
     symbols::Thread
 };
 
@@ -34,15 +35,24 @@ use crate::il2cpp::{
 
 #[cfg(target_os = "windows")]
 use crate::il2cpp::hook::UnityEngine_CoreModule::QualitySettings;
-#[cfg(target_os = "windows")]
-use crate::windows::free_camera::{self, FreeCameraMode};
-#[cfg(target_os = "windows")]
+use crate::core::free_camera::{self, FreeCameraMode};
+use super::camera_path::{CameraKeyframe, CameraPath};
 use super::game::Region;
 
 use super::{
     hachimi::{self, Language, REPO_PATH, WEBSITE_URL},
     http::{ureq_config, AsyncRequest},
+    bug_report,
+    captions,
+    config_profiles,
+    crash_report::{self, CrashReport},
+    hook_registry::{self, HookInfo, HookStatus},
+    live_profiles,
+    live_subtitles,
     live_utils,
+    log,
+    plugin_api,
+    screenshot,
     tl_repo::{self, RepoInfo, LocalRepoInfo},
     utils::{self, get_localized_string, umamusume_enum_options, SendPtr},
     Hachimi
@@ -69,6 +79,7 @@ pub fn enqueue_theme_preview(config: hachimi::Config) {
 #[derive(Debug)]
 pub enum NotificationRequest {
     ConfigLoadError,
+    PreviousCrash,
     TLRepoChanged,
     TLFolderMissing,
     Custom(String),
@@ -277,7 +288,10 @@ impl Window for PluginWindow {
                             if let Some(callback) = self.contents_callback {
                                 let _ = panic::catch_unwind(AssertUnwindSafe(|| {
                                     callback(ui as *mut _ as *mut c_void, self.userdata as *mut c_void);
-                                })).inspect_err(|_| error!("plugin window contents callback panicked"));
+                                })).inspect_err(|_| {
+                                    error!("plugin window contents callback panicked");
+                                    crash_report::discard_pending();
+                                });
                             }
                         });
                     },
@@ -285,7 +299,10 @@ impl Window for PluginWindow {
                         if let Some(callback) = self.bottom_callback {
                             let _ = panic::catch_unwind(AssertUnwindSafe(|| {
                                 callback(ui as *mut _ as *mut c_void, self.userdata as *mut c_void);
-                            })).inspect_err(|_| error!("plugin window bottom callback panicked"));
+                            })).inspect_err(|_| {
+                                error!("plugin window bottom callback panicked");
+                                crash_report::discard_pending();
+                            });
                         }
                     }
                 );
@@ -295,6 +312,14 @@ impl Window for PluginWindow {
     }
 
     fn plugin_window_id(&self) -> Option<i32> { Some(self.id) }
+    fn plugin_owned_by(&self, owns: &dyn Fn(usize) -> bool) -> bool { self.is_owned_by(owns) }
+}
+
+impl PluginWindow {
+    fn is_owned_by(&self, owns: &dyn Fn(usize) -> bool) -> bool {
+        self.contents_callback.is_some_and(|cb| owns(cb as usize)) ||
+        self.bottom_callback.is_some_and(|cb| owns(cb as usize))
+    }
 }
 
 pub fn show_plugin_window(
@@ -329,6 +354,31 @@ fn take_plugin_windows_to_close() -> Vec<i32> {
     std::mem::take(&mut *ids)
 }
 
+/// Drops every menu item, section and window whose callbacks satisfy `owns`.
+/// Used when a plugin is unloaded so that nothing calls into the freed library.
+pub fn remove_plugin_registrations(owns: &dyn Fn(usize) -> bool) {
+    let mut removed_labels = Vec::new();
+    PLUGIN_MENU_ITEMS.lock().unwrap().retain(|item| {
+        let owned = item.callback.is_some_and(|cb| owns(cb as usize));
+        if owned {
+            removed_labels.push(item.label.clone());
+        }
+        !owned
+    });
+    let mut icons = PLUGIN_MENU_ICONS.lock().unwrap();
+    for label in removed_labels {
+        icons.remove(&label);
+    }
+    drop(icons);
+
+    PLUGIN_MENU_SECTIONS.lock().unwrap().retain(|section| !owns(section.callback as usize));
+    PLUGIN_WINDOWS_TO_SHOW.lock().unwrap().retain(|window| !window.is_owned_by(owns));
+
+    if let Some(mutex) = Gui::instance() {
+        mutex.lock().unwrap().windows.retain(|w| !w.plugin_owned_by(owns));
+    }
+}
+
 #[cfg(target_os = "windows")]
 pub type RawKeybind = u16;
 #[cfg(target_os = "android")]
@@ -745,7 +795,22 @@ impl Gui {
         for req in requests {
             match req {
                 NotificationRequest::ConfigLoadError => {
+                    let errors = config_profiles::take_load_errors();
+                    if errors.is_empty() {
+                        continue;
+                    }
                     self.show_notification(&t!("notification.config_error"));
+                    self.show_window(Box::new(SimpleOkDialog::new(
+                        &t!("config_load_error.title"),
+                        &format_config_load_errors(&errors),
+                        true,
+                        || {}
+                    )));
+                }
+                NotificationRequest::PreviousCrash => {
+                    if let Some(report) = crash_report::take_previous_crash() {
+                        self.show_window(Box::new(CrashReportWindow::new(report)));
+                    }
                 }
                 NotificationRequest::TLRepoChanged => {
                     self.show_notification(&t!("notification.tl_repo_changed"));
@@ -780,6 +845,31 @@ impl Gui {
         }
     }
 
+    fn run_live_subtitles(ctx: &egui::Context, current: f32) {
+        let Some(text) = live_subtitles::subtitle_at(current) else {
+            return;
+        };
+
+        let scale = get_scale(ctx);
+        egui::Area::new(egui::Id::new("live_subtitles_area"))
+            .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -110.0 * scale))
+            .interactable(false)
+            .show(ctx, |ui| {
+                egui::Frame::NONE
+                    .fill(egui::Color32::from_black_alpha(150))
+                    .inner_margin(egui::Margin::symmetric((12.0 * scale) as i8, (6.0 * scale) as i8))
+                    .corner_radius(8.0 * scale)
+                    .show(ui, |ui| {
+                        ui.set_max_width(ctx.content_rect().width() * 0.8);
+                        ui.label(
+                            egui::RichText::new(text)
+                                .size(20.0 * scale)
+                                .color(egui::Color32::WHITE)
+                        );
+                    });
+            });
+    }
+
     fn run_live_slider(&mut self, ctx: &egui::Context) {
         if !IS_LIVE_SCENE.load(atomic::Ordering::Acquire) {
             return;
@@ -812,6 +902,8 @@ impl Gui {
             IS_LIVE_SCENE.store(false, atomic::Ordering::Release);
             IS_LIVE_SLIDER_ACTIVE.store(false, atomic::Ordering::Release);
             live_utils::reset_live_drag_state();
+            live_utils::reset_live_playback_state();
+            live_subtitles::clear();
             return;
         }
 
@@ -824,12 +916,30 @@ impl Gui {
         let total = Director::get_LiveTotalTime(director);
         if total <= 0.0 { return; }
 
-        if config.live_playback_loop && current >= total - 0.1 {
+        for action in live_utils::take_pending_actions() {
+            if let Some(time) = live_utils::apply_action(action, current, total) {
+                current = time;
+            }
+        }
+
+        if let Some(loop_start) = live_utils::check_live_loop(current) {
+            live_utils::move_live_playback(loop_start);
+            current = loop_start;
+        }
+        else if config.live_playback_loop && current >= total - 0.1 {
             live_utils::move_live_playback(0.0);
             current = 0.0;
         }
 
+        if config.live_subtitles {
+            Self::run_live_subtitles(ctx, current);
+        }
+
         let is_paused = Director::is_live_paused();
+        if !is_paused && live_utils::live_speed() != 1.0 {
+            live_utils::sync_live_speed();
+        }
+
         if !config.live_slider_always_show && !is_paused {
             IS_LIVE_SLIDER_ACTIVE.store(false, atomic::Ordering::Release);
             return;
@@ -876,6 +986,59 @@ impl Gui {
                                 if res.drag_stopped() {
                                     live_utils::end_live_drag();
                                 }
+
+                                let live_loop = live_utils::live_loop();
+                                let rect = res.rect;
+                                for (point, color) in [(live_loop.a, egui::Color32::LIGHT_GREEN), (live_loop.b, egui::Color32::LIGHT_RED)] {
+                                    let Some(time) = point else { continue };
+                                    let x = rect.left() + rect.width() * (time / total);
+                                    ui.painter().vline(x, rect.y_range(), egui::Stroke::new(2.0 * scale, color));
+                                }
+                            });
+                        });
+
+                        ui.horizontal(|ui| {
+                            let live_loop = live_utils::live_loop();
+                            if ui.button(t!("live_playback.set_loop_a")).clicked() {
+                                live_utils::set_loop_a(current);
+                            }
+                            if ui.button(t!("live_playback.set_loop_b")).clicked() {
+                                live_utils::set_loop_b(current);
+                            }
+                            if live_loop.a.is_some() || live_loop.b.is_some() {
+                                ui.label(format!(
+                                    "{} - {}",
+                                    live_loop.a.map(format_live_time).unwrap_or_else(|| "--".to_owned()),
+                                    live_loop.b.map(format_live_time).unwrap_or_else(|| "--".to_owned())
+                                ));
+                                if ui.button(t!("live_playback.clear_loop")).clicked() {
+                                    live_utils::clear_loop();
+                                }
+                            }
+
+                            ui.separator();
+
+                            ui.label(t!("live_playback.speed"));
+                            let speed = live_utils::live_speed();
+                            egui::ComboBox::from_id_salt("live_playback_speed")
+                                .selected_text(format!("{}x", speed))
+                                .show_ui(ui, |ui| {
+                                    for &choice in live_utils::LIVE_SPEEDS {
+                                        if ui.selectable_label(speed == choice, format!("{}x", choice)).clicked() {
+                                            live_utils::set_live_speed(choice);
+                                        }
+                                    }
+                                });
+
+                            ui.separator();
+
+                            ui.add_enabled_ui(is_paused, |ui| {
+                                if ui.button("\u{f048}").on_hover_text(t!("live_playback.step_back")).clicked() {
+                                    live_utils::step_live_frame(current, total, -1);
+                                }
+                                if ui.button("\u{f051}").on_hover_text(t!("live_playback.step_forward")).clicked() {
+                                    live_utils::step_live_frame(current, total, 1);
+                                }
                             });
                         });
                     });
@@ -930,7 +1093,6 @@ impl Gui {
         self.process_plugin_windows();
         self.run_windows();
         self.run_notifications();
-        #[cfg(target_os = "windows")]
         self.run_free_camera_overlay();
 
         if self.splash_visible { self.run_splash(); }
@@ -1025,7 +1187,7 @@ impl Gui {
 
         let has_interactive_widgets = IS_LIVE_SCENE.load(atomic::Ordering::Relaxed);
         #[cfg(target_os = "windows")]
-        let free_camera_input_capture = free_camera::wants_windows_input_capture();
+        let free_camera_input_capture = crate::windows::free_camera::wants_windows_input_capture();
 
         // Store these as atomic values so the input thread can check them without locking the gui
         #[cfg(target_os = "android")]
@@ -1183,6 +1345,9 @@ impl Gui {
                         if ui.button(t!("menu.open_config_editor")).clicked() {
                             show_window = Some(Box::new(ConfigEditor::new()));
                         }
+                        if ui.button(t!("menu.config_profiles")).clicked() {
+                            show_window = Some(Box::new(ConfigProfilesWindow::new()));
+                        }
                         if ui.button(t!("menu.reload_config")).clicked() {
                             hachimi.reload_config();
                             show_notification = Some(t!("notification.config_reloaded"));
@@ -1266,6 +1431,11 @@ impl Gui {
                             });
                             ui.end_row();
                         }
+                        if hachimi.config.load().free_camera.enabled {
+                            if ui.button(t!("menu.camera_path_editor")).clicked() {
+                                show_window = Some(Box::new(CameraPathWindow::new()));
+                            }
+                        }
                         ui.separator();
 
                         ui.heading(t!("menu.translation_heading"));
@@ -1298,10 +1468,21 @@ impl Gui {
                                     }
                                 })
                             }
+                            if live_subtitles::has_lyrics() && ui.button(t!("menu.export_lyrics")).clicked() {
+                                show_notification = Some(match live_subtitles::export_game_lyrics() {
+                                    Ok(path) => t!("notification.lyrics_exported", path = path.display().to_string()),
+                                    Err(e) => e.to_string().into()
+                                });
+                            }
                         }
                         if ui.button(t!("menu.edit_excludes")).clicked() {
                             show_window = Some(Box::new(ExcludesEditorWindow::new()));
                         }
+                        if hachimi.config.load().caption.caption_enable {
+                            if ui.button(t!("menu.caption_history")).clicked() {
+                                show_window = Some(Box::new(CaptionHistoryWindow::new()));
+                            }
+                        }
                         ui.separator();
 
                         let plugin_items = get_plugin_menu_items();
@@ -1330,6 +1511,7 @@ impl Gui {
                                         }))
                                         .inspect_err(|_| {
                                             error!("plugin menu item callback panicked: {}", item.label);
+                                            crash_report::discard_pending();
                                         });
                                     }
                                 }
@@ -1358,6 +1540,50 @@ impl Gui {
                                 }))
                                 .inspect_err(|_| {
                                     error!("plugin menu section callback panicked");
+                                    crash_report::discard_pending();
+                                });
+                            }
+                            ui.separator();
+                        }
+
+                        let plugin_names = plugin_api::plugin_names();
+                        ui.heading(t!("menu.troubleshooting_heading"));
+                        if ui.button(t!("menu.view_logs")).clicked() {
+                            show_window = Some(Box::new(LogViewerWindow::new()));
+                        }
+                        if ui.button(t!("menu.create_bug_report")).clicked() {
+                            LogViewerWindow::create_bug_report();
+                        }
+                        if ui.button(t!("menu.hook_diagnostics")).clicked() {
+                            show_window = Some(Box::new(HookDiagnosticsWindow::new()));
+                        }
+                        if ui.button(t!("menu.symbol_report")).clicked() {
+                            show_notification = Some(match symbol_report::write() {
+                                Ok((report, path)) => t!(
+                                    "notification.symbol_report_saved",
+                                    total = report.summary.total,
+                                    missing = report.summary.missing,
+                                    changed = report.summary.changed,
+                                    path = path.display().to_string()
+                                ),
+                                Err(e) => t!("notification.symbol_report_failed", error = e.to_string())
+                            });
+                        }
+                        ui.separator();
+
+                        if !plugin_names.is_empty() {
+                            ui.heading(t!("menu.plugins_heading"));
+                            for name in plugin_names {
+                                ui.horizontal(|ui| {
+                                    if plugin_api::is_plugin_unloaded(&name) {
+                                        ui.label(t!("menu.plugin_not_loaded", name = name));
+                                    }
+                                    else {
+                                        ui.label(&name);
+                                    }
+                                    if ui.button(t!("menu.reload_plugin")).clicked() {
+                                        plugin_api::request_reload(name.clone());
+                                    }
                                 });
                             }
                             ui.separator();
@@ -1387,6 +1613,9 @@ impl Gui {
                         if ui.button(t!("menu.toggle_game_ui")).clicked() {
                             Thread::main_thread().schedule(Self::toggle_game_ui);
                         }
+                        if ui.button(t!("menu.take_screenshot")).clicked() {
+                            screenshot::request_capture();
+                        }
 
                         #[cfg(target_os = "android")]
                         {
@@ -1431,6 +1660,10 @@ impl Gui {
         }
     }
 
+    pub fn is_game_ui_hidden() -> bool {
+        !DISABLED_GAME_UIS.lock().unwrap().is_empty()
+    }
+
     pub fn toggle_game_ui() {
         use crate::il2cpp::hook::{
             UnityEngine_CoreModule::{Object, Behaviour, GameObject},
@@ -1652,6 +1885,8 @@ impl Gui {
             tl_repo::UpdateProgress::new(1, 1)
         });
         let ratio = progress.current as f32 / progress.total as f32;
+        let tl_updater = &Hachimi::instance().tl_updater;
+        let paused = tl_updater.is_paused();
 
         egui::Area::new("update_progress".into())
         .fixed_pos(egui::Pos2 {
@@ -1665,9 +1900,13 @@ impl Gui {
             .corner_radius(4.0 * scale)
             .show(ui, |ui| {
                 ui.horizontal(|ui| {
-                    ui.label(t!("tl_updater.title"));
+                    ui.label(if paused { t!("tl_updater.paused") } else { t!("tl_updater.title") });
                     ui.add_space(26.0 * scale);
                     ui.label(format!("{:.2}%", ratio * 100.0));
+                    let (icon, hover) = if paused { ("\u{f04b}", t!("tl_updater.resume")) } else { ("\u{f04c}", t!("tl_updater.pause")) };
+                    if ui.small_button(icon).on_hover_text(hover).clicked() {
+                        tl_updater.set_paused(!paused);
+                    }
                 });
                 ui.add(
                     egui::ProgressBar::new(ratio)
@@ -1687,7 +1926,6 @@ impl Gui {
         self.notifications.retain_mut(|n| n.run(&self.context, &mut offset));
     }
 
-    #[cfg(target_os = "windows")]
     fn run_free_camera_overlay(&mut self) {
         let Some((content, alpha)) = free_camera::overlay_message() else {
             return;
@@ -1718,19 +1956,10 @@ impl Gui {
     }
 
     pub fn is_empty(&self) -> bool {
-        #[cfg(target_os = "windows")]
-        {
-            !self.splash_visible && !self.menu_visible && !self.update_progress_visible &&
-            self.notifications.is_empty() && self.windows.is_empty() &&
-            !IS_LIVE_SCENE.load(atomic::Ordering::Acquire) &&
-            !free_camera::has_overlay_message()
-        }
-        #[cfg(target_os = "android")]
-        {
-            !self.splash_visible && !self.menu_visible && !self.update_progress_visible &&
-            self.notifications.is_empty() && self.windows.is_empty() &&
-            !IS_LIVE_SCENE.load(atomic::Ordering::Acquire)
-        }
+        !self.splash_visible && !self.menu_visible && !self.update_progress_visible &&
+        self.notifications.is_empty() && self.windows.is_empty() &&
+        !IS_LIVE_SCENE.load(atomic::Ordering::Acquire) &&
+        !free_camera::has_overlay_message()
     }
 
     pub fn is_consuming_input(&self) -> bool {
@@ -1927,6 +2156,7 @@ impl Notification {
 pub trait Window {
     fn run(&mut self, ctx: &egui::Context) -> bool;
     fn plugin_window_id(&self) -> Option<i32> { None }
+    fn plugin_owned_by(&self, _owns: &dyn Fn(usize) -> bool) -> bool { false }
 }
 
 // Shared window creation function
@@ -2323,11 +2553,25 @@ impl ConfigEditorTab {
     }
 }
 
+fn format_live_time(time: f32) -> String {
+    format!("{:02}:{:05.2}", (time / 60.0).floor() as i32, time % 60.0)
+}
+
 fn should_show_option(search: &str, label: &str) -> bool {
     search.is_empty() || label.to_lowercase().contains(&search.to_lowercase())
 }
 
 impl ConfigEditor {
+    fn caption_category_label(category: &str) -> String {
+        match category {
+            "home" => t!("config_editor.caption_category_home").into_owned(),
+            "training" => t!("config_editor.caption_category_training").into_owned(),
+            "gacha" => t!("config_editor.caption_category_gacha").into_owned(),
+            "title_call" => t!("config_editor.caption_category_title_call").into_owned(),
+            _ => t!("config_editor.caption_category_other", category = category).into_owned()
+        }
+    }
+
     pub fn new() -> ConfigEditor {
         let handle = Hachimi::instance().config.load();
 
@@ -2424,6 +2668,42 @@ impl ConfigEditor {
                 ui.end_row();
             }
 
+            if should_show_option(search, &t!("config_editor.http_proxy_url")) {
+                ui.label(t!("config_editor.http_proxy_url"));
+                let mut proxy_url = config.http.proxy_url.clone().unwrap_or_default();
+                let _res = ui.add(egui::TextEdit::singleline(&mut proxy_url).hint_text("socks5://127.0.0.1:1080"));
+                #[cfg(target_os = "android")]
+                handle_android_keyboard(&_res, &mut proxy_url);
+                if proxy_url != config.http.proxy_url.as_deref().unwrap_or_default() {
+                    config.http.proxy_url = (!proxy_url.is_empty()).then_some(proxy_url);
+                }
+                ui.end_row();
+            }
+
+            if should_show_option(search, &t!("config_editor.http_ca_cert_path")) {
+                ui.label(t!("config_editor.http_ca_cert_path"));
+                let mut ca_cert_path = config.http.ca_cert_path.clone().unwrap_or_default();
+                let _res = ui.text_edit_singleline(&mut ca_cert_path);
+                #[cfg(target_os = "android")]
+                handle_android_keyboard(&_res, &mut ca_cert_path);
+                if ca_cert_path != config.http.ca_cert_path.as_deref().unwrap_or_default() {
+                    config.http.ca_cert_path = (!ca_cert_path.is_empty()).then_some(ca_cert_path);
+                }
+                ui.end_row();
+            }
+
+            if should_show_option(search, &t!("config_editor.http_connect_timeout")) {
+                ui.label(t!("config_editor.http_connect_timeout"));
+                ui.add(egui::DragValue::new(&mut config.http.connect_timeout).range(0..=600).suffix("s"));
+                ui.end_row();
+            }
+
+            if should_show_option(search, &t!("config_editor.http_response_timeout")) {
+                ui.label(t!("config_editor.http_response_timeout"));
+                ui.add(egui::DragValue::new(&mut config.http.response_timeout).range(0..=600).suffix("s"));
+                ui.end_row();
+            }
+
             if should_show_option(search, &t!("config_editor.meta_index_url")) {
                 ui.label(t!("config_editor.meta_index_url"));
                 let res = ui.add(egui::TextEdit::singleline(&mut config.meta_index_url).lock_focus(true));
@@ -2535,6 +2815,50 @@ impl ConfigEditor {
                 ui.end_row();
             }
 
+            if should_show_option(search, &t!("config_editor.log_levels")) {
+                ui.label(t!("config_editor.log_levels"));
+                ui.vertical(|ui| {
+                    let mut modules: Vec<String> = config.log_levels.keys().cloned().collect();
+                    modules.sort();
+                    for module in modules {
+                        ui.horizontal(|ui| {
+                            if let Some(level) = config.log_levels.get_mut(&module) {
+                                egui::ComboBox::new(ui.id().with(("log_level", &module)), "")
+                                .selected_text(level.as_str())
+                                .show_ui(ui, |ui| {
+                                    for choice in log::LogLevel::ALL {
+                                        ui.selectable_value(level, choice, choice.as_str());
+                                    }
+                                });
+                            }
+                            ui.label(&module);
+                            if ui.button("\u{f1f8}").clicked() {
+                                config.log_levels.remove(&module);
+                            }
+                        });
+                    }
+
+                    ui.horizontal(|ui| {
+                        let id = ui.id().with("new_log_module");
+                        let mut new_module = ui.data_mut(|d| d.get_temp::<String>(id)).unwrap_or_default();
+                        let _res = ui.add(
+                            egui::TextEdit::singleline(&mut new_module)
+                            .hint_text(t!("config_editor.log_levels_module"))
+                            .desired_width(120.0 * scale)
+                        );
+                        #[cfg(target_os = "android")]
+                        handle_android_keyboard(&_res, &mut new_module);
+
+                        if ui.add_enabled(!new_module.trim().is_empty(), egui::Button::new("\u{f067}")).clicked() {
+                            config.log_levels.insert(new_module.trim().to_owned(), log::LogLevel::Debug);
+                            new_module.clear();
+                        }
+                        ui.data_mut(|d| d.insert_temp(id, new_module));
+                    });
+                });
+                ui.end_row();
+            }
+
             if should_show_option(search, &t!("config_editor.apply_atlas_workaround")) {
                 ui.label(t!("config_editor.apply_atlas_workaround"));
                 ui.checkbox(&mut config.apply_atlas_workaround, "");
@@ -2594,6 +2918,20 @@ impl ConfigEditor {
                 }
             }
 
+            if should_show_option(search, &t!("config_editor.tl_update_speed_limit")) {
+                ui.label(t!("config_editor.tl_update_speed_limit"));
+                ui.add(egui::DragValue::new(&mut config.tl_update_speed_limit).range(0..=1048576).suffix(" KiB/s"));
+                ui.end_row();
+            }
+
+            if config.tl_auto_updater_mode == hachimi::TLAutoUpdaterMode::Silent &&
+                should_show_option(search, &t!("config_editor.tl_update_confirm_size"))
+            {
+                ui.label(t!("config_editor.tl_update_confirm_size"));
+                ui.add(egui::DragValue::new(&mut config.tl_update_confirm_size).range(0..=4096).suffix(" MiB"));
+                ui.end_row();
+            }
+
             if should_show_option(search, &t!("config_editor.disable_translations")) {
                 ui.label(t!("config_editor.disable_translations"));
                 ui.checkbox(&mut config.disable_translations, "");
@@ -2929,39 +3267,62 @@ impl ConfigEditor {
                 ui.end_row();
             }
 
-            #[cfg(target_os = "windows")]
-            {
-                if should_show_option(search, &t!("config_editor.free_camera")) && Hachimi::instance().game.region != Region::Global {
-                    ui.label(t!("config_editor.free_camera"));
-                    ui.checkbox(&mut config.windows.free_camera.enabled, "");
+            if should_show_option(search, &t!("config_editor.free_camera")) && Hachimi::instance().game.region != Region::Global {
+                ui.label(t!("config_editor.free_camera"));
+                ui.checkbox(&mut config.free_camera.enabled, "");
+                ui.end_row();
+
+                if config.free_camera.enabled {
+                    ui.label("");
+                    if ui.button(t!("free_camera.settings_title")).clicked() {
+                        thread::spawn(|| {
+                            Gui::instance().unwrap()
+                            .lock().unwrap()
+                            .show_window(Box::new(FreeCameraSettingsWindow::new()));
+                        });
+                    }
                     ui.end_row();
 
-                    if config.windows.free_camera.enabled {
-                        ui.label("");
-                        if ui.button(t!("free_camera.settings_title")).clicked() {
-                            thread::spawn(|| {
-                                Gui::instance().unwrap()
-                                .lock().unwrap()
-                                .show_window(Box::new(FreeCameraSettingsWindow::new()));
-                            });
-                        }
-                        ui.end_row();
+                    ui.label("");
+                    if ui.button(t!("free_camera.presets_title")).clicked() {
+                        thread::spawn(|| {
+                            Gui::instance().unwrap()
+                            .lock().unwrap()
+                            .show_window(Box::new(FreeCameraPresetsWindow::new()));
+                        });
+                    }
+                    ui.end_row();
 
-                        ui.label("");
-                        if ui.button(t!("free_camera.cheatsheet_title")).clicked() {
-                            thread::spawn(move || {
-                                Gui::instance().unwrap()
-                                .lock().unwrap()
-                                .show_window(Box::new(SimpleMarkdownDialog::new_with_height(
-                                    &t!("free_camera.cheatsheet_title"),
-                                    &t!("free_camera.cheatsheet_contents"),
-                                    400.0,
-                                    500.0
-                                )));
-                            });
-                        }
-                        ui.end_row();
+                    ui.label("");
+                    if ui.button(t!("camera_path.title")).clicked() {
+                        thread::spawn(|| {
+                            Gui::instance().unwrap()
+                            .lock().unwrap()
+                            .show_window(Box::new(CameraPathWindow::new()));
+                        });
+                    }
+                    ui.end_row();
+
+                    ui.label("");
+                    if ui.button(t!("free_camera.cheatsheet_title")).clicked() {
+                        thread::spawn(move || {
+                            let contents = if cfg!(target_os = "android") {
+                                t!("free_camera.cheatsheet_contents_android")
+                            }
+                            else {
+                                t!("free_camera.cheatsheet_contents")
+                            };
+                            Gui::instance().unwrap()
+                            .lock().unwrap()
+                            .show_window(Box::new(SimpleMarkdownDialog::new_with_height(
+                                &t!("free_camera.cheatsheet_title"),
+                                &contents,
+                                400.0,
+                                500.0
+                            )));
+                        });
                     }
+                    ui.end_row();
                 }
             }
 
@@ -2991,6 +3352,18 @@ impl ConfigEditor {
                 ui.end_row();
             }
 
+            if should_show_option(search, &t!("config_editor.live_profiles")) {
+                ui.label(t!("config_editor.live_profiles"));
+                if ui.button(t!("open")).clicked() {
+                    thread::spawn(|| {
+                        Gui::instance().unwrap()
+                        .lock().unwrap()
+                        .show_window(Box::new(LiveProfilesWindow::new()));
+                    });
+                }
+                ui.end_row();
+            }
+
             if should_show_option(search, &t!("config_editor.skill_info_dialog")) {
                 ui.label(t!("config_editor.skill_info_dialog"));
                 ui.checkbox(&mut config.skill_info_dialog, "");
@@ -3061,6 +3434,53 @@ impl ConfigEditor {
                 ui.end_row();
             }
 
+            if should_show_option(search, &t!("config_editor.screenshot_multiplier")) {
+                ui.label(t!("config_editor.screenshot_multiplier"));
+                ui.add(egui::Slider::new(&mut config.screenshot.multiplier, 1..=screenshot::MAX_MULTIPLIER));
+                ui.end_row();
+            }
+
+            if should_show_option(search, &t!("config_editor.screenshot_hide_game_ui")) {
+                ui.label(t!("config_editor.screenshot_hide_game_ui"));
+                ui.checkbox(&mut config.screenshot.hide_game_ui, "");
+                ui.end_row();
+            }
+
+            if should_show_option(search, &t!("config_editor.screenshot_hotkey")) {
+                ui.label(t!("config_editor.screenshot_hotkey"));
+                ui.horizontal(|ui| {
+                    let hotkey = config.screenshot.hotkey;
+                    if hotkey == 0 {
+                        ui.label(t!("live_playback.unbound"));
+                    }
+                    else {
+                        #[cfg(target_os = "windows")]
+                        ui.label(crate::windows::utils::vk_to_display_label(hotkey));
+                        #[cfg(target_os = "android")]
+                        ui.label(crate::android::gui_impl::keymap::keycode_display_label(hotkey as i32));
+                    }
+
+                    if ui.button(t!("bind_key")).clicked() {
+                        thread::spawn(|| {
+                            let Some(gui_mutex) = Gui::instance() else { return };
+                            gui_mutex.lock().unwrap().show_window(Box::new(SetKeybindWindow::new(|result| {
+                                let Some(raw) = result else { return };
+                                #[cfg(target_os = "android")]
+                                let raw = raw as u16;
+
+                                let mut new_config = Hachimi::instance().config.load().as_ref().clone();
+                                new_config.screenshot.hotkey = raw;
+                                save_and_reload_config(new_config);
+                            })));
+                        });
+                    }
+                    if hotkey != 0 && ui.button("\u{f00d}").clicked() {
+                        config.screenshot.hotkey = 0;
+                    }
+                });
+                ui.end_row();
+            }
+
             if should_show_option(search, &t!("config_editor.live_slider_always_show")) {
                 ui.label(t!("config_editor.live_slider_always_show"));
                 ui.checkbox(&mut config.live_slider_always_show, "");
@@ -3073,6 +3493,24 @@ impl ConfigEditor {
                 ui.end_row();
             }
 
+            if should_show_option(search, &t!("config_editor.live_playback_keybinds")) {
+                ui.label(t!("config_editor.live_playback_keybinds"));
+                if ui.button(t!("open")).clicked() {
+                    thread::spawn(|| {
+                        Gui::instance().unwrap()
+                        .lock().unwrap()
+                        .show_window(Box::new(LivePlaybackKeybindsWindow::new()));
+                    });
+                }
+                ui.end_row();
+            }
+
+            if should_show_option(search, &t!("config_editor.live_subtitles")) {
+                ui.label(t!("config_editor.live_subtitles"));
+                ui.checkbox(&mut config.live_subtitles, "");
+                ui.end_row();
+            }
+
             if should_show_option(search, &t!("config_editor.champions_live_show_text")) {
                 ui.label(t!("config_editor.champions_live_show_text"));
                 ui.checkbox(&mut config.champions_live_show_text, "");
@@ -3166,6 +3604,30 @@ impl ConfigEditor {
                         });
                     ui.end_row();
                 }
+
+                if should_show_option(search, &t!("caption_styles.title")) {
+                    ui.label(t!("caption_styles.title"));
+                    if ui.button(t!("open")).clicked() {
+                        thread::spawn(|| {
+                            Gui::instance().unwrap()
+                            .lock().unwrap()
+                            .show_window(Box::new(CaptionStylesWindow::new()));
+                        });
+                    }
+                    ui.end_row();
+                }
+
+                for (category, shown_by_default) in captions::caption_rule_categories() {
+                    let label = Self::caption_category_label(&category);
+                    if should_show_option(search, &label) {
+                        ui.label(label);
+                        let mut shown = config.caption.caption_categories.get(&category).copied().unwrap_or(shown_by_default);
+                        if ui.checkbox(&mut shown, "").changed() {
+                            config.caption.caption_categories.insert(category, shown);
+                        }
+                        ui.end_row();
+                    }
+                }
             }
         }
         // Gameplay tab end
@@ -3339,15 +3801,32 @@ impl Window for ConfigEditor {
     }
 }
 
+fn format_config_load_errors(errors: &[config_profiles::ConfigLoadError]) -> String {
+    let mut text = t!("config_load_error.description").into_owned();
+    for error in errors {
+        text.push_str("\n\n");
+        text.push_str(&t!("config_load_error.file", path = error.path.display().to_string()));
+        if let Some(field) = &error.field {
+            text.push('\n');
+            text.push_str(&t!("config_load_error.field", field = field));
+        }
+        text.push('\n');
+        text.push_str(&t!("config_load_error.reason", reason = error.message));
+        if let Some(backup_path) = &error.backup_path {
+            text.push('\n');
+            text.push_str(&t!("config_load_error.backup", path = backup_path.display().to_string()));
+        }
+    }
+    text
+}
+
 fn save_and_reload_config(config: hachimi::Config) {
     let notif = match Hachimi::instance().save_and_reload_config(config) {
         Ok(_) => {
-            #[cfg(target_os = "windows")]
-            {
-                if Hachimi::instance().game.region != Region::Global {
-                    crate::windows::wnd_hook::apply_freeform_window_config();
-                    free_camera::reload_runtime_config();
-                }
+            if Hachimi::instance().game.region != Region::Global {
+                #[cfg(target_os = "windows")]
+                crate::windows::wnd_hook::apply_freeform_window_config();
+                free_camera::reload_runtime_config();
             }
             t!("notification.config_saved").into_owned()
         },
@@ -3363,6 +3842,185 @@ fn save_and_reload_config(config: hachimi::Config) {
     });
 }
 
+struct ConfigProfilesWindow {
+    id: egui::Id,
+    profiles: Vec<String>,
+    active: Option<String>,
+    new_name: String,
+    import_path: String
+}
+
+impl ConfigProfilesWindow {
+    const EXPORTS_DIR: &'static str = "config_profile_exports";
+
+    fn new() -> ConfigProfilesWindow {
+        let hachimi = Hachimi::instance();
+        ConfigProfilesWindow {
+            id: random_id(),
+            profiles: config_profiles::list_profiles(&hachimi.game.data_dir),
+            active: hachimi.config.load().config_profile.clone(),
+            new_name: String::new(),
+            import_path: hachimi.get_data_path(Self::EXPORTS_DIR).display().to_string()
+        }
+    }
+
+    fn refresh(&mut self) {
+        let hachimi = Hachimi::instance();
+        self.profiles = config_profiles::list_profiles(&hachimi.game.data_dir);
+        self.active = hachimi.config.load().config_profile.clone();
+    }
+
+    fn notify(message: String) {
+        thread::spawn(move || {
+            Gui::instance().unwrap()
+                .lock().unwrap()
+                .show_notification(&message);
+        });
+    }
+
+    fn switch_to(name: Option<&str>) {
+        let hachimi = Hachimi::instance();
+        let message = match hachimi.set_config_profile(name) {
+            Ok(()) => {
+                if hachimi.game.region != Region::Global {
+                    #[cfg(target_os = "windows")]
+                    crate::windows::wnd_hook::apply_freeform_window_config();
+                    free_camera::reload_runtime_config();
+                }
+                let name = name.map(|s| s.to_owned()).unwrap_or_else(|| t!("config_profiles.base").into_owned());
+                t!("config_profiles.switched", name = name).into_owned()
+            },
+            Err(e) => e.to_string()
+        };
+        Self::notify(message);
+    }
+
+    fn export(name: &str) {
+        let hachimi = Hachimi::instance();
+        let dir = hachimi.get_data_path(Self::EXPORTS_DIR);
+        let path = dir.join(format!("{}.json", name));
+        let res = std::fs::create_dir_all(&dir).map_err(super::Error::from).and_then(|_| {
+            config_profiles::export_profile(&hachimi.game.data_dir, &hachimi.game.region, name, &path)
+        });
+        Self::notify(match res {
+            Ok(()) => t!("config_profiles.exported", path = path.display().to_string()).into_owned(),
+            Err(e) => e.to_string()
+        });
+    }
+
+    fn run_profiles_grid(&mut self, ui: &mut egui::Ui) {
+        let hachimi = Hachimi::instance();
+        let mut switch: Option<Option<String>> = None;
+        let mut delete = None;
+
+        let entries = std::iter::once(None).chain(self.profiles.iter().map(|s| Some(s.as_str())));
+        for entry in entries {
+            let label = entry.map(Cow::Borrowed).unwrap_or_else(|| t!("config_profiles.base"));
+            let is_active = entry == self.active.as_deref();
+            if is_active {
+                ui.strong(format!("\u{f00c} {}", label));
+            }
+            else {
+                ui.label(label.as_ref());
+            }
+
+            ui.horizontal(|ui| {
+                if ui.add_enabled(!is_active, egui::Button::new(t!("config_profiles.activate"))).clicked() {
+                    switch = Some(entry.map(|s| s.to_owned()));
+                }
+                if let Some(name) = entry {
+                    if ui.button(t!("config_profiles.export")).clicked() {
+                        Self::export(name);
+                    }
+                    if ui.add_enabled(!is_active, egui::Button::new("\u{f1f8}")).clicked() {
+                        delete = Some(name.to_owned());
+                    }
+                }
+            });
+            ui.end_row();
+        }
+
+        if let Some(name) = switch {
+            Self::switch_to(name.as_deref());
+            self.refresh();
+        }
+        if let Some(name) = delete {
+            if let Err(e) = config_profiles::delete_profile(&hachimi.game.data_dir, &name) {
+                Self::notify(e.to_string());
+            }
+            self.refresh();
+        }
+    }
+}
+
+impl Window for ConfigProfilesWindow {
+    fn run(&mut self, ctx: &egui::Context) -> bool {
+        let scale = get_scale(ctx);
+        let mut open = true;
+        let mut open2 = true;
+
+        new_window(ctx, self.id, t!("config_profiles.title"))
+        .default_width(380.0 * scale)
+        .open(&mut open)
+        .show(ctx, |ui| {
+            simple_window_layout(ui, self.id,
+                |ui| {
+                    let hachimi = Hachimi::instance();
+                    ui.label(t!("config_profiles.description", region = hachimi.game.region.to_string().to_lowercase()));
+                    ui.separator();
+
+                    egui::Grid::new(self.id.with("profiles_grid"))
+                    .striped(true)
+                    .num_columns(2)
+                    .spacing([16.0 * scale, 4.0 * scale])
+                    .show(ui, |ui| self.run_profiles_grid(ui));
+
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        ui.add(egui::TextEdit::singleline(&mut self.new_name)
+                            .hint_text(t!("config_profiles.new_name"))
+                            .desired_width(160.0 * scale));
+                        let valid = config_profiles::is_valid_profile_name(&self.new_name);
+                        if ui.add_enabled(valid, egui::Button::new(t!("config_profiles.create"))).clicked() {
+                            match config_profiles::create_profile(&hachimi.game.data_dir, &self.new_name) {
+                                Ok(()) => self.new_name.clear(),
+                                Err(e) => Self::notify(e.to_string())
+                            }
+                            self.refresh();
+                        }
+                    });
+
+                    ui.horizontal(|ui| {
+                        ui.add(egui::TextEdit::singleline(&mut self.import_path)
+                            .hint_text(t!("config_profiles.import_path"))
+                            .desired_width(160.0 * scale));
+                        if ui.button(t!("config_profiles.import")).clicked() {
+                            let res = config_profiles::import_profile(
+                                &hachimi.game.data_dir, &hachimi.game.region, std::path::Path::new(&self.import_path), None
+                            );
+                            Self::notify(match res {
+                                Ok(name) => t!("config_profiles.imported", name = name).into_owned(),
+                                Err(e) => e.to_string()
+                            });
+                            self.refresh();
+                        }
+                    });
+                },
+                |ui| {
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+                        if ui.button(t!("close")).clicked() {
+                            open2 = false;
+                        }
+                    });
+                }
+            );
+        });
+
+        open &= open2;
+        open
+    }
+}
+
 struct FirstTimeSetupWindow {
     id: egui::Id,
     meta_index_url: String,
@@ -3669,128 +4327,530 @@ impl Window for LiveVocalsSwapWindow {
     }
 }
 
-#[cfg(target_os = "windows")]
-struct FreeCameraSettingsWindow {
+struct LiveProfilesWindow {
     id: egui::Id,
-    config: hachimi::Config,
+    profiles: live_profiles::LiveProfiles,
+    master: live_profiles::MasterIds,
+    chara_choices: Vec<(i32, String)>,
+    dress_choices: Vec<(i32, String)>,
+    song_names: HashMap<i32, Option<String>>,
+    selected: Option<usize>,
+    errors: Vec<String>,
+    search_term: String
 }
 
-#[cfg(target_os = "windows")]
-impl FreeCameraSettingsWindow {
-    fn new() -> FreeCameraSettingsWindow {
-        FreeCameraSettingsWindow {
+impl LiveProfilesWindow {
+    const MAX_MEMBERS: usize = 18;
+
+    fn new() -> LiveProfilesWindow {
+        let hachimi = Hachimi::instance();
+        let master = live_profiles::MasterIds::load();
+
+        let mut chara_choices: Vec<(i32, String)> = vec![
+            (0, t!("default").into_owned()),
+            (live_profiles::MOB_CHARA_ID, t!("live_profiles.mob").into_owned())
+        ];
+        let data = hachimi.chara_data.load();
+        let mut chara_ids: Vec<i32> = master.chara_ids.iter().copied().collect();
+        chara_ids.sort_unstable();
+        for id in chara_ids {
+            chara_choices.push((id, data.get_name(id)));
+        }
+
+        let mut dress_choices: Vec<(i32, String)> = vec![(0, t!("default").into_owned())];
+        dress_choices.extend(master.live_dress_ids.iter().map(|&id| (id, id.to_string())));
+
+        let profiles = live_profiles::profiles();
+        LiveProfilesWindow {
             id: random_id(),
-            config: (**Hachimi::instance().config.load()).clone(),
+            selected: if profiles.profiles.is_empty() { None } else { Some(0) },
+            profiles,
+            master,
+            chara_choices,
+            dress_choices,
+            song_names: HashMap::new(),
+            errors: Vec::new(),
+            search_term: String::new()
         }
     }
 
-    fn keybind_row(
-        ui: &mut egui::Ui,
-        label: Cow<'static, str>,
-        key: u16,
-        setter: fn(&mut free_camera::FreeCameraKeybinds, u16),
-    ) {
-        ui.label(label);
-        ui.horizontal(|ui| {
-            ui.label(crate::windows::utils::vk_to_display_label(key));
-            if ui.button(t!("bind_key")).clicked() {
-                Self::open_keybind_window(setter);
-            }
+    fn song_name(&mut self, music_id: i32) -> Option<&str> {
+        self.song_names
+            .entry(music_id)
+            .or_insert_with(|| crate::il2cpp::sql::get_master_text(16, music_id))
+            .as_deref()
+    }
+
+    fn new_profile(&mut self) {
+        let music_id = free_camera::live_music_id();
+        self.profiles.profiles.push(live_profiles::LiveProfile {
+            name: t!("live_profiles.default_name", index = self.profiles.profiles.len() + 1).into_owned(),
+            music_id: music_id.max(0),
+            members: vec![live_profiles::LiveMemberOverride::default(); 5],
+            vocals: [0; 6]
         });
-        ui.end_row();
+        self.selected = Some(self.profiles.profiles.len() - 1);
     }
 
-    fn open_keybind_window(setter: fn(&mut free_camera::FreeCameraKeybinds, u16)) {
-        thread::spawn(move || {
-            let Some(gui_mutex) = Gui::instance() else { return };
-            let mut gui = gui_mutex.lock().unwrap();
-            gui.show_window(Box::new(SetKeybindWindow::new(move |result| {
-                let Some(raw) = result else { return };
+    fn validate(&self) -> Vec<String> {
+        let mut errors: Vec<String> = self.profiles.profiles.iter()
+            .flat_map(|profile| self.master.validate(profile))
+            .collect();
 
-                let hachimi = Hachimi::instance();
-                let mut new_config = hachimi.config.load().as_ref().clone();
-                setter(&mut new_config.windows.free_camera.keybinds, raw);
-                save_and_reload_config(new_config);
-            })));
+        let mut seen = FnvHashSet::default();
+        for profile in &self.profiles.profiles {
+            if !seen.insert((profile.music_id, profile.name.as_str())) {
+                errors.push(t!("live_profiles.error_duplicate_name", name = profile.name.as_str()).into_owned());
+            }
+        }
+        errors
+    }
+
+    fn run_profile_editor(&mut self, ui: &mut egui::Ui, index: usize, scale: f32) {
+        let music_id = self.profiles.profiles[index].music_id;
+        let song_name = self.song_name(music_id).map(|name| name.to_owned());
+
+        let Self { profiles, chara_choices, dress_choices, search_term, id, .. } = self;
+        let live_profiles::LiveProfiles { profiles, active } = profiles;
+        let profile = &mut profiles[index];
+
+        let chara_items: Vec<(i32, &str)> = chara_choices.iter().map(|(id, name)| (*id, name.as_str())).collect();
+        let dress_items: Vec<(i32, &str)> = dress_choices.iter().map(|(id, name)| (*id, name.as_str())).collect();
+        let vocal_items: Vec<(i32, &str)> = chara_items.iter().copied().filter(|&(id, _)| id != live_profiles::MOB_CHARA_ID).collect();
+
+        egui::Grid::new(id.with("profile_grid"))
+        .num_columns(2)
+        .spacing([40.0 * scale, 4.0 * scale])
+        .show(ui, |ui| {
+            let old_name = profile.name.clone();
+            ui.label(t!("live_profiles.name"));
+            if ui.text_edit_singleline(&mut profile.name).changed() && active.get(&profile.music_id) == Some(&old_name) {
+                active.insert(profile.music_id, profile.name.clone());
+            }
+            ui.end_row();
+
+            let old_music_id = profile.music_id;
+            ui.label(t!("live_profiles.music_id"));
+            ui.horizontal(|ui| {
+                if ui.add(egui::DragValue::new(&mut profile.music_id).range(0..=i32::MAX)).changed() &&
+                    active.get(&old_music_id) == Some(&profile.name)
+                {
+                    active.remove(&old_music_id);
+                }
+                let current = free_camera::live_music_id();
+                if current > 0 && ui.button(t!("live_profiles.use_current_live")).clicked() {
+                    if active.get(&profile.music_id) == Some(&profile.name) {
+                        active.remove(&profile.music_id);
+                    }
+                    profile.music_id = current;
+                }
+            });
+            ui.end_row();
+
+            ui.label("");
+            ui.label(song_name.as_deref().unwrap_or("???"));
+            ui.end_row();
+
+            ui.label(t!("live_profiles.active"));
+            let mut is_active = active.get(&profile.music_id) == Some(&profile.name);
+            if ui.checkbox(&mut is_active, "").changed() {
+                if is_active {
+                    active.insert(profile.music_id, profile.name.clone());
+                }
+                else {
+                    active.remove(&profile.music_id);
+                }
+            }
+            ui.end_row();
+        });
+
+        ui.add_space(8.0 * scale);
+        ui.heading(t!("live_profiles.members"));
+        ui.separator();
+
+        let mut remove = None;
+        egui::Grid::new(id.with("members_grid"))
+        .striped(true)
+        .num_columns(4)
+        .spacing([8.0 * scale, 4.0 * scale])
+        .show(ui, |ui| {
+            ui.label("");
+            ui.label(t!("live_profiles.character"));
+            ui.label(t!("live_profiles.dress"));
+            ui.label(t!("live_profiles.mob_id"));
+            ui.end_row();
+
+            for (i, member) in profile.members.iter_mut().enumerate() {
+                ui.horizontal(|ui| {
+                    if ui.button("\u{f00d}").clicked() {
+                        remove = Some(i);
+                    }
+                    ui.label(t!("live_profiles.slot_n", index = i + 1));
+                });
+                Gui::run_combo_menu(ui, id.with("member_chara").with(i), &mut member.chara_id, &chara_items, search_term);
+                Gui::run_combo_menu(ui, id.with("member_dress").with(i), &mut member.dress_id, &dress_items, search_term);
+                if member.chara_id == live_profiles::MOB_CHARA_ID {
+                    ui.add(egui::DragValue::new(&mut member.mob_id).range(0..=i32::MAX));
+                }
+                else {
+                    ui.label("");
+                }
+                ui.end_row();
+            }
+        });
+        if let Some(i) = remove {
+            profile.members.remove(i);
+        }
+        if profile.members.len() < Self::MAX_MEMBERS && ui.button(t!("live_profiles.add_member")).clicked() {
+            profile.members.push(live_profiles::LiveMemberOverride::default());
+        }
+
+        ui.add_space(8.0 * scale);
+        ui.heading(t!("live_profiles.vocals"));
+        ui.separator();
+
+        egui::Grid::new(id.with("vocals_grid"))
+        .striped(true)
+        .num_columns(2)
+        .spacing([40.0 * scale, 4.0 * scale])
+        .show(ui, |ui| {
+            for (i, vocal) in profile.vocals.iter_mut().enumerate() {
+                ui.label(t!("config_editor.live_vocals_swap_character_n", index = i + 1));
+                Gui::run_combo_menu(ui, id.with("vocal").with(i), vocal, &vocal_items, search_term);
+                ui.end_row();
+            }
         });
     }
 }
 
-#[cfg(target_os = "windows")]
-impl Window for FreeCameraSettingsWindow {
+impl Window for LiveProfilesWindow {
     fn run(&mut self, ctx: &egui::Context) -> bool {
         let scale = get_scale(ctx);
         let mut open = true;
         let mut open2 = true;
         let mut save_clicked = false;
-        let mut reset_clicked = false;
-
-        self.config.windows.free_camera.keybinds =
-            Hachimi::instance().config.load().windows.free_camera.keybinds.clone();
-
-        let mode_free = t!("free_camera.mode_free");
-        let mode_first_person = t!("free_camera.mode_first_person");
-        let mode_selfie_stick = t!("free_camera.mode_selfie_stick");
-        let mode_choices = [
-            (FreeCameraMode::Free, mode_free.as_ref()),
-            (FreeCameraMode::FirstPerson, mode_first_person.as_ref()),
-            (FreeCameraMode::SelfieStick, mode_selfie_stick.as_ref()),
-        ];
-        let live_position_choices: Vec<(i32, &str)> = free_camera::LIVE_POSITION_CHOICES
-            .iter()
-            .enumerate()
-            .map(|(i, (name, _))| (i as i32, *name))
-            .collect();
-        let live_part_choices: Vec<(i32, &str)> = free_camera::LIVE_PART_CHOICES
-            .iter()
-            .enumerate()
-            .map(|(i, (name, _))| (i as i32, *name))
-            .collect();
+        let mut reload_clicked = false;
 
-        new_window(ctx, self.id, t!("free_camera.settings_title"))
-        .default_width(340.0 * scale)
-        .max_width(360.0 * scale)
-        .max_height(430.0 * scale)
+        new_window(ctx, self.id, t!("live_profiles.title"))
+        .default_width(520.0 * scale)
+        .max_height(560.0 * scale)
         .open(&mut open)
         .show(ctx, |ui| {
-            simple_window_layout(ui, self.id, |ui| {
-                ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Wrap);
+            simple_window_layout(ui, self.id,
+                |ui| {
+                    ui.horizontal(|ui| {
+                        let selected_text = self.selected
+                            .and_then(|i| self.profiles.profiles.get(i))
+                            .map(|p| format!("{} ({})", p.name, p.music_id))
+                            .unwrap_or_default();
+                        egui::ComboBox::new(self.id.with("profile_select"), "")
+                            .selected_text(selected_text)
+                            .show_ui(ui, |ui| {
+                                for (i, profile) in self.profiles.profiles.iter().enumerate() {
+                                    ui.selectable_value(&mut self.selected, Some(i), format!("{} ({})", profile.name, profile.music_id));
+                                }
+                            });
+                        if ui.button(t!("live_profiles.new")).clicked() {
+                            self.new_profile();
+                        }
+                        if let Some(i) = self.selected {
+                            if ui.button(t!("live_profiles.delete")).clicked() {
+                                let removed = self.profiles.profiles.remove(i);
+                                if self.profiles.active.get(&removed.music_id) == Some(&removed.name) {
+                                    self.profiles.active.remove(&removed.music_id);
+                                }
+                                self.selected = if self.profiles.profiles.is_empty() {
+                                    None
+                                }
+                                else {
+                                    Some(i.min(self.profiles.profiles.len() - 1))
+                                };
+                            }
+                        }
+                    });
+                    ui.separator();
 
-                egui::ScrollArea::vertical()
-                    .id_salt(self.id.with("free_camera_settings_scroll"))
+                    egui::ScrollArea::vertical()
+                    .id_salt(self.id.with("profile_scroll"))
                     .auto_shrink([false, false])
                     .show(ui, |ui| {
-                        egui::Frame::NONE
-                        .inner_margin(egui::Margin::symmetric(8, 0))
-                        .show(ui, |ui| {
-                            egui::Grid::new(self.id.with("free_camera_settings_grid"))
-                            .striped(true)
-                            .num_columns(2)
-                            .spacing([40.0 * scale, 4.0 * scale])
-                            .show(ui, |ui| {
-                                let cfg = &mut self.config.windows.free_camera;
-
-                                ui.vertical(|ui| {
-                                    ui.heading(t!("free_camera.section_general"));
-                                    ui.separator();
-                                });
-                                ui.end_row();
-
-                                ui.label(t!("free_camera.remove_camera_effects"));
-                                ui.checkbox(&mut cfg.remove_camera_effects, "");
-                                ui.end_row();
-
-                                ui.label(t!("free_camera.show_overlay"));
-                                ui.checkbox(&mut cfg.show_overlay, "");
-                                ui.end_row();
-
-                                ui.label(t!("free_camera.selfie_use_head_transform"));
-                                ui.checkbox(&mut cfg.selfie_use_head_transform, "");
-                                ui.end_row();
+                        match self.selected {
+                            Some(i) if i < self.profiles.profiles.len() => self.run_profile_editor(ui, i, scale),
+                            _ => { ui.label(t!("live_profiles.no_profiles")); }
+                        }
 
-                                ui.label(t!("free_camera.mode"));
-                                Gui::run_combo(ui, "free_camera_mode", &mut cfg.mode, &mode_choices);
-                                ui.end_row();
+                        for error in &self.errors {
+                            ui.colored_label(egui::Color32::LIGHT_RED, error);
+                        }
+                    });
+                },
+                |ui| {
+                    ui.with_layout(egui::Layout::left_to_right(egui::Align::Min), |ui| {
+                        if ui.button(t!("live_profiles.reload")).clicked() {
+                            reload_clicked = true;
+                        }
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+                            if ui.button(t!("close")).clicked() {
+                                open2 = false;
+                            }
+                            if ui.button(t!("save")).clicked() {
+                                save_clicked = true;
+                            }
+                        });
+                    });
+                }
+            );
+        });
+
+        if reload_clicked {
+            live_profiles::reload_profiles();
+            self.profiles = live_profiles::profiles();
+            self.selected = if self.profiles.profiles.is_empty() { None } else { Some(0) };
+            self.errors.clear();
+        }
+
+        if save_clicked {
+            self.errors = self.validate();
+            if self.errors.is_empty() {
+                let message = match live_profiles::save_profiles(self.profiles.clone()) {
+                    Ok(path) => t!("live_profiles.saved", path = path.display().to_string()).into_owned(),
+                    Err(e) => e.to_string()
+                };
+                thread::spawn(move || {
+                    Gui::instance().unwrap()
+                        .lock().unwrap()
+                        .show_notification(&message);
+                });
+            }
+        }
+
+        open &= open2;
+        open
+    }
+}
+
+struct LivePlaybackKeybindsWindow {
+    id: egui::Id
+}
+
+impl LivePlaybackKeybindsWindow {
+    fn new() -> LivePlaybackKeybindsWindow {
+        LivePlaybackKeybindsWindow {
+            id: random_id()
+        }
+    }
+
+    fn set_keybind(setter: fn(&mut live_utils::LivePlaybackKeybinds, u16), key: u16) {
+        let mut new_config = Hachimi::instance().config.load().as_ref().clone();
+        setter(&mut new_config.live_playback_keybinds, key);
+        save_and_reload_config(new_config);
+    }
+
+    fn keybind_row(
+        ui: &mut egui::Ui,
+        label: Cow<'static, str>,
+        key: u16,
+        setter: fn(&mut live_utils::LivePlaybackKeybinds, u16),
+    ) {
+        ui.label(label);
+        ui.horizontal(|ui| {
+            if key == 0 {
+                ui.label(t!("live_playback.unbound"));
+            }
+            else {
+                #[cfg(target_os = "windows")]
+                ui.label(crate::windows::utils::vk_to_display_label(key));
+                #[cfg(target_os = "android")]
+                ui.label(crate::android::gui_impl::keymap::keycode_display_label(key as i32));
+            }
+
+            if ui.button(t!("bind_key")).clicked() {
+                thread::spawn(move || {
+                    let Some(gui_mutex) = Gui::instance() else { return };
+                    gui_mutex.lock().unwrap().show_window(Box::new(SetKeybindWindow::new(move |result| {
+                        let Some(raw) = result else { return };
+                        #[cfg(target_os = "android")]
+                        let raw = raw as u16;
+                        Self::set_keybind(setter, raw);
+                    })));
+                });
+            }
+            if key != 0 && ui.button("\u{f00d}").clicked() {
+                Self::set_keybind(setter, 0);
+            }
+        });
+        ui.end_row();
+    }
+}
+
+impl Window for LivePlaybackKeybindsWindow {
+    fn run(&mut self, ctx: &egui::Context) -> bool {
+        let scale = get_scale(ctx);
+        let mut open = true;
+        let mut open2 = true;
+        let keybinds = Hachimi::instance().config.load().live_playback_keybinds.clone();
+
+        new_window(ctx, self.id, t!("live_playback.keybinds_title"))
+        .open(&mut open)
+        .show(ctx, |ui| {
+            simple_window_layout(ui, self.id,
+                |ui| {
+                    egui::Grid::new(self.id.with("keybinds_grid"))
+                    .striped(true)
+                    .num_columns(2)
+                    .spacing([40.0 * scale, 4.0 * scale])
+                    .show(ui, |ui| {
+                        macro_rules! keybind_row {
+                            ($field:ident, $key:literal) => {{
+                                let setter: fn(&mut live_utils::LivePlaybackKeybinds, u16) =
+                                    |keybinds, raw| keybinds.$field = raw;
+                                Self::keybind_row(ui, t!($key), keybinds.$field, setter);
+                            }};
+                        }
+
+                        keybind_row!(set_loop_a, "live_playback.key_set_loop_a");
+                        keybind_row!(set_loop_b, "live_playback.key_set_loop_b");
+                        keybind_row!(clear_loop, "live_playback.key_clear_loop");
+                        keybind_row!(speed_down, "live_playback.key_speed_down");
+                        keybind_row!(speed_up, "live_playback.key_speed_up");
+                        keybind_row!(step_back, "live_playback.key_step_back");
+                        keybind_row!(step_forward, "live_playback.key_step_forward");
+                    });
+                },
+                |ui| {
+                    if ui.button(t!("close")).clicked() {
+                        open2 = false;
+                    }
+                }
+            );
+        });
+
+        open &= open2;
+        open
+    }
+}
+
+struct FreeCameraSettingsWindow {
+    id: egui::Id,
+    config: hachimi::Config,
+}
+
+impl FreeCameraSettingsWindow {
+    fn new() -> FreeCameraSettingsWindow {
+        FreeCameraSettingsWindow {
+            id: random_id(),
+            config: (**Hachimi::instance().config.load()).clone(),
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    fn keybind_row(
+        ui: &mut egui::Ui,
+        label: Cow<'static, str>,
+        key: u16,
+        setter: fn(&mut free_camera::FreeCameraKeybinds, u16),
+    ) {
+        ui.label(label);
+        ui.horizontal(|ui| {
+            ui.label(crate::windows::utils::vk_to_display_label(key));
+            if ui.button(t!("bind_key")).clicked() {
+                Self::open_keybind_window(setter);
+            }
+        });
+        ui.end_row();
+    }
+
+    #[cfg(target_os = "windows")]
+    fn open_keybind_window(setter: fn(&mut free_camera::FreeCameraKeybinds, u16)) {
+        thread::spawn(move || {
+            let Some(gui_mutex) = Gui::instance() else { return };
+            let mut gui = gui_mutex.lock().unwrap();
+            gui.show_window(Box::new(SetKeybindWindow::new(move |result| {
+                let Some(raw) = result else { return };
+
+                let hachimi = Hachimi::instance();
+                let mut new_config = hachimi.config.load().as_ref().clone();
+                setter(&mut new_config.free_camera.keybinds, raw);
+                save_and_reload_config(new_config);
+            })));
+        });
+    }
+}
+
+impl Window for FreeCameraSettingsWindow {
+    fn run(&mut self, ctx: &egui::Context) -> bool {
+        let scale = get_scale(ctx);
+        let mut open = true;
+        let mut open2 = true;
+        let mut save_clicked = false;
+        let mut reset_clicked = false;
+
+        self.config.free_camera.keybinds =
+            Hachimi::instance().config.load().free_camera.keybinds.clone();
+
+        let mode_free = t!("free_camera.mode_free");
+        let mode_first_person = t!("free_camera.mode_first_person");
+        let mode_selfie_stick = t!("free_camera.mode_selfie_stick");
+        let mode_choices = [
+            (FreeCameraMode::Free, mode_free.as_ref()),
+            (FreeCameraMode::FirstPerson, mode_first_person.as_ref()),
+            (FreeCameraMode::SelfieStick, mode_selfie_stick.as_ref()),
+        ];
+        let live_position_choices: Vec<(i32, &str)> = free_camera::LIVE_POSITION_CHOICES
+            .iter()
+            .enumerate()
+            .map(|(i, (name, _))| (i as i32, *name))
+            .collect();
+        let live_part_choices: Vec<(i32, &str)> = free_camera::LIVE_PART_CHOICES
+            .iter()
+            .enumerate()
+            .map(|(i, (name, _))| (i as i32, *name))
+            .collect();
+
+        new_window(ctx, self.id, t!("free_camera.settings_title"))
+        .default_width(340.0 * scale)
+        .max_width(360.0 * scale)
+        .max_height(430.0 * scale)
+        .open(&mut open)
+        .show(ctx, |ui| {
+            simple_window_layout(ui, self.id, |ui| {
+                ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Wrap);
+
+                egui::ScrollArea::vertical()
+                    .id_salt(self.id.with("free_camera_settings_scroll"))
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        egui::Frame::NONE
+                        .inner_margin(egui::Margin::symmetric(8, 0))
+                        .show(ui, |ui| {
+                            egui::Grid::new(self.id.with("free_camera_settings_grid"))
+                            .striped(true)
+                            .num_columns(2)
+                            .spacing([40.0 * scale, 4.0 * scale])
+                            .show(ui, |ui| {
+                                let cfg = &mut self.config.free_camera;
+
+                                ui.vertical(|ui| {
+                                    ui.heading(t!("free_camera.section_general"));
+                                    ui.separator();
+                                });
+                                ui.end_row();
+
+                                ui.label(t!("free_camera.remove_camera_effects"));
+                                ui.checkbox(&mut cfg.remove_camera_effects, "");
+                                ui.end_row();
+
+                                ui.label(t!("free_camera.show_overlay"));
+                                ui.checkbox(&mut cfg.show_overlay, "");
+                                ui.end_row();
+
+                                ui.label(t!("free_camera.selfie_use_head_transform"));
+                                ui.checkbox(&mut cfg.selfie_use_head_transform, "");
+                                ui.end_row();
+
+                                ui.label(t!("free_camera.mode"));
+                                Gui::run_combo(ui, "free_camera_mode", &mut cfg.mode, &mode_choices);
+                                ui.end_row();
 
                                 ui.label(t!("free_camera.live_move_step"));
                                 ui.add(egui::DragValue::new(&mut cfg.live_move_step).speed(0.01).range(0.001..=100.0));
@@ -3804,9 +4864,23 @@ impl Window for FreeCameraSettingsWindow {
                                 ui.add(egui::DragValue::new(&mut cfg.look_step).speed(0.05).range(0.001..=30.0));
                                 ui.end_row();
 
-                                ui.label(t!("free_camera.mouse_speed"));
-                                ui.add(egui::DragValue::new(&mut cfg.mouse_speed).speed(1.0).range(1.0..=1000.0));
-                                ui.end_row();
+                                #[cfg(target_os = "windows")]
+                                {
+                                    ui.label(t!("free_camera.mouse_speed"));
+                                    ui.add(egui::DragValue::new(&mut cfg.mouse_speed).speed(1.0).range(1.0..=1000.0));
+                                    ui.end_row();
+                                }
+
+                                #[cfg(target_os = "android")]
+                                {
+                                    ui.label(t!("free_camera.touch_look_speed"));
+                                    ui.add(egui::DragValue::new(&mut cfg.touch_look_speed).speed(0.5).range(0.1..=1000.0));
+                                    ui.end_row();
+
+                                    ui.label(t!("free_camera.touch_move_speed"));
+                                    ui.add(egui::DragValue::new(&mut cfg.touch_move_speed).speed(0.05).range(0.01..=100.0));
+                                    ui.end_row();
+                                }
 
                                 ui.label(t!("free_camera.live_fov"));
                                 ui.add(egui::DragValue::new(&mut cfg.live_fov).speed(0.5).range(1.0..=120.0));
@@ -3875,6 +4949,10 @@ impl Window for FreeCameraSettingsWindow {
                                 ui.add(egui::DragValue::new(&mut cfg.live_follow_smooth_lookat_step).speed(0.01).range(0.02..=1.0));
                                 ui.end_row();
 
+                                ui.label(t!("free_camera.camera_path_record_interval"));
+                                ui.add(egui::DragValue::new(&mut cfg.camera_path_record_interval).speed(0.05).range(0.05..=10.0).suffix("s"));
+                                ui.end_row();
+
                                 ui.vertical(|ui| {
                                     ui.add_space(8.0 * scale);
                                     ui.heading(t!("free_camera.section_race"));
@@ -3886,49 +4964,52 @@ impl Window for FreeCameraSettingsWindow {
                                 ui.add(egui::DragValue::new(&mut cfg.race_target_index).speed(1.0).range(-1..=17));
                                 ui.end_row();
 
-                                ui.vertical(|ui| {
-                                    ui.add_space(8.0 * scale);
-                                    ui.heading(t!("free_camera.section_keybinds"));
-                                    ui.separator();
-                                });
-                                ui.end_row();
+                                #[cfg(target_os = "windows")]
+                                {
+                                    ui.vertical(|ui| {
+                                        ui.add_space(8.0 * scale);
+                                        ui.heading(t!("free_camera.section_keybinds"));
+                                        ui.separator();
+                                    });
+                                    ui.end_row();
 
-                                macro_rules! keybind_row {
-                                    ($field:ident, $key:literal) => {{
-                                        let setter: fn(&mut free_camera::FreeCameraKeybinds, u16) =
-                                            |keybinds, raw| keybinds.$field = raw;
-                                        Self::keybind_row(
-                                            ui,
-                                            t!($key),
-                                            cfg.keybinds.$field,
-                                            setter,
-                                        );
-                                    }};
-                                }
+                                    macro_rules! keybind_row {
+                                        ($field:ident, $key:literal) => {{
+                                            let setter: fn(&mut free_camera::FreeCameraKeybinds, u16) =
+                                                |keybinds, raw| keybinds.$field = raw;
+                                            Self::keybind_row(
+                                                ui,
+                                                t!($key),
+                                                cfg.keybinds.$field,
+                                                setter,
+                                            );
+                                        }};
+                                    }
 
-                                keybind_row!(move_forward, "free_camera.key_move_forward");
-                                keybind_row!(move_back, "free_camera.key_move_back");
-                                keybind_row!(move_left, "free_camera.key_move_left");
-                                keybind_row!(move_right, "free_camera.key_move_right");
-                                keybind_row!(move_down, "free_camera.key_move_down");
-                                keybind_row!(move_up, "free_camera.key_move_up");
-                                keybind_row!(look_up, "free_camera.key_look_up");
-                                keybind_row!(look_down, "free_camera.key_look_down");
-                                keybind_row!(look_left, "free_camera.key_look_left");
-                                keybind_row!(look_right, "free_camera.key_look_right");
-                                keybind_row!(fov_increase, "free_camera.key_fov_increase");
-                                keybind_row!(fov_decrease, "free_camera.key_fov_decrease");
-                                keybind_row!(follow_offset_up, "free_camera.key_follow_offset_up");
-                                keybind_row!(follow_offset_down, "free_camera.key_follow_offset_down");
-                                keybind_row!(follow_offset_left, "free_camera.key_follow_offset_left");
-                                keybind_row!(follow_offset_right, "free_camera.key_follow_offset_right");
-                                keybind_row!(target_previous, "free_camera.key_target_previous");
-                                keybind_row!(target_next, "free_camera.key_target_next");
-                                keybind_row!(part_previous, "free_camera.key_part_previous");
-                                keybind_row!(part_next, "free_camera.key_part_next");
-                                keybind_row!(reset, "free_camera.key_reset");
-                                keybind_row!(cycle_mode, "free_camera.key_cycle_mode");
-                                keybind_row!(reverse, "free_camera.key_reverse");
+                                    keybind_row!(move_forward, "free_camera.key_move_forward");
+                                    keybind_row!(move_back, "free_camera.key_move_back");
+                                    keybind_row!(move_left, "free_camera.key_move_left");
+                                    keybind_row!(move_right, "free_camera.key_move_right");
+                                    keybind_row!(move_down, "free_camera.key_move_down");
+                                    keybind_row!(move_up, "free_camera.key_move_up");
+                                    keybind_row!(look_up, "free_camera.key_look_up");
+                                    keybind_row!(look_down, "free_camera.key_look_down");
+                                    keybind_row!(look_left, "free_camera.key_look_left");
+                                    keybind_row!(look_right, "free_camera.key_look_right");
+                                    keybind_row!(fov_increase, "free_camera.key_fov_increase");
+                                    keybind_row!(fov_decrease, "free_camera.key_fov_decrease");
+                                    keybind_row!(follow_offset_up, "free_camera.key_follow_offset_up");
+                                    keybind_row!(follow_offset_down, "free_camera.key_follow_offset_down");
+                                    keybind_row!(follow_offset_left, "free_camera.key_follow_offset_left");
+                                    keybind_row!(follow_offset_right, "free_camera.key_follow_offset_right");
+                                    keybind_row!(target_previous, "free_camera.key_target_previous");
+                                    keybind_row!(target_next, "free_camera.key_target_next");
+                                    keybind_row!(part_previous, "free_camera.key_part_previous");
+                                    keybind_row!(part_next, "free_camera.key_part_next");
+                                    keybind_row!(reset, "free_camera.key_reset");
+                                    keybind_row!(cycle_mode, "free_camera.key_cycle_mode");
+                                    keybind_row!(reverse, "free_camera.key_reverse");
+                                }
 
                                 ui.horizontal(|ui| ui.add_space(130.0 * scale));
                                 ui.horizontal(|ui| ui.add_space(130.0 * scale));
@@ -3957,7 +5038,7 @@ impl Window for FreeCameraSettingsWindow {
         );
 
         if reset_clicked {
-            self.config.windows.free_camera = free_camera::FreeCameraConfig::default();
+            self.config.free_camera = free_camera::FreeCameraConfig::default();
         }
 
         if save_clicked {
@@ -3969,27 +5050,496 @@ impl Window for FreeCameraSettingsWindow {
     }
 }
 
-struct ThemeEditorWindow {
+struct FreeCameraPresetsWindow {
     id: egui::Id,
-    config: hachimi::Config,
-    old_config: hachimi::Config
+    presets: free_camera::FreeCameraPresets,
+    new_name: String,
+    /// Filled in by the keybind window with (preset index, key)
+    bound_hotkey: Arc<Mutex<Option<(usize, u16)>>>
 }
 
-impl ThemeEditorWindow {
-    fn new() -> ThemeEditorWindow {
-        let current_cfg = (**Hachimi::instance().config.load()).clone();
-        ThemeEditorWindow {
+impl FreeCameraPresetsWindow {
+    fn new() -> FreeCameraPresetsWindow {
+        FreeCameraPresetsWindow {
             id: random_id(),
-            config: current_cfg.clone(),
-            old_config: current_cfg
+            presets: free_camera::presets(),
+            new_name: String::new(),
+            bound_hotkey: Arc::new(Mutex::new(None))
         }
     }
-}
 
-fn theme_color_row(ui: &mut egui::Ui, label: &str, color: &mut egui::Color32) -> bool {
-    let mut changed = false;
+    fn bind_hotkey(bound_hotkey: Arc<Mutex<Option<(usize, u16)>>>, index: usize) {
+        bound_hotkey.lock().unwrap().take();
+        thread::spawn(move || {
+            let Some(gui_mutex) = Gui::instance() else { return };
+            gui_mutex.lock().unwrap().show_window(Box::new(SetKeybindWindow::new(move |result| {
+                if let Some(raw) = result {
+                    #[cfg(target_os = "android")]
+                    let raw = raw as u16;
+                    *bound_hotkey.lock().unwrap() = Some((index, raw));
+                }
+            })));
+        });
+    }
 
-    ui.columns(2, |cols| {
+    fn preset_combo(ui: &mut egui::Ui, id_child: impl std::hash::Hash, value: &mut Option<String>, names: &[String]) {
+        let none_label = t!("free_camera.preset_none");
+        egui::ComboBox::new(ui.id().with(id_child), "")
+            .selected_text(value.as_deref().unwrap_or(&none_label))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(value, None, &*none_label);
+                for name in names {
+                    ui.selectable_value(value, Some(name.clone()), name);
+                }
+            });
+    }
+
+    fn run_presets_grid(&mut self, ui: &mut egui::Ui) {
+        let mut remove = None;
+        for (i, preset) in self.presets.presets.iter_mut().enumerate() {
+            ui.text_edit_singleline(&mut preset.name);
+            ui.horizontal(|ui| {
+                if ui.button(t!("free_camera.preset_apply")).clicked() {
+                    free_camera::apply_preset(preset);
+                }
+                if ui.button(t!("free_camera.preset_update")).clicked() {
+                    let hotkey = preset.hotkey;
+                    *preset = free_camera::current_preset(preset.name.clone());
+                    preset.hotkey = hotkey;
+                }
+                let hotkey_label = if preset.hotkey == 0 {
+                    t!("free_camera.preset_no_hotkey").into_owned()
+                }
+                else {
+                    #[cfg(target_os = "windows")]
+                    { crate::windows::utils::vk_to_display_label(preset.hotkey) }
+                    #[cfg(target_os = "android")]
+                    { crate::android::gui_impl::keymap::keycode_display_label(preset.hotkey as i32) }
+                };
+                if ui.button(hotkey_label).clicked() {
+                    Self::bind_hotkey(self.bound_hotkey.clone(), i);
+                }
+                if preset.hotkey != 0 && ui.button("\u{f00d}").clicked() {
+                    preset.hotkey = 0;
+                }
+                if ui.button(t!("free_camera.preset_delete")).clicked() {
+                    remove = Some(i);
+                }
+            });
+            ui.end_row();
+        }
+        if let Some(i) = remove {
+            self.presets.presets.remove(i);
+        }
+
+        ui.text_edit_singleline(&mut self.new_name);
+        if ui.add_enabled(!self.new_name.is_empty(), egui::Button::new(t!("free_camera.preset_save_current"))).clicked() {
+            let preset = free_camera::current_preset(std::mem::take(&mut self.new_name));
+            match self.presets.presets.iter_mut().find(|p| p.name == preset.name) {
+                Some(existing) => *existing = preset,
+                None => self.presets.presets.push(preset)
+            }
+        }
+        ui.end_row();
+    }
+
+    fn run_auto_apply_grid(&mut self, ui: &mut egui::Ui) {
+        let names: Vec<String> = self.presets.presets.iter().map(|p| p.name.clone()).collect();
+
+        ui.label(t!("free_camera.preset_race"));
+        Self::preset_combo(ui, "race_preset", &mut self.presets.race_preset, &names);
+        ui.end_row();
+
+        let music_id = free_camera::live_music_id();
+        if music_id != 0 {
+            ui.label(t!("free_camera.preset_current_live", music_id = music_id));
+            let mut value = self.presets.live_presets.get(&music_id).cloned();
+            Self::preset_combo(ui, "current_live_preset", &mut value, &names);
+            match value {
+                Some(name) => { self.presets.live_presets.insert(music_id, name); },
+                None => { self.presets.live_presets.remove(&music_id); }
+            }
+            ui.end_row();
+        }
+
+        let mut live_ids: Vec<i32> = self.presets.live_presets.keys().copied().filter(|id| *id != music_id).collect();
+        live_ids.sort();
+        for id in live_ids {
+            ui.label(t!("free_camera.preset_live", music_id = id));
+            ui.horizontal(|ui| {
+                ui.label(self.presets.live_presets[&id].as_str());
+                if ui.button("\u{f00d}").clicked() {
+                    self.presets.live_presets.remove(&id);
+                }
+            });
+            ui.end_row();
+        }
+    }
+}
+
+impl Window for FreeCameraPresetsWindow {
+    fn run(&mut self, ctx: &egui::Context) -> bool {
+        let scale = get_scale(ctx);
+        let mut open = true;
+        let mut open2 = true;
+        let mut save_clicked = false;
+        let mut reload_clicked = false;
+
+        if let Some((index, key)) = self.bound_hotkey.lock().unwrap().take() {
+            if let Some(preset) = self.presets.presets.get_mut(index) {
+                preset.hotkey = key;
+            }
+        }
+
+        new_window(ctx, self.id, t!("free_camera.presets_title"))
+        .default_width(420.0 * scale)
+        .max_height(480.0 * scale)
+        .open(&mut open)
+        .show(ctx, |ui| {
+            simple_window_layout(ui, self.id,
+                |ui| {
+                    egui::ScrollArea::vertical()
+                    .id_salt(self.id.with("presets_scroll"))
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        ui.heading(t!("free_camera.presets_title"));
+                        ui.separator();
+                        egui::Grid::new(self.id.with("presets_grid"))
+                        .striped(true)
+                        .num_columns(2)
+                        .spacing([16.0 * scale, 4.0 * scale])
+                        .show(ui, |ui| self.run_presets_grid(ui));
+
+                        ui.add_space(8.0 * scale);
+                        ui.heading(t!("free_camera.preset_auto_apply"));
+                        ui.separator();
+                        egui::Grid::new(self.id.with("presets_auto_grid"))
+                        .striped(true)
+                        .num_columns(2)
+                        .spacing([40.0 * scale, 4.0 * scale])
+                        .show(ui, |ui| self.run_auto_apply_grid(ui));
+                    });
+                },
+                |ui| {
+                    ui.with_layout(egui::Layout::left_to_right(egui::Align::Min), |ui| {
+                        if ui.button(t!("free_camera.presets_reload")).clicked() {
+                            reload_clicked = true;
+                        }
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+                            if ui.button(t!("close")).clicked() {
+                                open2 = false;
+                            }
+                            if ui.button(t!("save")).clicked() {
+                                save_clicked = true;
+                            }
+                        });
+                    });
+                }
+            );
+        });
+
+        if reload_clicked {
+            free_camera::reload_presets();
+            self.presets = free_camera::presets();
+        }
+
+        if save_clicked {
+            let message = match free_camera::save_presets(self.presets.clone()) {
+                Ok(path) => t!("free_camera.presets_saved", path = path.display().to_string()).into_owned(),
+                Err(e) => e.to_string()
+            };
+            thread::spawn(move || {
+                Gui::instance().unwrap()
+                    .lock().unwrap()
+                    .show_notification(&message);
+            });
+        }
+
+        open &= open2;
+        open
+    }
+}
+
+struct CameraPathWindow {
+    id: egui::Id,
+    selected: Option<usize>,
+    dragging: bool
+}
+
+impl CameraPathWindow {
+    fn new() -> CameraPathWindow {
+        CameraPathWindow {
+            id: random_id(),
+            selected: None,
+            dragging: false
+        }
+    }
+
+    fn notify(message: String) {
+        thread::spawn(move || {
+            Gui::instance().unwrap()
+                .lock().unwrap()
+                .show_notification(&message);
+        });
+    }
+
+    /// Draws the keyframes along the live's duration. Clicking a keyframe selects it, dragging it
+    /// changes its time and clicking anywhere else seeks the live.
+    fn run_timeline(&mut self, ui: &mut egui::Ui, path: &mut CameraPath, current: f32, total: f32) -> bool {
+        let scale = get_scale(ui.ctx());
+        let (rect, res) = ui.allocate_exact_size(
+            egui::vec2(ui.available_width(), 36.0 * scale),
+            egui::Sense::click_and_drag()
+        );
+        let to_x = |time: f32| rect.left() + (time / total).clamp(0.0, 1.0) * rect.width();
+        let to_time = |x: f32| ((x - rect.left()) / rect.width()).clamp(0.0, 1.0) * total;
+
+        let painter = ui.painter_at(rect);
+        let visuals = ui.visuals();
+        painter.rect_filled(rect, 4.0 * scale, visuals.extreme_bg_color);
+        if path.keyframes.len() > 1 {
+            let first = to_x(path.keyframes[0].time);
+            let last = to_x(path.keyframes[path.keyframes.len() - 1].time);
+            painter.line_segment(
+                [egui::pos2(first, rect.center().y), egui::pos2(last, rect.center().y)],
+                egui::Stroke::new(2.0 * scale, visuals.widgets.inactive.bg_stroke.color)
+            );
+        }
+        for (i, keyframe) in path.keyframes.iter().enumerate() {
+            let color = if self.selected == Some(i) { visuals.selection.bg_fill } else { visuals.text_color() };
+            let center = egui::pos2(to_x(keyframe.time), rect.center().y);
+            let r = 5.0 * scale;
+            painter.add(egui::Shape::convex_polygon(
+                vec![center + egui::vec2(0.0, -r), center + egui::vec2(r, 0.0), center + egui::vec2(0.0, r), center + egui::vec2(-r, 0.0)],
+                color,
+                egui::Stroke::NONE
+            ));
+        }
+        let playhead = to_x(current);
+        painter.line_segment(
+            [egui::pos2(playhead, rect.top()), egui::pos2(playhead, rect.bottom())],
+            egui::Stroke::new(2.0 * scale, egui::Color32::RED)
+        );
+
+        let mut changed = false;
+        let hit_radius = 8.0 * scale;
+        let hovered_keyframe = |keyframes: &[CameraKeyframe], pos: egui::Pos2| keyframes.iter()
+            .position(|k| (to_x(k.time) - pos.x).abs() <= hit_radius);
+
+        if res.drag_started() {
+            if let Some(pos) = res.interact_pointer_pos() {
+                if let Some(i) = hovered_keyframe(&path.keyframes, pos) {
+                    self.selected = Some(i);
+                    self.dragging = true;
+                }
+            }
+        }
+        if res.dragged() && self.dragging {
+            if let (Some(i), Some(pos)) = (self.selected, res.interact_pointer_pos()) {
+                if let Some(keyframe) = path.keyframes.get_mut(i) {
+                    keyframe.time = to_time(pos.x);
+                    changed = true;
+                }
+            }
+        }
+        if res.drag_stopped() {
+            self.dragging = false;
+        }
+        if res.clicked() {
+            if let Some(pos) = res.interact_pointer_pos() {
+                match hovered_keyframe(&path.keyframes, pos) {
+                    Some(i) => self.selected = Some(i),
+                    None => live_utils::move_live_playback(to_time(pos.x))
+                }
+            }
+        }
+
+        changed
+    }
+}
+
+impl Window for CameraPathWindow {
+    fn run(&mut self, ctx: &egui::Context) -> bool {
+        let scale = get_scale(ctx);
+        let mut open = true;
+        let mut open2 = true;
+
+        let director = Director::instance();
+        let (current, total) = if director.is_null() {
+            (0.0, 0.0)
+        }
+        else {
+            (Director::get_LiveCurrentTime(director), Director::get_LiveTotalTime(director))
+        };
+
+        let mut path = free_camera::camera_path();
+        let mut changed = false;
+        let mut clear_clicked = false;
+        let mut reload_clicked = false;
+        if self.selected.is_some_and(|i| i >= path.keyframes.len()) {
+            self.selected = None;
+        }
+
+        new_window(ctx, self.id, t!("camera_path.title"))
+        .default_width(420.0 * scale)
+        .open(&mut open)
+        .show(ctx, |ui| {
+            simple_window_layout(ui, self.id,
+                |ui| {
+                    if path.music_id == 0 {
+                        ui.label(t!("camera_path.no_live"));
+                        return;
+                    }
+
+                    ui.label(t!("camera_path.info",
+                        music_id = path.music_id,
+                        count = path.keyframes.len(),
+                        time = format_live_time(current)
+                    ));
+
+                    ui.horizontal(|ui| {
+                        let recording = free_camera::is_camera_path_recording();
+                        let label = if recording { t!("camera_path.stop_recording") } else { t!("camera_path.record") };
+                        if ui.button(label).clicked() {
+                            free_camera::set_camera_path_recording(!recording);
+                        }
+
+                        let playing = free_camera::is_camera_path_playing();
+                        let label = if playing { t!("camera_path.stop_playback") } else { t!("camera_path.play") };
+                        if ui.add_enabled(playing || !path.keyframes.is_empty(), egui::Button::new(label)).clicked() {
+                            free_camera::set_camera_path_playing(!playing);
+                        }
+
+                        if ui.button(t!("camera_path.add_keyframe")).clicked() {
+                            if let Some(i) = free_camera::add_camera_path_keyframe() {
+                                self.selected = Some(i);
+                            }
+                        }
+                    });
+
+                    if total > 0.0 {
+                        changed |= self.run_timeline(ui, &mut path, current, total);
+                    }
+                    ui.separator();
+
+                    let Some(i) = self.selected else {
+                        ui.label(t!("camera_path.no_selection"));
+                        return;
+                    };
+
+                    let mut delete = false;
+                    egui::Grid::new(self.id.with("camera_path_keyframe_grid"))
+                    .striped(true)
+                    .num_columns(2)
+                    .spacing([40.0 * scale, 4.0 * scale])
+                    .show(ui, |ui| {
+                        let keyframe = &mut path.keyframes[i];
+
+                        ui.label(t!("camera_path.time"));
+                        changed |= ui.add(egui::DragValue::new(&mut keyframe.time).speed(0.01).range(0.0..=total.max(0.0)).suffix("s")).changed();
+                        ui.end_row();
+
+                        ui.label(t!("camera_path.position"));
+                        ui.horizontal(|ui| {
+                            for value in keyframe.position.iter_mut() {
+                                changed |= ui.add(egui::DragValue::new(value).speed(0.01)).changed();
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label(t!("free_camera.live_fov"));
+                        changed |= ui.add(egui::DragValue::new(&mut keyframe.fov).speed(0.5).range(1.0..=120.0)).changed();
+                        ui.end_row();
+
+                        ui.label("");
+                        ui.horizontal(|ui| {
+                            if ui.button(t!("camera_path.go_to")).clicked() {
+                                live_utils::move_live_playback(keyframe.time);
+                            }
+                            if ui.button(t!("camera_path.set_from_camera")).clicked() {
+                                free_camera::update_camera_path_keyframe(i);
+                            }
+                            if ui.button(t!("camera_path.delete")).clicked() {
+                                delete = true;
+                            }
+                        });
+                        ui.end_row();
+                    });
+
+                    if delete {
+                        path.keyframes.remove(i);
+                        self.selected = None;
+                        changed = true;
+                    }
+                },
+                |ui| {
+                    ui.with_layout(egui::Layout::left_to_right(egui::Align::Min), |ui| {
+                        if ui.button(t!("camera_path.clear")).clicked() {
+                            clear_clicked = true;
+                        }
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+                            if ui.button(t!("close")).clicked() {
+                                open2 = false;
+                            }
+                            if ui.button(t!("camera_path.reload")).clicked() {
+                                reload_clicked = true;
+                            }
+                            if ui.button(t!("save")).clicked() {
+                                Self::notify(match free_camera::save_camera_path() {
+                                    Ok(file) => t!("camera_path.saved", path = file.display().to_string()).into_owned(),
+                                    Err(e) => e.to_string()
+                                });
+                            }
+                        });
+                    });
+                }
+            );
+        });
+
+        if clear_clicked {
+            path.keyframes.clear();
+            changed = true;
+        }
+
+        if changed {
+            // Keep the same keyframe selected if its time moved it past its neighbours
+            let selected = self.selected.and_then(|i| path.keyframes.get(i).copied());
+            path.sort();
+            self.selected = selected.and_then(|s| path.keyframes.iter().position(|k| *k == s));
+            free_camera::set_camera_path_keyframes(path.keyframes);
+        }
+
+        if reload_clicked {
+            free_camera::reload_camera_path();
+            self.selected = None;
+        }
+
+        open &= open2;
+        open
+    }
+}
+
+struct ThemeEditorWindow {
+    id: egui::Id,
+    config: hachimi::Config,
+    old_config: hachimi::Config
+}
+
+impl ThemeEditorWindow {
+    fn new() -> ThemeEditorWindow {
+        let current_cfg = (**Hachimi::instance().config.load()).clone();
+        ThemeEditorWindow {
+            id: random_id(),
+            config: current_cfg.clone(),
+            old_config: current_cfg
+        }
+    }
+}
+
+fn theme_color_row(ui: &mut egui::Ui, label: &str, color: &mut egui::Color32) -> bool {
+    let mut changed = false;
+
+    ui.columns(2, |cols| {
         cols[0].with_layout(egui::Layout::left_to_right(egui::Align::Center), |ui| {
             ui.label(label);
         });
@@ -4520,79 +6070,629 @@ impl Window for ExcludesEditorWindow {
                                                 .desired_width(ui.available_width())
                                         );
 
-                                        #[cfg(target_os = "android")]
-                                        handle_android_keyboard(&_edit_res, &mut self.edit_value);
-                                    });
-                                });
-                            } else {
-                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
-                                    if ui.button(t!("remove")).clicked() {
-                                        self.confirm_action = Some(ConfirmAction::Remove { index: i });
-                                    }
-                                    if ui.button(t!("edit")).clicked() {
-                                        to_edit = Some(i);
-                                    }
+                                        #[cfg(target_os = "android")]
+                                        handle_android_keyboard(&_edit_res, &mut self.edit_value);
+                                    });
+                                });
+                            } else {
+                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+                                    if ui.button(t!("remove")).clicked() {
+                                        self.confirm_action = Some(ConfirmAction::Remove { index: i });
+                                    }
+                                    if ui.button(t!("edit")).clicked() {
+                                        to_edit = Some(i);
+                                    }
+
+                                    ui.with_layout(egui::Layout::left_to_right(egui::Align::Min), |ui| {
+                                        ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Wrap);
+                                        ui.label(exclude_str.as_str());
+                                    });
+                                });
+                            }
+                        }
+
+                        if let Some(idx) = to_remove {
+                            self.excludes.remove(idx);
+                            if self.edit_index == Some(idx) {
+                                self.edit_index = None;
+                            } else if let Some(edit_idx) = self.edit_index {
+                                if edit_idx > idx {
+                                    self.edit_index = Some(edit_idx - 1);
+                                }
+                            }
+                        }
+
+                        if let Some(idx) = to_edit {
+                            self.edit_value = self.excludes[idx].clone();
+                            self.edit_index = Some(idx);
+                        }
+                    });
+                },
+                |ui| {
+                    ui.with_layout(egui::Layout::left_to_right(egui::Align::Min), |ui| {
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+                            if ui.button(t!("cancel")).clicked() {
+                                open2 = false;
+                            }
+                            if ui.button(t!("save")).clicked() {
+                                save_clicked = true;
+                                open2 = false;
+                            }
+                        });
+                    });
+                }
+            );
+        });
+
+        if save_clicked {
+            match Self::save_excludes(&self.excludes) {
+                Ok(()) => {
+                    thread::spawn(|| {
+                        Gui::instance().unwrap()
+                            .lock().unwrap()
+                            .show_notification(&t!("excludes_editor.saved"));
+                    });
+                }
+                Err(e) => {
+                    let err = e.clone();
+                    thread::spawn(move || {
+                        Gui::instance().unwrap()
+                            .lock().unwrap()
+                            .show_notification(&err);
+                    });
+                }
+            }
+        }
+
+        open &= open2;
+        open
+    }
+}
+
+struct CaptionStylesWindow {
+    id: egui::Id,
+    config: hachimi::Config,
+    chara_choices: Vec<(i32, String)>,
+    font_color_options: Vec<String>,
+    outline_size_options: Vec<String>,
+    outline_color_options: Vec<String>,
+    search_term: String,
+    new_chara: i32,
+    preview_chara: i32
+}
+
+impl CaptionStylesWindow {
+    fn new() -> CaptionStylesWindow {
+        let hachimi = Hachimi::instance();
+        let data = hachimi.chara_data.load();
+        let mut chara_choices: Vec<(i32, String)> = data.chara_ids.iter()
+            .map(|&id| (id, data.get_name(id)))
+            .collect();
+        chara_choices.sort_by_key(|choice| choice.0);
+
+        CaptionStylesWindow {
+            id: random_id(),
+            config: (**hachimi.config.load()).clone(),
+            new_chara: chara_choices.first().map(|c| c.0).unwrap_or_default(),
+            chara_choices,
+            font_color_options: umamusume_enum_options(c"FontColorType"),
+            outline_size_options: umamusume_enum_options(c"OutlineSizeType"),
+            outline_color_options: umamusume_enum_options(c"OutlineColorType"),
+            search_term: String::new(),
+            preview_chara: 0
+        }
+    }
+
+    /// Combo box for an optional enum name, where None keeps the inherited value.
+    fn option_combo(ui: &mut egui::Ui, id_child: impl std::hash::Hash, value: &mut Option<String>, options: &[String]) {
+        let default_label = t!("default");
+        egui::ComboBox::new(ui.id().with(id_child), "")
+            .selected_text(value.as_deref().unwrap_or(&default_label))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(value, None, &*default_label);
+                for option in options {
+                    ui.selectable_value(value, Some(option.clone()), option);
+                }
+            });
+    }
+
+    fn style_rows(&self, ui: &mut egui::Ui, index: usize, style: &mut captions::CaptionStyle) {
+        ConfigEditor::option_slider(ui, &t!("config_editor.caption_font_size"), &mut style.font_size, 10..=128);
+
+        ui.label(t!("config_editor.caption_color"));
+        Self::option_combo(ui, ("preset_color", index), &mut style.color, &self.font_color_options);
+        ui.end_row();
+
+        ui.label(t!("config_editor.caption_outline_size"));
+        Self::option_combo(ui, ("preset_outline_size", index), &mut style.outline_size, &self.outline_size_options);
+        ui.end_row();
+
+        ui.label(t!("config_editor.caption_outline_color"));
+        Self::option_combo(ui, ("preset_outline_color", index), &mut style.outline_color, &self.outline_color_options);
+        ui.end_row();
+
+        ConfigEditor::option_slider(ui, &t!("config_editor.caption_bg_alpha"), &mut style.bg_alpha, 0.0..=1.0);
+        ConfigEditor::option_slider(ui, &t!("config_editor.caption_pos_x"), &mut style.pos_x, -10.0..=10.0);
+        ConfigEditor::option_slider(ui, &t!("config_editor.caption_pos_y"), &mut style.pos_y, -10.0..=10.0);
+    }
+
+    fn section_heading(ui: &mut egui::Ui, text: Cow<'_, str>) {
+        ui.vertical(|ui| {
+            ui.add_space(8.0 * get_scale(ui.ctx()));
+            ui.heading(text);
+            ui.separator();
+        });
+        ui.end_row();
+    }
+
+    fn run_grid(&mut self, ui: &mut egui::Ui) {
+        let mut caption = std::mem::take(&mut self.config.caption);
+        let preset_names: Vec<String> = caption.caption_styles.iter().map(|p| p.name.clone()).collect();
+        let mut preset_choices: Vec<(Option<&str>, &str)> = vec![(None, "-")];
+        preset_choices.extend(preset_names.iter().map(|name| (Some(name.as_str()), name.as_str())));
+        let chara_choices: Vec<(i32, &str)> = self.chara_choices.iter()
+            .map(|&(id, ref name)| (id, name.as_str()))
+            .collect();
+
+        Self::section_heading(ui, t!("caption_styles.section_general"));
+
+        ui.label(t!("caption_styles.show_speaker_name"));
+        ui.checkbox(&mut caption.caption_show_speaker_name, "");
+        ui.end_row();
+
+        ui.label(t!("caption_styles.active_preset"));
+        let mut active = caption.caption_style.as_deref();
+        if Gui::run_combo(ui, "active_preset", &mut active, &preset_choices) {
+            caption.caption_style = active.map(str::to_owned);
+        }
+        ui.end_row();
+
+        ui.label(t!("caption_styles.preview_character"));
+        let mut preview_choices = vec![(0, "-".to_owned())];
+        preview_choices.extend(self.chara_choices.iter().cloned());
+        let preview_choices: Vec<(i32, &str)> = preview_choices.iter().map(|&(id, ref name)| (id, name.as_str())).collect();
+        Gui::run_combo_menu(ui, self.id.with("preview_chara"), &mut self.preview_chara, &preview_choices, &mut self.search_term);
+        ui.end_row();
+
+        Self::section_heading(ui, t!("caption_styles.section_presets"));
+
+        let mut remove_preset = None;
+        for (i, preset) in caption.caption_styles.iter_mut().enumerate() {
+            ui.label(t!("caption_styles.preset_name"));
+            ui.horizontal(|ui| {
+                ui.text_edit_singleline(&mut preset.name);
+                if ui.button("\u{f00d}").clicked() {
+                    remove_preset = Some(i);
+                }
+            });
+            ui.end_row();
+
+            self.style_rows(ui, i, &mut preset.style);
+
+            ui.separator();
+            ui.separator();
+            ui.end_row();
+        }
+        if let Some(i) = remove_preset {
+            caption.caption_styles.remove(i);
+        }
+
+        ui.label("");
+        if ui.button(t!("caption_styles.add_preset")).clicked() {
+            caption.caption_styles.push(captions::CaptionStylePreset {
+                name: t!("caption_styles.new_preset_name", index = caption.caption_styles.len() + 1).into_owned(),
+                style: captions::CaptionStyle::default()
+            });
+        }
+        ui.end_row();
+
+        Self::section_heading(ui, t!("caption_styles.section_characters"));
+
+        let show_name_choices = [
+            (None, t!("default")),
+            (Some(true), t!("caption_styles.show_name")),
+            (Some(false), t!("caption_styles.hide_name"))
+        ];
+        let show_name_choices: Vec<(Option<bool>, &str)> = show_name_choices.iter().map(|(v, s)| (*v, s.as_ref())).collect();
+
+        let mut chara_ids: Vec<i32> = caption.caption_character_styles.keys().copied().collect();
+        chara_ids.sort();
+        for chara_id in chara_ids {
+            let Some(chara_style) = caption.caption_character_styles.get_mut(&chara_id) else { continue };
+            let name = chara_choices.iter().find(|c| c.0 == chara_id).map(|c| c.1.to_owned()).unwrap_or_else(|| chara_id.to_string());
+
+            ui.strong(name);
+            if ui.button(t!("caption_styles.remove")).clicked() {
+                caption.caption_character_styles.remove(&chara_id);
+                ui.end_row();
+                continue;
+            }
+            ui.end_row();
+
+            ui.label(t!("caption_styles.preset"));
+            let mut preset = chara_style.preset.as_deref();
+            if Gui::run_combo(ui, ("chara_preset", chara_id), &mut preset, &preset_choices) {
+                chara_style.preset = preset.map(str::to_owned);
+            }
+            ui.end_row();
+
+            ui.label(t!("config_editor.caption_color"));
+            Self::option_combo(ui, ("chara_color", chara_id), &mut chara_style.color, &self.font_color_options);
+            ui.end_row();
+
+            ui.label(t!("config_editor.caption_outline_color"));
+            Self::option_combo(ui, ("chara_outline_color", chara_id), &mut chara_style.outline_color, &self.outline_color_options);
+            ui.end_row();
+
+            ui.label(t!("caption_styles.speaker_name"));
+            Gui::run_combo(ui, ("chara_show_name", chara_id), &mut chara_style.show_name, &show_name_choices);
+            ui.end_row();
+        }
+
+        Gui::run_combo_menu(ui, self.id.with("new_chara"), &mut self.new_chara, &chara_choices, &mut self.search_term);
+        if ui.button(t!("caption_styles.add_character")).clicked() && self.new_chara != 0 {
+            caption.caption_character_styles.entry(self.new_chara).or_default();
+        }
+        ui.end_row();
+
+        self.config.caption = caption;
+    }
+
+    fn preview(&self) {
+        captions::preview_caption(
+            self.config.caption.clone(),
+            self.preview_chara,
+            t!("caption_styles.preview_text").into_owned()
+        );
+    }
+}
+
+impl Window for CaptionStylesWindow {
+    fn run(&mut self, ctx: &egui::Context) -> bool {
+        let scale = get_scale(ctx);
+        let mut open = true;
+        let mut open2 = true;
+        let mut save_clicked = false;
+        let mut preview_clicked = false;
+
+        let caption = &self.config.caption;
+        let before = (
+            caption.caption_style.clone(),
+            caption.caption_styles.clone(),
+            caption.caption_character_styles.clone(),
+            caption.caption_show_speaker_name
+        );
+
+        new_window(ctx, self.id, t!("caption_styles.title"))
+        .default_width(360.0 * scale)
+        .max_height(480.0 * scale)
+        .open(&mut open)
+        .show(ctx, |ui| {
+            simple_window_layout(ui, self.id,
+                |ui| {
+                    egui::ScrollArea::vertical()
+                    .id_salt(self.id.with("caption_styles_scroll"))
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        egui::Frame::NONE
+                        .inner_margin(egui::Margin::symmetric(8, 0))
+                        .show(ui, |ui| {
+                            egui::Grid::new(self.id.with("caption_styles_grid"))
+                            .striped(true)
+                            .num_columns(2)
+                            .spacing([40.0 * scale, 4.0 * scale])
+                            .show(ui, |ui| self.run_grid(ui));
+                        });
+                    });
+                },
+                |ui| {
+                    ui.with_layout(egui::Layout::left_to_right(egui::Align::Min), |ui| {
+                        if ui.button(t!("caption_styles.preview")).clicked() {
+                            preview_clicked = true;
+                        }
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+                            if ui.button(t!("cancel")).clicked() {
+                                open2 = false;
+                            }
+                            if ui.button(t!("save")).clicked() {
+                                save_clicked = true;
+                                open2 = false;
+                            }
+                        });
+                    });
+                }
+            );
+        });
+
+        let caption = &self.config.caption;
+        let changed = before.0 != caption.caption_style ||
+            before.1 != caption.caption_styles ||
+            before.2 != caption.caption_character_styles ||
+            before.3 != caption.caption_show_speaker_name;
+        if preview_clicked || changed {
+            self.preview();
+        }
+
+        if save_clicked {
+            save_and_reload_config(self.config.clone());
+        }
+
+        open &= open2;
+        open
+    }
+}
+
+struct CaptionHistoryWindow {
+    id: egui::Id,
+    search_term: String
+}
+
+impl CaptionHistoryWindow {
+    fn new() -> CaptionHistoryWindow {
+        CaptionHistoryWindow {
+            id: random_id(),
+            search_term: String::new()
+        }
+    }
+
+    fn export(format: captions::CaptionExportFormat) {
+        let message = match captions::export_caption_history(format) {
+            Ok(path) => t!("caption_history.exported", path = path.display().to_string()).into_owned(),
+            Err(e) => e.to_string()
+        };
+        thread::spawn(move || {
+            Gui::instance().unwrap()
+                .lock().unwrap()
+                .show_notification(&message);
+        });
+    }
+
+    fn matches_search(&self, entry: &captions::CaptionHistoryEntry, name: &str) -> bool {
+        if self.search_term.is_empty() {
+            return true;
+        }
+        let search = self.search_term.to_lowercase();
+        entry.text.to_lowercase().contains(&search) ||
+        entry.original_text.to_lowercase().contains(&search) ||
+        name.to_lowercase().contains(&search)
+    }
+}
+
+impl Window for CaptionHistoryWindow {
+    fn run(&mut self, ctx: &egui::Context) -> bool {
+        let scale = get_scale(ctx);
+        let mut open = true;
+        let mut open2 = true;
+        let chara_data = Hachimi::instance().chara_data.load();
+
+        new_window(ctx, self.id, t!("caption_history.title"))
+        .open(&mut open)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                let _search_res = ui.add_sized(
+                    [ui.available_width() - 30.0 * scale, 24.0 * scale],
+                    egui::TextEdit::singleline(&mut self.search_term).hint_text(t!("search_filter"))
+                );
+                #[cfg(target_os = "android")]
+                handle_android_keyboard(&_search_res, &mut self.search_term);
+
+                if ui.button("\u{f00d}").clicked() {
+                    self.search_term.clear();
+                }
+            });
+
+            ui.separator();
 
-                                    ui.with_layout(egui::Layout::left_to_right(egui::Align::Min), |ui| {
-                                        ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Wrap);
-                                        ui.label(exclude_str.as_str());
-                                    });
-                                });
+            simple_window_layout(ui, self.id,
+                |ui| {
+                    let entries = captions::caption_history();
+                    if entries.is_empty() {
+                        ui.label(t!("caption_history.empty"));
+                        return;
+                    }
+
+                    egui::ScrollArea::vertical().max_height(300.0 * scale).show(ui, |ui| {
+                        // Newest first
+                        for entry in entries.iter().rev() {
+                            let name = chara_data.get_name(entry.character_id);
+                            if !self.matches_search(entry, &name) {
+                                continue;
                             }
-                        }
+                            let time = chrono::DateTime::from_timestamp_millis(entry.timestamp as i64)
+                                .map(|t| t.with_timezone(&chrono::Local).format("%H:%M:%S").to_string())
+                                .unwrap_or_default();
 
-                        if let Some(idx) = to_remove {
-                            self.excludes.remove(idx);
-                            if self.edit_index == Some(idx) {
-                                self.edit_index = None;
-                            } else if let Some(edit_idx) = self.edit_index {
-                                if edit_idx > idx {
-                                    self.edit_index = Some(edit_idx - 1);
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+                                if ui.button("\u{f04b}").on_hover_text(t!("caption_history.replay")).clicked() {
+                                    captions::replay_caption(entry.clone());
+                                }
+                                if ui.button("\u{f0c5}").on_hover_text(t!("caption_history.copy")).clicked() {
+                                    ui.ctx().copy_text(entry.text.clone());
                                 }
-                            }
-                        }
 
-                        if let Some(idx) = to_edit {
-                            self.edit_value = self.excludes[idx].clone();
-                            self.edit_index = Some(idx);
+                                ui.with_layout(egui::Layout::top_down(egui::Align::Min), |ui| {
+                                    ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Wrap);
+                                    ui.label(
+                                        egui::RichText::new(format!("[{}] {} ({})", time, name, entry.voice_id)).small().weak()
+                                    );
+                                    ui.label(&entry.text);
+                                    if entry.text != entry.original_text {
+                                        ui.label(egui::RichText::new(&entry.original_text).weak());
+                                    }
+                                });
+                            });
+                            ui.separator();
                         }
                     });
                 },
                 |ui| {
-                    ui.with_layout(egui::Layout::left_to_right(egui::Align::Min), |ui| {
-                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
-                            if ui.button(t!("cancel")).clicked() {
-                                open2 = false;
-                            }
-                            if ui.button(t!("save")).clicked() {
-                                save_clicked = true;
-                                open2 = false;
-                            }
-                        });
-                    });
+                    if ui.button(t!("close")).clicked() {
+                        open2 = false;
+                    }
+                    if ui.button(t!("caption_history.export_srt")).clicked() {
+                        Self::export(captions::CaptionExportFormat::Srt);
+                    }
+                    if ui.button(t!("caption_history.export_json")).clicked() {
+                        Self::export(captions::CaptionExportFormat::Json);
+                    }
+                    if ui.button(t!("caption_history.clear")).clicked() {
+                        captions::clear_caption_history();
+                    }
                 }
             );
         });
 
-        if save_clicked {
-            match Self::save_excludes(&self.excludes) {
-                Ok(()) => {
-                    thread::spawn(|| {
-                        Gui::instance().unwrap()
-                            .lock().unwrap()
-                            .show_notification(&t!("excludes_editor.saved"));
-                    });
+        open &= open2;
+        open
+    }
+}
+
+struct LogViewerWindow {
+    id: egui::Id,
+    records: Vec<log::LogRecord>,
+    generation: u64,
+    max_level: log::LogLevel,
+    module_filter: String,
+    search_term: String
+}
+
+impl LogViewerWindow {
+    fn new() -> LogViewerWindow {
+        LogViewerWindow {
+            id: random_id(),
+            records: log::recent_records(),
+            generation: log::recent_generation(),
+            max_level: log::LogLevel::Trace,
+            module_filter: String::new(),
+            search_term: String::new()
+        }
+    }
+
+    fn create_bug_report() {
+        thread::spawn(|| {
+            let message = match bug_report::create() {
+                Ok(path) => t!("log_viewer.bug_report_created", path = path.display().to_string()).into_owned(),
+                Err(e) => t!("log_viewer.bug_report_failed", error = e.to_string()).into_owned()
+            };
+            Gui::instance().unwrap()
+                .lock().unwrap()
+                .show_notification(&message);
+        });
+    }
+
+    fn matches(&self, record: &log::LogRecord) -> bool {
+        if record.level > self.max_level.to_level_filter() {
+            return false;
+        }
+        if !self.module_filter.is_empty() && !record.target.contains(self.module_filter.as_str()) {
+            return false;
+        }
+        self.search_term.is_empty() || record.message.to_lowercase().contains(&self.search_term.to_lowercase())
+    }
+
+    fn level_color(level: ::log::Level) -> egui::Color32 {
+        match level {
+            ::log::Level::Error => egui::Color32::from_rgb(255, 100, 100),
+            ::log::Level::Warn => egui::Color32::from_rgb(255, 200, 80),
+            ::log::Level::Info => egui::Color32::from_rgb(140, 200, 255),
+            ::log::Level::Debug | ::log::Level::Trace => egui::Color32::GRAY
+        }
+    }
+}
+
+impl Window for LogViewerWindow {
+    fn run(&mut self, ctx: &egui::Context) -> bool {
+        let scale = get_scale(ctx);
+        let mut open = true;
+        let mut open2 = true;
+
+        let generation = log::recent_generation();
+        if generation != self.generation {
+            self.records = log::recent_records();
+            self.generation = generation;
+        }
+
+        new_window(ctx, self.id, t!("log_viewer.title"))
+        .max_width(480.0 * scale)
+        .open(&mut open)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                egui::ComboBox::new(self.id.with("level"), "")
+                .selected_text(self.max_level.as_str())
+                .show_ui(ui, |ui| {
+                    for level in log::LogLevel::ALL.into_iter().skip(1) {
+                        ui.selectable_value(&mut self.max_level, level, level.as_str());
+                    }
+                });
+
+                let _module_res = ui.add(
+                    egui::TextEdit::singleline(&mut self.module_filter)
+                    .hint_text(t!("log_viewer.module_filter"))
+                    .desired_width(120.0 * scale)
+                );
+                #[cfg(target_os = "android")]
+                handle_android_keyboard(&_module_res, &mut self.module_filter);
+
+                let _search_res = ui.add(
+                    egui::TextEdit::singleline(&mut self.search_term)
+                    .hint_text(t!("search_filter"))
+                    .desired_width(ui.available_width() - 30.0 * scale)
+                );
+                #[cfg(target_os = "android")]
+                handle_android_keyboard(&_search_res, &mut self.search_term);
+
+                if ui.button("\u{f00d}").clicked() {
+                    self.module_filter.clear();
+                    self.search_term.clear();
                 }
-                Err(e) => {
-                    let err = e.clone();
-                    thread::spawn(move || {
-                        Gui::instance().unwrap()
-                            .lock().unwrap()
-                            .show_notification(&err);
+            });
+
+            ui.separator();
+
+            simple_window_layout(ui, self.id,
+                |ui| {
+                    let visible: Vec<&log::LogRecord> = self.records.iter().filter(|r| self.matches(r)).collect();
+                    if visible.is_empty() {
+                        ui.label(t!("log_viewer.empty"));
+                        return;
+                    }
+
+                    let row_height = ui.text_style_height(&egui::TextStyle::Small);
+                    egui::ScrollArea::both()
+                    .max_height(300.0 * scale)
+                    .stick_to_bottom(true)
+                    .auto_shrink([false, true])
+                    .show_rows(ui, row_height, visible.len(), |ui, range| {
+                        for record in &visible[range] {
+                            ui.horizontal(|ui| {
+                                ui.label(egui::RichText::new(record.time.format("%H:%M:%S%.3f").to_string()).small().weak());
+                                ui.label(egui::RichText::new(record.level.as_str()).small().color(Self::level_color(record.level)));
+                                ui.label(egui::RichText::new(&record.target).small().weak());
+                                ui.add(egui::Label::new(egui::RichText::new(&record.message).small()).extend());
+                            });
+                        }
                     });
+                },
+                |ui| {
+                    if ui.button(t!("close")).clicked() {
+                        open2 = false;
+                    }
+                    if ui.button(t!("log_viewer.create_bug_report")).clicked() {
+                        Self::create_bug_report();
+                    }
+                    if ui.button(t!("log_viewer.copy")).clicked() {
+                        let text: Vec<String> = self.records.iter()
+                            .filter(|r| self.matches(r))
+                            .map(|r| r.to_string())
+                            .collect();
+                        ui.ctx().copy_text(text.join("\n"));
+                    }
+                    if ui.button(t!("log_viewer.clear")).clicked() {
+                        log::clear_recent_records();
+                    }
                 }
-            }
-        }
+            );
+        });
 
         open &= open2;
         open
@@ -5476,6 +7576,221 @@ impl Window for SimpleMarkdownDialog {
     }
 }
 
+struct HookDiagnosticsWindow {
+    id: egui::Id,
+    hooks: Vec<HookInfo>,
+    search_term: String,
+    problems_only: bool,
+    last_refresh: Instant
+}
+
+impl HookDiagnosticsWindow {
+    const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+    fn new() -> HookDiagnosticsWindow {
+        HookDiagnosticsWindow {
+            id: random_id(),
+            hooks: hook_registry::hooks(),
+            search_term: String::new(),
+            problems_only: false,
+            last_refresh: Instant::now()
+        }
+    }
+
+    fn status_text(hook: &HookInfo) -> egui::RichText {
+        match hook.status {
+            HookStatus::Installed if hook.active => egui::RichText::new(t!("hook_diagnostics.installed")).color(egui::Color32::from_rgb(120, 220, 120)),
+            HookStatus::Installed => egui::RichText::new(t!("hook_diagnostics.inactive")).weak(),
+            HookStatus::Disabled => egui::RichText::new(t!("hook_diagnostics.disabled")).weak(),
+            HookStatus::NullAddress => egui::RichText::new(t!("hook_diagnostics.null_address")).color(egui::Color32::from_rgb(255, 200, 80)),
+            HookStatus::Failed => egui::RichText::new(t!("hook_diagnostics.failed")).color(egui::Color32::from_rgb(255, 100, 100))
+        }
+    }
+
+    fn is_problem(hook: &HookInfo) -> bool {
+        matches!(hook.status, HookStatus::NullAddress | HookStatus::Failed)
+    }
+}
+
+impl Window for HookDiagnosticsWindow {
+    fn run(&mut self, ctx: &egui::Context) -> bool {
+        let scale = get_scale(ctx);
+        let mut open = true;
+        let mut open2 = true;
+
+        if self.last_refresh.elapsed() >= Self::REFRESH_INTERVAL {
+            self.hooks = hook_registry::hooks();
+            self.last_refresh = Instant::now();
+        }
+
+        new_window(ctx, self.id, t!("hook_diagnostics.title"))
+        .max_width(420.0 * scale)
+        .open(&mut open)
+        .show(ctx, |ui| {
+            let installed = self.hooks.iter().filter(|h| h.status == HookStatus::Installed && h.active).count();
+            let problems = self.hooks.iter().filter(|h| Self::is_problem(h)).count();
+            ui.label(t!("hook_diagnostics.summary", total = self.hooks.len(), installed = installed, problems = problems));
+
+            ui.horizontal(|ui| {
+                let _search_res = ui.add(
+                    egui::TextEdit::singleline(&mut self.search_term)
+                    .hint_text(t!("search_filter"))
+                    .desired_width(160.0 * scale)
+                );
+                #[cfg(target_os = "android")]
+                handle_android_keyboard(&_search_res, &mut self.search_term);
+                ui.checkbox(&mut self.problems_only, t!("hook_diagnostics.problems_only"));
+            });
+            ui.separator();
+
+            simple_window_layout(ui, self.id,
+                |ui| {
+                    let search = self.search_term.to_lowercase();
+                    let mut toggle = None;
+
+                    egui::ScrollArea::vertical().max_height(250.0 * scale).show(ui, |ui| {
+                        egui::Grid::new(self.id.with("hooks_grid"))
+                        .striped(true)
+                        .num_columns(3)
+                        .show(ui, |ui| {
+                            for hook in self.hooks.iter() {
+                                if self.problems_only && !Self::is_problem(hook) {
+                                    continue;
+                                }
+                                if !search.is_empty() && !hook.name.to_lowercase().contains(&search) {
+                                    continue;
+                                }
+
+                                let mut enabled = hook.active;
+                                let can_toggle = hook.orig_addr != 0;
+                                if ui.add_enabled(can_toggle, egui::Checkbox::without_text(&mut enabled)).changed() {
+                                    toggle = Some((hook.name, enabled));
+                                }
+
+                                let name = ui.label(hook.name);
+                                let hover = format!("orig: {:#x}\nhook: {:#x}", hook.orig_addr, hook.hook_addr);
+                                match &hook.error {
+                                    Some(error) => name.on_hover_text(format!("{}\n{}", hover, error)),
+                                    None => name.on_hover_text(hover)
+                                };
+                                ui.label(Self::status_text(hook));
+                                ui.end_row();
+                            }
+                        });
+                    });
+
+                    if let Some((name, enabled)) = toggle {
+                        hook_registry::request_set_enabled(name, enabled);
+                        // Applied on the main thread, pick up the result on the next refresh
+                        self.last_refresh = Instant::now() - Self::REFRESH_INTERVAL / 2;
+                    }
+                },
+                |ui| {
+                    if ui.button(t!("close")).clicked() {
+                        open2 = false;
+                    }
+                    if ui.button(t!("hook_diagnostics.copy")).clicked() {
+                        let text = serde_json::to_string_pretty(&self.hooks).unwrap_or_default();
+                        ui.ctx().copy_text(text);
+                    }
+                }
+            );
+        });
+
+        open &= open2;
+        open
+    }
+}
+
+struct CrashReportWindow {
+    id: egui::Id,
+    report: CrashReport,
+    hook_disabled: bool
+}
+
+impl CrashReportWindow {
+    fn new(report: CrashReport) -> CrashReportWindow {
+        let hook_disabled = report.hook.as_ref()
+            .is_some_and(|hook| Hachimi::instance().config.load().disabled_hooks.contains(hook));
+        CrashReportWindow {
+            id: random_id(),
+            report,
+            hook_disabled
+        }
+    }
+
+    fn disable_hook(hook: &str) {
+        let mut config = Hachimi::instance().config.load().as_ref().clone();
+        config.disabled_hooks.insert(hook.to_owned());
+        save_and_reload_config(config);
+    }
+}
+
+impl Window for CrashReportWindow {
+    fn run(&mut self, ctx: &egui::Context) -> bool {
+        let scale = get_scale(ctx);
+        let mut open = true;
+        let mut open2 = true;
+
+        new_window(ctx, self.id, t!("crash_report.title"))
+        .open(&mut open)
+        .show(ctx, |ui| {
+            simple_window_layout(ui, self.id,
+                |ui| {
+                    ui.label(t!("crash_report.description"));
+                    ui.separator();
+
+                    let report = &self.report;
+                    let hook = report.hook.as_deref().unwrap_or("-");
+                    ui.label(t!("crash_report.hook", hook = hook));
+                    ui.label(t!("crash_report.view", view_id = report.view_id));
+                    ui.label(t!("crash_report.version", version = report.hachimi_version));
+                    ui.label(t!("crash_report.message", message = report.message));
+                    if let Some(location) = &report.location {
+                        ui.label(egui::RichText::new(location).small().weak());
+                    }
+
+                    egui::CollapsingHeader::new(t!("crash_report.backtrace"))
+                    .id_salt(self.id.with("backtrace"))
+                    .show(ui, |ui| {
+                        egui::ScrollArea::both().max_height(150.0 * scale).show(ui, |ui| {
+                            ui.add(egui::Label::new(egui::RichText::new(&report.backtrace).small().monospace()).extend());
+                        });
+                    });
+
+                    if report.hook.is_some() {
+                        ui.separator();
+                        if self.hook_disabled {
+                            ui.label(t!("crash_report.hook_disabled"));
+                        }
+                        else {
+                            ui.label(t!("crash_report.disable_hook_hint"));
+                        }
+                    }
+                },
+                |ui| {
+                    if ui.button(t!("close")).clicked() {
+                        open2 = false;
+                    }
+                    if ui.button(t!("crash_report.copy")).clicked() {
+                        let text = serde_json::to_string_pretty(&self.report).unwrap_or_default();
+                        ui.ctx().copy_text(text);
+                    }
+                    if let Some(hook) = &self.report.hook {
+                        if ui.add_enabled(!self.hook_disabled, egui::Button::new(t!("crash_report.disable_hook"))).clicked() {
+                            Self::disable_hook(hook);
+                            self.hook_disabled = true;
+                        }
+                    }
+                }
+            );
+        });
+
+        open &= open2;
+        open
+    }
+}
+
 struct AboutWindow {
     id: egui::Id
 }
@@ -2,12 +2,13 @@ use std::{fs, path::{Path, PathBuf}, process, sync::{atomic::{self, AtomicBool,
 use arc_swap::ArcSwap;
 use fnv::{FnvHashMap, FnvHashSet};
 use once_cell::sync::OnceCell;
+use schemars::JsonSchema;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use textwrap::wrap_algorithms::Penalties;
 
-use crate::{core::{gui, plugin_api::Plugin, updater}, gui_impl, hachimi_impl, il2cpp::{self, hook::umamusume::{CySpringController::SpringUpdateMode, GameSystem}, sql::{CharacterData, SkillInfo}}};
+use crate::{core::{captions::{CaptionCharacterStyle, CaptionRule, CaptionStylePreset}, free_camera::FreeCameraConfig, gui, http::HttpConfig, screenshot::ScreenshotConfig, live_utils::LivePlaybackKeybinds, plugin_api::Plugin, updater}, gui_impl, hachimi_impl, il2cpp::{self, hook::umamusume::{CySpringController::SpringUpdateMode, GameSystem}, sql::{CharacterData, SkillInfo}}};
 
-use super::{game::{Game, Region}, ipc, plurals, template, template_filters, tl_repo, utils, Error, Interceptor};
+use super::{config_migration, config_profiles, config_schema, crash_report, game::{Game, Region}, ipc, log::LogLevel, plurals, template, template_filters, tl_repo, Error, Interceptor};
 
 pub const REPO_PATH: &str = "kairusds/Hachimi-Edge";
 pub const GITHUB_API: &str = "https://api.github.com/repos";
@@ -51,6 +52,7 @@ pub struct Hachimi {
     pub hooking_finished: AtomicBool,
     pub plugins: Mutex<Vec<Plugin>>,
     pub plugin_init_callbacks: Mutex<Vec<(usize, usize)>>,
+    pub config_changed_callbacks: Mutex<Vec<(usize, usize)>>,
     #[cfg(target_os = "windows")]
     pub present_callbacks: Mutex<Vec<(usize, usize)>>,
 
@@ -99,7 +101,7 @@ impl Hachimi {
         let instance = match Self::new() {
             Ok(v) => v,
             Err(e) => {
-                super::log::init(false, false); // early init to log error
+                super::log::init(false, false, &FnvHashMap::default()); // early init to log error
                 error!("Init failed: {}", e);
                 return false;
             }
@@ -115,11 +117,18 @@ impl Hachimi {
             instance.config.store(Arc::new(config));
         }
 
-        super::log::init(config.debug_mode, config.enable_file_logging);
+        super::log::init(config.debug_mode, config.enable_file_logging, &config.log_levels);
 
         info!("Hachimi {}", env!("HACHIMI_DISPLAY_VERSION"));
         info!("Game region: {}", instance.game.region);
 
+        crash_report::init(&instance.game.data_dir);
+
+        if let Err(e) = config_schema::write_schemas(&instance.game.data_dir) {
+            warn!("Failed to write config schemas: {}", e);
+        }
+        instance.validate_config_files();
+
         if let Err(e) = instance.repair_tl_repo_state() {
             error!("TL repo repair failed: {}", e);
         }
@@ -151,6 +160,7 @@ impl Hachimi {
             hooking_finished: AtomicBool::new(false),
             plugins: Mutex::default(),
             plugin_init_callbacks: Mutex::default(),
+            config_changed_callbacks: Mutex::default(),
             #[cfg(target_os = "windows")]
             present_callbacks: Mutex::default(),
 
@@ -185,21 +195,14 @@ impl Hachimi {
         })
     }
 
-    // region param is unused?
-    fn load_config(data_dir: &Path, _region: &Region) -> Result<Config, Error> {
-        let config_path = data_dir.join("config.json");
-        if fs::metadata(&config_path).is_ok() {
-            let json = fs::read_to_string(&config_path)?;
-            match serde_json::from_str::<Config>(&json) {
-                Ok(config) => Ok(config),
-                Err(e) => {
-                    eprintln!("Failed to parse config: {}", e);
-                    gui::request_notification(gui::NotificationRequest::ConfigLoadError);
-                    Ok(Config::default())
-                }
-            }
-        }else {
-            Ok(Config::default())
+    fn load_config(data_dir: &Path, region: &Region) -> Result<Config, Error> {
+        config_profiles::load(data_dir, region)
+    }
+
+    /// Logs unknown fields and mistyped values in the config files.
+    fn validate_config_files(&self) {
+        for (path, warnings) in config_profiles::validate_layers(&self.game.data_dir, &self.game.region) {
+            config_schema::log_warnings(&path, &warnings);
         }
     }
 
@@ -211,20 +214,31 @@ impl Hachimi {
                 return;
             }
         };
+        self.validate_config_files();
 
+        let old_id = self.config.load().selected_tl_repo_id;
         new_config.language.set_locale();
         self.config.store(Arc::new(new_config));
 
+        if self.config.load().selected_tl_repo_id != old_id {
+            self.load_localized_data();
+            gui::request_notification(gui::NotificationRequest::TLRepoChanged);
+        }
+        self.on_config_changed();
+
         if Hachimi::is_initialized() && self.hooking_finished.load(atomic::Ordering::Relaxed) {
             Hachimi::instance().start_translation_updater_thread();
         }
     }
 
     pub fn save_config(&self, config: &Config) -> Result<(), Error> {
-        fs::create_dir_all(&self.game.data_dir)?;
-        let config_path = self.get_data_path("config.json");
-        utils::write_json_file(config, &config_path)?;
+        config_profiles::save(&self.game.data_dir, &self.game.region, config)
+    }
 
+    /// Switches to another config profile (None for the base config) and reloads the config.
+    pub fn set_config_profile(&self, name: Option<&str>) -> Result<(), Error> {
+        config_profiles::set_active_profile(&self.game.data_dir, name)?;
+        self.reload_config();
         Ok(())
     }
 
@@ -240,6 +254,7 @@ impl Hachimi {
             self.load_localized_data();
             gui::request_notification(gui::NotificationRequest::TLRepoChanged);
         }
+        self.on_config_changed();
 
         if Hachimi::is_initialized() && self.hooking_finished.load(atomic::Ordering::Relaxed) {
             Hachimi::instance().start_translation_updater_thread();
@@ -248,6 +263,22 @@ impl Hachimi {
         Ok(())
     }
 
+    fn on_config_changed(&self) {
+        let config = self.config.load();
+        super::log::set_filter(config.debug_mode, &config.log_levels);
+
+        // Copied so callbacks can register callbacks or reload plugins without deadlocking
+        let callbacks = self.config_changed_callbacks.lock().unwrap().clone();
+        for &(callback, userdata) in callbacks.iter() {
+            // An earlier callback may have unloaded the plugin this one belongs to
+            if !self.config_changed_callbacks.lock().unwrap().contains(&(callback, userdata)) {
+                continue;
+            }
+            let callback: unsafe extern "C" fn(*mut std::ffi::c_void) = unsafe { std::mem::transmute(callback) };
+            unsafe { callback(userdata as *mut std::ffi::c_void); }
+        }
+    }
+
     pub fn get_active_tl_dir(&self) -> Option<PathBuf> {
         let id = self.config.load().selected_tl_repo_id?;
         Some(self.get_repo_dir(id))
@@ -374,6 +405,7 @@ impl Hachimi {
         info!("GameAssembly finished loading");
         il2cpp::symbols::init();
         il2cpp::hook::init();
+        il2cpp::symbol_report::write_startup_report();
 
         // By the time it finished hooking the game will have already finished initializing
         GameSystem::on_game_initialized();
@@ -610,7 +642,7 @@ fn default_serde_instance<'a, T: Deserialize<'a>>() -> Option<T> {
     T::deserialize(empty_deserializer).ok()
 }
 
-#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, JsonSchema)]
 pub enum TLAutoUpdaterMode {
     Disabled,
     Periodic,
@@ -621,7 +653,7 @@ impl Default for TLAutoUpdaterMode {
     fn default() -> Self { Self::Disabled }
 }
 
-#[derive(Deserialize, Serialize, Clone)]
+#[derive(Deserialize, Serialize, Clone, JsonSchema)]
 pub struct CaptionConfig {
     #[serde(default)]
     pub caption_enable: bool,
@@ -641,6 +673,22 @@ pub struct CaptionConfig {
     pub caption_pos_x: f32,
     #[serde(default = "CaptionConfig::default_pos_y")]
     pub caption_pos_y: f32,
+    /// Replaces the translation pack's (or the built-in) caption rules when set.
+    #[serde(default)]
+    pub caption_rules: Option<Vec<CaptionRule>>,
+    /// Per-category overrides for caption rules; true to caption the category, false to hide it.
+    #[serde(default)]
+    pub caption_categories: FnvHashMap<String, bool>,
+    /// Name of the preset from `caption_styles` applied on top of the options above.
+    #[serde(default)]
+    pub caption_style: Option<String>,
+    #[serde(default)]
+    pub caption_styles: Vec<CaptionStylePreset>,
+    /// Per-character style overrides, keyed by character id.
+    #[serde(default)]
+    pub caption_character_styles: FnvHashMap<i32, CaptionCharacterStyle>,
+    #[serde(default)]
+    pub caption_show_speaker_name: bool,
 }
 
 impl Default for CaptionConfig {
@@ -655,6 +703,12 @@ impl Default for CaptionConfig {
             caption_bg_alpha: 0.0,
             caption_pos_x: 0.0,
             caption_pos_y: -3.0,
+            caption_rules: None,
+            caption_categories: FnvHashMap::default(),
+            caption_style: None,
+            caption_styles: Vec::new(),
+            caption_character_styles: FnvHashMap::default(),
+            caption_show_speaker_name: false,
         }
     }
 }
@@ -670,12 +724,19 @@ impl CaptionConfig {
     fn default_pos_y() -> f32 { -3.0 }
 }
 
-#[derive(Deserialize, Serialize, Clone)]
+#[derive(Deserialize, Serialize, Clone, JsonSchema)]
 pub struct Config {
+    /// Schema version, older configs are upgraded by config_migration before deserializing.
+    #[serde(default = "Config::default_version")]
+    pub version: u32,
     #[serde(default)]
     pub debug_mode: bool,
     #[serde(default)]
     pub enable_file_logging: bool,
+    /// Log level per module path, e.g. `il2cpp::hook` or `sqlparser`. Other modules log at Info,
+    /// or Debug with debug mode on.
+    #[serde(default)]
+    pub log_levels: FnvHashMap<String, LogLevel>,
     #[serde(default)]
     pub apply_atlas_workaround: bool,
     #[serde(default)]
@@ -684,6 +745,9 @@ pub struct Config {
     pub disable_gui: bool,
     #[serde(default)]
     pub disable_gui_once: bool,
+    /// Profile from config_profiles layered on top of this config. Only read from config.json.
+    #[serde(default)]
+    pub config_profile: Option<String>,
     // legacy fallback path. populated by old versions, new code uses selected_tl_repo_id + get_active_tl_dir() exclusively
     // do NOT write this in new code
     pub localized_data_dir: Option<String>,
@@ -708,6 +772,12 @@ pub struct Config {
     pub tl_auto_updater_mode: TLAutoUpdaterMode,
     #[serde(default = "Config::default_tl_auto_updater_interval_sec")]
     pub tl_auto_updater_interval_sec: u64,
+    /// Download speed limit for translation updates in KiB/s, 0 for no limit.
+    #[serde(default)]
+    pub tl_update_speed_limit: u32,
+    /// Silent updates bigger than this many MiB wait for confirmation, 0 to never ask.
+    #[serde(default)]
+    pub tl_update_confirm_size: u32,
 
     #[serde(default)]
     pub disable_translations: bool,
@@ -760,6 +830,9 @@ pub struct Config {
     pub meta_index_url: String,
     #[serde(default)]
     pub ipv4_only: bool,
+    /// Proxy, CA certificate, timeout and URL rewrite settings for every request Hachimi makes.
+    #[serde(default)]
+    pub http: HttpConfig,
     pub physics_update_mode: Option<SpringUpdateMode>,
     #[serde(default)]
     pub cyspring_mono_uncap_frame_scale: bool,
@@ -772,6 +845,14 @@ pub struct Config {
     #[serde(default)]
     pub live_playback_loop: bool,
     #[serde(default)]
+    pub live_playback_keybinds: LivePlaybackKeybinds,
+    #[serde(default)]
+    pub live_subtitles: bool,
+    #[serde(default)]
+    pub free_camera: FreeCameraConfig,
+    #[serde(default)]
+    pub screenshot: ScreenshotConfig,
+    #[serde(default)]
     pub champions_live_show_text: bool,
     #[serde(default = "Config::default_champions_live_resource_id")]
     pub champions_live_resource_id: i32,
@@ -786,14 +867,19 @@ pub struct Config {
 
     // theme settings
     #[serde(default = "Config::default_ui_accent")]
+    #[schemars(with = "[u8; 4]")]
     pub ui_accent_color: egui::Color32,
     #[serde(default = "Config::default_window_fill")]
+    #[schemars(with = "[u8; 4]")]
     pub ui_window_fill: egui::Color32,
     #[serde(default = "Config::default_panel_fill")]
+    #[schemars(with = "[u8; 4]")]
     pub ui_panel_fill: egui::Color32,
     #[serde(default = "Config::default_extreme_bg")]
+    #[schemars(with = "[u8; 4]")]
     pub ui_extreme_bg_color: egui::Color32,
     #[serde(default = "Config::default_text_color")]
+    #[schemars(with = "[u8; 4]")]
     pub ui_text_color: egui::Color32,
     #[serde(default = "Config::default_window_rounding")]
     pub ui_window_rounding: f32,
@@ -808,6 +894,7 @@ pub struct Config {
 }
 
 impl Config {
+    fn default_version() -> u32 { config_migration::CONFIG_VERSION }
     fn default_open_browser_url() -> String { "https://www.google.com/".to_owned() }
     fn default_virtual_res_mult() -> f32 { 1.0 }
     fn default_ui_scale() -> f32 { 1.0 }
@@ -844,6 +931,25 @@ pub struct OsOption<T> {
     windows: Option<T>
 }
 
+/// Schema for `OsOption`. Files list every platform, so both keys are described whichever one this
+/// build reads.
+#[derive(JsonSchema)]
+#[allow(dead_code)]
+struct OsOptionSchema<T> {
+    android: Option<T>,
+    windows: Option<T>
+}
+
+impl<T: JsonSchema> JsonSchema for OsOption<T> {
+    fn schema_name() -> String {
+        format!("OsOption_for_{}", T::schema_name())
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        OsOptionSchema::<T>::json_schema(gen)
+    }
+}
+
 impl<T> OsOption<T> {
     pub fn as_ref(&self) -> Option<&T> {
         #[cfg(target_os = "android")]
@@ -854,7 +960,7 @@ impl<T> OsOption<T> {
     }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[derive(Copy, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
 #[allow(non_camel_case_types)]
 pub enum Language {
     #[serde(rename = "en")]
@@ -971,6 +1077,7 @@ pub struct LocalizedData {
     pub character_system_text_dict: FnvHashMap<i32, FnvHashMap<i32, String>>, // {"character_id": {"voice_id": "text"}}
     pub race_jikkyo_comment_dict: FnvHashMap<i32, String>, // {"id": "text"}
     pub race_jikkyo_message_dict: FnvHashMap<i32, String>, // {"id": "text"}
+    pub caption_rules: Option<Vec<CaptionRule>>,
     assets_path: Option<PathBuf>,
 
     pub plural_form: plurals::Resolver,
@@ -1007,7 +1114,9 @@ impl LocalizedData {
             let ld_config_path = p.join("config.json");
             if fs::metadata(&ld_config_path).is_ok() {
                 let json = fs::read_to_string(&ld_config_path)?;
-                serde_json::from_str(&json)?
+                let value: serde_json::Value = serde_json::from_str(&json)?;
+                config_schema::log_warnings(&ld_config_path, &config_schema::validate_localized_data_config(&value));
+                serde_json::from_value(value)?
             }
             else {
                 warn!("Localized data config not found");
@@ -1030,6 +1139,7 @@ impl LocalizedData {
             character_system_text_dict: Self::load_dict_static(&path, config.character_system_text_dict.as_ref()).unwrap_or_default(),
             race_jikkyo_comment_dict: Self::load_dict_static(&path, config.race_jikkyo_comment_dict.as_ref()).unwrap_or_default(),
             race_jikkyo_message_dict: Self::load_dict_static(&path, config.race_jikkyo_message_dict.as_ref()).unwrap_or_default(),
+            caption_rules: Self::load_dict_static(&path, config.caption_rules.as_ref()),
             assets_path: path.as_ref()
                 .map(|p| config.assets_dir.as_ref()
                     .map(|dir| p.join(dir))
@@ -1149,56 +1259,88 @@ impl LocalizedData {
     }
 }
 
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Clone, JsonSchema)]
 pub struct LocalizedDataConfig {
+    /// Path of the localize dict, relative to the localized data dir.
     pub localize_dict: Option<String>,
+    /// Path of the hashed text dict.
     pub hashed_dict: Option<String>,
+    /// Path of the text data dict (master.mdb text_data).
     pub text_data_dict: Option<String>,
+    /// Path of the character system text dict.
     pub character_system_text_dict: Option<String>,
+    /// Path of the race commentary comment dict.
     pub race_jikkyo_comment_dict: Option<String>,
+    /// Path of the race commentary message dict.
     pub race_jikkyo_message_dict: Option<String>,
+    /// Directory containing replacement assets.
     pub assets_dir: Option<String>,
+    /// Asset bundle loaded on startup, per platform.
     #[serde(default)]
     pub extra_asset_bundle: OsOption<String>,
+    /// Font asset in the extra asset bundle that replaces the game font.
     pub replacement_font_name: Option<String>,
 
+    /// Plural form expression used by the plural template filter.
     pub plural_form: Option<String>,
+    /// Ordinal form expression, its result indexes into `ordinal_types`.
     pub ordinal_form: Option<String>,
+    /// Ordinal suffixes selected by `ordinal_form`.
     #[serde(default)]
     pub ordinal_types: Vec<String>,
+    /// Month names, January first.
     #[serde(default)]
     pub months: Vec<String>,
+    /// Template used to format in-game month text.
     pub month_text_format: Option<String>,
 
+    /// Wraps text by word instead of by character.
     #[serde(default)]
     pub use_text_wrapper: bool,
-    // Predefined line widths are counts of cjk characters.
-    // 1 cjk char = 2 columns, so setting this value to 2 replicates the default behaviour.
+    /// Predefined line widths are counts of cjk characters.
+    /// 1 cjk char = 2 columns, so setting this value to 2 replicates the default behaviour.
     pub line_width_multiplier: Option<f32>,
+    /// Max line count of character system text per cue type, `default` applies to the rest.
     #[serde(default)]
     pub systext_cue_lines: FnvHashMap<String, i32>,
+    /// Path of the caption rules file.
+    pub caption_rules: Option<String>,
+    /// Line break penalties used by the text wrapper.
     pub wrapper_penalties: Option<PenaltiesConfig>,
 
+    /// Lengthens story text clips to fit the translated text.
     #[serde(default)]
     pub auto_adjust_story_clip_length: bool,
+    /// Added to the line count of story text.
     pub story_line_count_offset: Option<i32>,
+    /// Line spacing multiplier of the story text frame.
     pub text_frame_line_spacing_multiplier: Option<f32>,
+    /// Font size multiplier of the story text frame.
     pub text_frame_font_size_multiplier: Option<f32>,
+    /// Line spacing multiplier of story choice buttons.
     pub choice_btn_line_spacing_multiplier: Option<f32>,
+    /// Skill name and description formatting.
     #[serde(default)]
     pub skill_formatting: SkillFormatting,
+    /// Lets text overflow its box instead of being cut off.
     #[serde(default)]
     pub text_common_allow_overflow: bool,
+    /// Shrinks text to fit its box.
     #[serde(default)]
     pub text_common_best_fit: bool,
+    /// Truncates long comic titles on the loading screen with an ellipsis.
     #[serde(default)]
     pub now_loading_comic_title_ellipsis: bool,
 
+    /// Removes ruby (furigana) text.
     #[serde(default)]
     pub remove_ruby: bool,
+    /// Text of the gallery button on the character note screen.
     pub character_note_top_gallery_button: Option<UITextConfig>,
+    /// Text of the talk gallery button on the character note screen.
     pub character_note_top_talk_gallery_button: Option<UITextConfig>,
 
+    /// URL opened instead of the in-game news page.
     pub news_url: Option<String>,
 
     // RESERVED
@@ -1206,8 +1348,9 @@ pub struct LocalizedDataConfig {
     pub _debug: i32
 }
 
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Clone, JsonSchema)]
 pub struct UITextConfig {
+    /// Replacement text.
     pub text: Option<String>,
     pub font_size: Option<i32>,
     pub line_spacing: Option<f32>
@@ -1270,26 +1413,36 @@ pub struct AssetMetadata {
     pub bundle_name: Option<String>
 }
 
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Clone, JsonSchema)]
 pub struct PenaltiesConfig {
+    /// Cost of each line break.
     nline_penalty: usize,
+    /// Cost of a line exceeding the line width.
     overflow_penalty: usize,
+    /// Last lines shorter than 1/n of the line width count as short.
     short_last_line_fraction: usize,
+    /// Cost of a short last line.
     short_last_line_penalty: usize,
+    /// Cost of breaking a word with a hyphen.
     hyphen_penalty: usize
 }
 
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Clone, JsonSchema)]
 pub struct SkillFormatting {
+    /// Line width of skill names.
     #[serde(default = "SkillFormatting::default_length")]
     pub name_length: i32,
+    /// Line width of skill descriptions.
     #[serde(default = "SkillFormatting::default_length")]
     pub desc_length: i32,
+    /// Max line count of skill names in short layouts.
     #[serde(default = "SkillFormatting::default_lines")]
     pub name_short_lines: i32,
 
+    /// Font size multiplier of skill names in short layouts.
     #[serde(default = "SkillFormatting::default_mult")]
     pub name_short_mult: f32,
+    /// Font size multiplier of skill names on the skill learning screen.
     #[serde(default = "SkillFormatting::default_mult")]
     pub name_sp_mult: f32,
 }
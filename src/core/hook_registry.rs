@@ -0,0 +1,142 @@
+//! Install results of every `new_hook!`, and toggling hooks at runtime so problem hooks can be
+//! bisected without editing `disabled_hooks` by hand.
+
+use std::{collections::VecDeque, sync::Mutex};
+
+use serde::Serialize;
+
+use super::{Error, Hachimi};
+use crate::il2cpp::symbols::Thread;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookStatus {
+    Installed,
+    /// Listed in `disabled_hooks` or turned off at runtime.
+    Disabled,
+    /// The original function couldn't be resolved.
+    NullAddress,
+    Failed
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HookInfo {
+    /// `new_hook!` name, the same one used in `disabled_hooks`.
+    pub name: &'static str,
+    pub orig_addr: usize,
+    pub hook_addr: usize,
+    pub status: HookStatus,
+    pub error: Option<String>,
+    /// Whether the hook is currently in place. Soft restarts remove every hook.
+    pub active: bool
+}
+
+static HOOKS: Mutex<Vec<HookInfo>> = Mutex::new(Vec::new());
+static PENDING_TOGGLES: Mutex<VecDeque<(String, bool)>> = Mutex::new(VecDeque::new());
+
+/// Called by `new_hook!`.
+pub fn record(name: &'static str, orig_addr: usize, hook_addr: usize, status: HookStatus, error: Option<String>) {
+    let mut hooks = HOOKS.lock().unwrap();
+    let info = HookInfo { name, orig_addr, hook_addr, status, error, active: false };
+    match hooks.iter_mut().find(|h| h.hook_addr == hook_addr) {
+        Some(existing) => *existing = info,
+        None => hooks.push(info)
+    }
+}
+
+/// Every recorded hook in install order.
+pub fn hooks() -> Vec<HookInfo> {
+    let interceptor = &Hachimi::instance().interceptor;
+    let mut hooks = HOOKS.lock().unwrap().clone();
+    for hook in hooks.iter_mut() {
+        hook.active = interceptor.is_hooked(hook.hook_addr);
+    }
+    hooks
+}
+
+/// Installs or removes every hook named `name` and returns how many changed. Hooks can run on
+/// any thread, but most run on the main thread, so call this from there to avoid unhooking a
+/// function while it's executing.
+pub fn apply(name: &str, enabled: bool) -> Result<usize, Error> {
+    let interceptor = &Hachimi::instance().interceptor;
+    let mut hooks = HOOKS.lock().unwrap();
+    let mut found = false;
+    let mut changed = 0;
+    let mut last_error = None;
+
+    for hook in hooks.iter_mut().filter(|h| h.name == name) {
+        found = true;
+        if hook.orig_addr == 0 {
+            continue;
+        }
+
+        if enabled {
+            if interceptor.is_hooked(hook.hook_addr) {
+                continue;
+            }
+            match interceptor.hook(hook.orig_addr, hook.hook_addr) {
+                Ok(_) => {
+                    info!("Hook enabled: {}", name);
+                    hook.status = HookStatus::Installed;
+                    hook.error = None;
+                    changed += 1;
+                },
+                Err(e) => {
+                    error!("Failed to enable hook {}: {}", name, e);
+                    hook.status = HookStatus::Failed;
+                    hook.error = Some(e.to_string());
+                    last_error = Some(e);
+                }
+            }
+        }
+        else if interceptor.unhook(hook.hook_addr).is_some() {
+            info!("Hook disabled: {}", name);
+            hook.status = HookStatus::Disabled;
+            changed += 1;
+        }
+    }
+
+    if !found {
+        return Err(Error::RuntimeError(format!("Unknown hook: {}", name)));
+    }
+    match last_error {
+        Some(e) if changed == 0 => Err(e),
+        _ => Ok(changed)
+    }
+}
+
+/// Saves the hook's state to `disabled_hooks` so it stays that way on the next launch.
+pub fn persist(name: &str, enabled: bool) -> Result<(), Error> {
+    let hachimi = Hachimi::instance();
+    let mut config = hachimi.config.load().as_ref().clone();
+    let changed = if enabled {
+        config.disabled_hooks.remove(name)
+    }
+    else {
+        config.disabled_hooks.insert(name.to_owned())
+    };
+    if changed {
+        hachimi.save_and_reload_config(config)?;
+    }
+    Ok(())
+}
+
+fn apply_pending() {
+    loop {
+        let Some((name, enabled)) = PENDING_TOGGLES.lock().unwrap().pop_front() else {
+            break;
+        };
+        if let Err(e) = apply(&name, enabled) {
+            error!("{}", e);
+        }
+    }
+}
+
+/// Toggles a hook from any thread. The change is applied on the main thread and persisted.
+pub fn request_set_enabled(name: &str, enabled: bool) {
+    if let Err(e) = persist(name, enabled) {
+        error!("Failed to save disabled hooks: {}", e);
+    }
+    PENDING_TOGGLES.lock().unwrap().push_back((name.to_owned(), enabled));
+    Thread::main_thread().schedule(apply_pending);
+}
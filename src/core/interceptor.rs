@@ -71,6 +71,10 @@ impl Interceptor {
         }
     }
 
+    pub fn is_hooked(&self, hook_addr: usize) -> bool {
+        self.hook_map.lock().unwrap().contains_key(&hook_addr)
+    }
+
     pub fn unhook(&self, hook_addr: usize) -> Option<HookHandle> {
         let hook = self.hook_map.lock().unwrap().remove(&hook_addr)?;
         if let Err(e) = unsafe { hook.unhook() } {
@@ -80,6 +84,19 @@ impl Interceptor {
         Some(hook)
     }
 
+    /// Unhooks every hook whose hook function matches `pred`. Returns the number of hooks removed.
+    pub fn unhook_where(&self, pred: impl Fn(usize) -> bool) -> usize {
+        let mut hook_map = self.hook_map.lock().unwrap();
+        let hook_addrs: Vec<usize> = hook_map.keys().copied().filter(|addr| pred(*addr)).collect();
+        for hook_addr in hook_addrs.iter() {
+            let Some(hook) = hook_map.remove(hook_addr) else { continue };
+            if let Err(e) = unsafe { hook.unhook() } {
+                error!("Failed to unhook {}: {}", hook.orig_addr, e);
+            }
+        }
+        hook_addrs.len()
+    }
+
     pub fn unhook_all(&self) {
         for (_, hook) in self.hook_map.lock().unwrap().drain() {
             if let Err(e) = unsafe { hook.unhook() } {
@@ -97,6 +114,14 @@ impl Interceptor {
     }
 }
 
+/// Marks `$hook` as the hook running on this thread until the end of the enclosing scope, for crash
+/// reports. Goes at the top of every hook function.
+macro_rules! hook_scope {
+    ($hook:ident) => (
+        let _hook_scope = crate::core::crash_report::HookScope::enter(stringify!($hook));
+    )
+}
+
 macro_rules! get_orig_fn {
     ($hook:ident, $type:tt) => (
         unsafe { std::mem::transmute::<usize, $type>(crate::core::Hachimi::instance().interceptor.get_trampoline_addr($hook as *const () as usize)) }
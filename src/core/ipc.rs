@@ -1,9 +1,11 @@
-use std::sync::{Condvar, Mutex};
+use std::{collections::VecDeque, ffi::{c_char, c_void, CStr, CString}, sync::{atomic::{AtomicBool, Ordering}, Arc, Condvar, Mutex, RwLock}, time::Duration};
 
+use fnv::FnvHashMap;
+use once_cell::sync::Lazy;
 use rust_i18n::t;
 use serde::{Deserialize, Serialize};
 use tiny_http::{Header, Method, Request, Response, Server};
-use crate::{core::utils::notify_error, il2cpp::{hook::umamusume::{GameSystem, StoryTimelineController, StoryTimelineData}, symbols::{IList, Thread}}};
+use crate::{core::{config_profiles, hook_registry::{self, HookInfo}, plugin_api, screenshot, utils::notify_error}, il2cpp::{hook::umamusume::{GameSystem, StoryTimelineController, StoryTimelineData}, symbol_report::{self, SymbolReport}, symbols::{IList, Thread}}};
 use super::{Error, Gui, Hachimi};
 
 pub fn start_http(listen_all: bool) {
@@ -58,12 +60,157 @@ fn http_thread(listen_all: bool) {
     }
 }
 
+pub type PluginCommandCallback = unsafe extern "C" fn(
+    args_json: *const c_char, response: *mut c_void, userdata: *mut c_void
+) -> bool;
+
+struct PluginCommand {
+    callback: PluginCommandCallback,
+    userdata: usize,
+    main_thread: bool
+}
+
+static PLUGIN_COMMANDS: Lazy<Mutex<FnvHashMap<String, PluginCommand>>> = Lazy::new(|| Mutex::default());
+/// Held for reading while a plugin command runs and for writing while a plugin's commands are
+/// removed, so a plugin can't be unloaded in the middle of one of its commands. Commands that run
+/// off the main thread mustn't wait on it while running, since plugins are unloaded from there.
+static PLUGIN_COMMAND_CALLS: RwLock<()> = RwLock::new(());
+
+const BUILTIN_COMMANDS: &[&str] = &["StoryGotoBlock", "ReloadLocalizedData", "SoftReset", "Capabilities", "Plugin", "ReloadPlugin", "Screenshot", "ListConfigProfiles", "SetConfigProfile", "ListHooks", "SetHookEnabled", "SymbolReport"];
+const MAIN_THREAD_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub fn register_plugin_command(name: String, callback: PluginCommandCallback, userdata: usize, main_thread: bool) -> bool {
+    if name.is_empty() {
+        return false;
+    }
+    let mut commands = PLUGIN_COMMANDS.lock().unwrap();
+    if commands.contains_key(&name) {
+        warn!("IPC command already registered: {}", name);
+        return false;
+    }
+    commands.insert(name, PluginCommand { callback, userdata, main_thread });
+    true
+}
+
+pub fn unregister_plugin_command(name: &str) -> bool {
+    PLUGIN_COMMANDS.lock().unwrap().remove(name).is_some()
+}
+
+pub fn unregister_plugin_commands_where(owns: &dyn Fn(usize) -> bool) {
+    let _calls = PLUGIN_COMMAND_CALLS.write().unwrap();
+    PLUGIN_COMMANDS.lock().unwrap().retain(|_, command| !owns(command.callback as usize));
+}
+
+fn plugin_command_names() -> Vec<String> {
+    let mut names: Vec<String> = PLUGIN_COMMANDS.lock().unwrap().keys().cloned().collect();
+    names.sort();
+    names
+}
+
+static MAIN_THREAD_TASKS: Mutex<VecDeque<Box<dyn FnOnce() + Send>>> = Mutex::new(VecDeque::new());
+
+/// Returns None if the task didn't start within the timeout, in which case it never runs. Tasks may
+/// hold pointers into plugins that are gone by the time the main thread gets to them.
+fn run_on_main_thread<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> Option<T> {
+    let (tx, rx) = crossbeam_channel::bounded(1);
+    // Claimed by whichever comes first, the task starting or the caller giving up on it
+    let claimed = Arc::new(AtomicBool::new(false));
+    let task_claimed = Arc::clone(&claimed);
+    MAIN_THREAD_TASKS.lock().unwrap().push_back(Box::new(move || {
+        if task_claimed.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        _ = tx.send(f());
+    }));
+
+    Thread::main_thread().schedule(|| {
+        let task = MAIN_THREAD_TASKS.lock().unwrap().pop_front();
+        if let Some(task) = task {
+            task();
+        }
+    });
+
+    if let Ok(result) = rx.recv_timeout(MAIN_THREAD_TIMEOUT) {
+        return Some(result);
+    }
+    if !claimed.swap(true, Ordering::AcqRel) {
+        return None;
+    }
+    // Already running, so the result is still coming
+    rx.recv().ok()
+}
+
+/// Looks the command up again right before calling it, returns None if it's been unregistered.
+fn call_plugin_command(name: &str, args_json: &CStr) -> Option<(bool, String)> {
+    let _call = PLUGIN_COMMAND_CALLS.read().unwrap();
+    let (callback, userdata) = {
+        let commands = PLUGIN_COMMANDS.lock().unwrap();
+        let command = commands.get(name)?;
+        (command.callback, command.userdata)
+    };
+
+    let mut response = String::new();
+    let ok = unsafe {
+        callback(args_json.as_ptr(), &mut response as *mut String as *mut c_void, userdata as *mut c_void)
+    };
+    Some((ok, response))
+}
+
+fn run_plugin_command(name: &str, args: serde_json::Value) -> CommandResponse {
+    let Some(main_thread) = PLUGIN_COMMANDS.lock().unwrap().get(name).map(|command| command.main_thread) else {
+        return CommandResponse::error(format!("Unknown plugin command: {}", name));
+    };
+
+    let Ok(args_json) = CString::new(args.to_string()) else {
+        return CommandResponse::error("Invalid command arguments".to_owned());
+    };
+
+    let result = if main_thread {
+        let command_name = name.to_owned();
+        match run_on_main_thread(move || call_plugin_command(&command_name, &args_json)) {
+            Some(result) => result,
+            None => return CommandResponse::error(format!("Plugin command timed out: {}", name))
+        }
+    }
+    else {
+        call_plugin_command(name, &args_json)
+    };
+
+    // Unregistered while waiting, e.g. by a plugin reload
+    let Some((ok, response)) = result else {
+        return CommandResponse::error(format!("Unknown plugin command: {}", name));
+    };
+
+    if !ok {
+        return CommandResponse::error(if response.is_empty() { None } else { Some(response) });
+    }
+
+    if response.is_empty() {
+        return CommandResponse::PluginResult { result: serde_json::Value::Null };
+    }
+    match serde_json::from_str(&response) {
+        Ok(result) => CommandResponse::PluginResult { result },
+        Err(e) => CommandResponse::error(format!("Plugin command returned invalid JSON: {}", e))
+    }
+}
+
+fn capabilities() -> CommandResponse {
+    CommandResponse::Capabilities {
+        version: env!("HACHIMI_DISPLAY_VERSION"),
+        commands: BUILTIN_COMMANDS,
+        plugin_commands: plugin_command_names()
+    }
+}
+
 static STORY_GOTO_BLOCK_PARAMS: Mutex<(i32, bool)> = Mutex::new((0, false));
 static STORY_GOTO_BLOCK_CVAR: Condvar = Condvar::new();
 
 fn on_http_request(request: &mut Request) -> Result<CommandResponse, Error> {
     let method = request.method();
     if *method == Method::Get {
+        if request.url().trim_end_matches('/') == "/capabilities" {
+            return Ok(capabilities());
+        }
         return Ok(CommandResponse::HelloWorld { message: "Hachimi's IPC server is working!" });
     }
     else if *method != Method::Post {
@@ -160,6 +307,81 @@ fn on_http_request(request: &mut Request) -> Result<CommandResponse, Error> {
                     mutex.lock().unwrap().show_notification(&t!("notification.ipc_softreset_exec"));
                 }
             } else { notify_error("SoftReset needs exec=true"); }
+        },
+
+        Command::Capabilities => return Ok(capabilities()),
+
+        Command::Plugin { command, args } => return Ok(run_plugin_command(&command, args)),
+
+        Command::ReloadPlugin { name } => {
+            let res = run_on_main_thread(move || plugin_api::reload_plugin(&name).map_err(|e| e.to_string()));
+            match res {
+                Some(Ok(())) => (),
+                Some(Err(e)) => return Ok(CommandResponse::error(e)),
+                None => return Ok(CommandResponse::error("Plugin reload timed out".to_owned()))
+            }
+        },
+
+        Command::Screenshot { multiplier, hide_game_ui } => {
+            let config = Hachimi::instance().config.load();
+            let multiplier = multiplier.unwrap_or(config.screenshot.multiplier);
+            let hide_game_ui = hide_game_ui.unwrap_or(config.screenshot.hide_game_ui);
+
+            let res = run_on_main_thread(move || {
+                screenshot::capture(multiplier, hide_game_ui).map_err(|e| e.to_string())
+            });
+            let capture = match res {
+                Some(Ok(v)) => v,
+                Some(Err(e)) => return Ok(CommandResponse::error(e)),
+                None => return Ok(CommandResponse::error("Screenshot timed out".to_owned()))
+            };
+            let path = capture.save()?;
+            return Ok(CommandResponse::Screenshot { path: path.display().to_string() });
+        },
+
+        Command::ListConfigProfiles => {
+            let hachimi = Hachimi::instance();
+            return Ok(CommandResponse::ConfigProfiles {
+                active: hachimi.config.load().config_profile.clone(),
+                profiles: config_profiles::list_profiles(&hachimi.game.data_dir)
+            });
+        },
+
+        Command::SetConfigProfile { name } => {
+            let profile = name.clone();
+            let res = run_on_main_thread(move || {
+                Hachimi::instance().set_config_profile(profile.as_deref()).map_err(|e| e.to_string())
+            });
+            match res {
+                Some(Ok(())) => (),
+                Some(Err(e)) => return Ok(CommandResponse::error(e)),
+                None => return Ok(CommandResponse::error("Switching the config profile timed out".to_owned()))
+            }
+            if let Some(mutex) = Gui::instance() {
+                let name = name.unwrap_or_else(|| t!("config_profiles.base").into_owned());
+                mutex.lock().unwrap().show_notification(&t!("config_profiles.switched", name = name));
+            }
+        },
+
+        Command::ListHooks => {
+            return Ok(CommandResponse::Hooks { hooks: hook_registry::hooks() });
+        },
+
+        Command::SetHookEnabled { name, enabled } => {
+            let hook_name = name.clone();
+            let res = run_on_main_thread(move || hook_registry::apply(&hook_name, enabled).map_err(|e| e.to_string()));
+            let changed = match res {
+                Some(Ok(v)) => v,
+                Some(Err(e)) => return Ok(CommandResponse::error(e)),
+                None => return Ok(CommandResponse::error("Toggling the hook timed out".to_owned()))
+            };
+            hook_registry::persist(&name, enabled)?;
+            return Ok(CommandResponse::HookToggled { changed });
+        },
+
+        Command::SymbolReport => {
+            let (report, _) = symbol_report::write()?;
+            return Ok(CommandResponse::SymbolReport { report });
         }
     }
 
@@ -193,7 +415,33 @@ enum Command {
     ReloadLocalizedData,
     SoftReset {
         exec: bool
-    }
+    },
+    Capabilities,
+    Plugin {
+        command: String,
+        #[serde(default)]
+        args: serde_json::Value
+    },
+    ReloadPlugin {
+        name: String
+    },
+    Screenshot {
+        #[serde(default)]
+        multiplier: Option<u32>,
+        #[serde(default)]
+        hide_game_ui: Option<bool>
+    },
+    ListConfigProfiles,
+    SetConfigProfile {
+        #[serde(default)]
+        name: Option<String>
+    },
+    ListHooks,
+    SetHookEnabled {
+        name: String,
+        enabled: bool
+    },
+    SymbolReport
 }
 
 #[derive(Serialize)]
@@ -207,6 +455,37 @@ enum CommandResponse {
 
     HelloWorld {
         message: &'static str
+    },
+
+    Capabilities {
+        version: &'static str,
+        commands: &'static [&'static str],
+        plugin_commands: Vec<String>
+    },
+
+    PluginResult {
+        result: serde_json::Value
+    },
+
+    Screenshot {
+        path: String
+    },
+
+    ConfigProfiles {
+        active: Option<String>,
+        profiles: Vec<String>
+    },
+
+    Hooks {
+        hooks: Vec<HookInfo>
+    },
+
+    HookToggled {
+        changed: usize
+    },
+
+    SymbolReport {
+        report: SymbolReport
     }
 }
 
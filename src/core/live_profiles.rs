@@ -0,0 +1,162 @@
+use std::{fs, path::PathBuf, sync::Mutex};
+
+use fnv::{FnvHashMap, FnvHashSet};
+use once_cell::sync::Lazy;
+use rust_i18n::t;
+use serde::{Deserialize, Serialize};
+
+use crate::il2cpp::sql;
+
+use super::{utils, Error, Hachimi};
+
+/// Character id the game uses for mob slots, the actual mob is picked by `mob_id`.
+pub const MOB_CHARA_ID: i32 = 1;
+
+/// Override for one member slot of a live. 0 keeps the game's choice.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct LiveMemberOverride {
+    pub chara_id: i32,
+    pub dress_id: i32,
+    /// Only used when the slot is a mob.
+    pub mob_id: i32,
+}
+
+impl LiveMemberOverride {
+    pub fn is_empty(&self) -> bool {
+        self.chara_id == 0 && self.dress_id == 0 && self.mob_id == 0
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct LiveProfile {
+    pub name: String,
+    pub music_id: i32,
+    pub members: Vec<LiveMemberOverride>,
+    /// Vocal character ids by slot, used instead of `live_vocals_swap` when any is set.
+    pub vocals: [i32; 6],
+}
+
+impl LiveProfile {
+    pub fn has_vocals(&self) -> bool {
+        self.vocals.iter().any(|&id| id != 0)
+    }
+}
+
+/// Contents of live_profiles.json.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct LiveProfiles {
+    pub profiles: Vec<LiveProfile>,
+    /// Name of the profile applied when a live with the given music id starts.
+    pub active: FnvHashMap<i32, String>,
+}
+
+impl LiveProfiles {
+    const FILENAME: &'static str = "live_profiles.json";
+
+    fn path() -> PathBuf {
+        Hachimi::instance().get_data_path(Self::FILENAME)
+    }
+
+    fn load() -> Self {
+        let path = Self::path();
+        let Ok(json) = fs::read_to_string(&path) else {
+            return Self::default();
+        };
+        match serde_json::from_str(&json) {
+            Ok(profiles) => profiles,
+            Err(e) => {
+                error!("Failed to parse {}: {}", path.display(), e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn active_profile(&self, music_id: i32) -> Option<&LiveProfile> {
+        let name = self.active.get(&music_id)?;
+        self.profiles.iter().find(|p| p.music_id == music_id && &p.name == name)
+    }
+}
+
+/// Master data ids used to validate profiles.
+#[derive(Default)]
+pub struct MasterIds {
+    pub chara_ids: FnvHashSet<i32>,
+    pub dress_ids: FnvHashSet<i32>,
+    /// Dresses that can be worn in the live theater.
+    pub live_dress_ids: Vec<i32>,
+    pub mob_ids: FnvHashSet<i32>,
+    pub music_ids: FnvHashSet<i32>,
+}
+
+impl MasterIds {
+    pub fn load() -> MasterIds {
+        let mut live_dress_ids = sql::get_default_dress_ids();
+        live_dress_ids.sort_unstable();
+        MasterIds {
+            chara_ids: sql::get_all_chara_ids().into_iter().collect(),
+            dress_ids: sql::get_all_dress_ids().into_iter().collect(),
+            live_dress_ids,
+            mob_ids: sql::get_all_mob_ids().into_iter().collect(),
+            music_ids: sql::get_all_music_ids().into_iter().collect(),
+        }
+    }
+
+    /// Returns a message for every id in the profile that doesn't exist in the master data.
+    pub fn validate(&self, profile: &LiveProfile) -> Vec<String> {
+        let mut errors = Vec::new();
+        let name = profile.name.as_str();
+
+        if profile.name.trim().is_empty() {
+            errors.push(t!("live_profiles.error_empty_name").into_owned());
+        }
+        if !self.music_ids.contains(&profile.music_id) {
+            errors.push(t!("live_profiles.error_music_id", name = name, id = profile.music_id).into_owned());
+        }
+
+        for (i, member) in profile.members.iter().enumerate() {
+            let slot = i + 1;
+            if member.chara_id != 0 && member.chara_id != MOB_CHARA_ID && !self.chara_ids.contains(&member.chara_id) {
+                errors.push(t!("live_profiles.error_chara_id", name = name, slot = slot, id = member.chara_id).into_owned());
+            }
+            if member.dress_id != 0 && !self.dress_ids.contains(&member.dress_id) {
+                errors.push(t!("live_profiles.error_dress_id", name = name, slot = slot, id = member.dress_id).into_owned());
+            }
+            if member.mob_id != 0 && !self.mob_ids.contains(&member.mob_id) {
+                errors.push(t!("live_profiles.error_mob_id", name = name, slot = slot, id = member.mob_id).into_owned());
+            }
+        }
+
+        for (i, &id) in profile.vocals.iter().enumerate() {
+            if id != 0 && !self.chara_ids.contains(&id) {
+                errors.push(t!("live_profiles.error_vocal_id", name = name, slot = i + 1, id = id).into_owned());
+            }
+        }
+
+        errors
+    }
+}
+
+static PROFILES: Lazy<Mutex<LiveProfiles>> = Lazy::new(|| Mutex::new(LiveProfiles::load()));
+
+pub fn profiles() -> LiveProfiles {
+    PROFILES.lock().unwrap().clone()
+}
+
+pub fn active_profile(music_id: i32) -> Option<LiveProfile> {
+    PROFILES.lock().unwrap().active_profile(music_id).cloned()
+}
+
+/// Replaces the profiles and writes them to live_profiles.json.
+pub fn save_profiles(profiles: LiveProfiles) -> Result<PathBuf, Error> {
+    let path = LiveProfiles::path();
+    utils::write_json_file(&profiles, &path)?;
+    *PROFILES.lock().unwrap() = profiles;
+    Ok(path)
+}
+
+pub fn reload_profiles() {
+    *PROFILES.lock().unwrap() = LiveProfiles::load();
+}
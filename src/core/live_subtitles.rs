@@ -0,0 +1,163 @@
+use std::{fmt::Write, fs, path::PathBuf, sync::Mutex};
+
+use crate::core::{Error, Hachimi};
+
+#[derive(Clone)]
+pub struct SubtitleCue {
+    /// Seconds from the start of the live
+    pub start: f32,
+    /// Cues without an end time last until the next cue starts
+    pub end: Option<f32>,
+    pub text: String
+}
+
+struct LiveSubtitles {
+    /// Lyrics file name, e.g. m1001_lyrics
+    name: String,
+    /// Lyrics as loaded by the game, before any translation is applied
+    game_lyrics: Vec<SubtitleCue>,
+    track: Vec<SubtitleCue>
+}
+
+static CURRENT: Mutex<Option<LiveSubtitles>> = Mutex::new(None);
+
+/// Called when the game loads a lyrics file. `game_lyrics` are the original timings and text,
+/// `translated` are the same timings after the translation pack's lyrics dict has been applied.
+pub fn on_lyrics_loaded(name: &str, game_lyrics: Vec<SubtitleCue>, translated: Vec<SubtitleCue>) {
+    let track = load_pack_track(name).unwrap_or(translated);
+    *CURRENT.lock().unwrap() = Some(LiveSubtitles {
+        name: name.to_owned(),
+        game_lyrics,
+        track
+    });
+}
+
+/// Looks for lyrics/<name>.lrc or lyrics/<name>.srt in the translation pack's assets.
+fn load_pack_track(name: &str) -> Option<Vec<SubtitleCue>> {
+    let localized_data = Hachimi::instance().localized_data.load();
+    for (ext, parse) in [("lrc", parse_lrc as fn(&str) -> Vec<SubtitleCue>), ("srt", parse_srt)] {
+        let Some(path) = localized_data.get_assets_path(format!("lyrics/{}.{}", name, ext)) else {
+            return None;
+        };
+        let Ok(contents) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let cues = parse(contents.trim_start_matches('\u{feff}'));
+        if cues.is_empty() {
+            warn!("No subtitles found in '{}'", path.display());
+            continue;
+        }
+        info!("Loaded live subtitles from '{}'", path.display());
+        return Some(cues);
+    }
+    None
+}
+
+/// Text that should be shown at `time`, if any.
+pub fn subtitle_at(time: f32) -> Option<String> {
+    let current = CURRENT.lock().unwrap();
+    let cues = &current.as_ref()?.track;
+    let index = cues.partition_point(|cue| cue.start <= time).checked_sub(1)?;
+    let cue = &cues[index];
+    let end = cue.end.or_else(|| cues.get(index + 1).map(|next| next.start)).unwrap_or(f32::MAX);
+    if time >= end || cue.text.is_empty() {
+        return None;
+    }
+    Some(cue.text.clone())
+}
+
+pub fn has_lyrics() -> bool {
+    CURRENT.lock().unwrap().is_some()
+}
+
+/// Called when leaving a live so the next one doesn't pick up this one's lyrics.
+pub fn clear() {
+    *CURRENT.lock().unwrap() = None;
+}
+
+/// Writes the game's lyric timings for the current live to the `lyrics_export` folder in the
+/// data dir, in a format that can be dropped into a translation pack once translated.
+pub fn export_game_lyrics() -> Result<PathBuf, Error> {
+    let current = CURRENT.lock().unwrap();
+    let Some(subtitles) = current.as_ref() else {
+        return Err(Error::RuntimeError("No lyrics have been loaded".to_owned()));
+    };
+
+    let dir = Hachimi::instance().get_data_path("lyrics_export");
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("{}.lrc", subtitles.name));
+    fs::write(&path, to_lrc(&subtitles.game_lyrics))?;
+    Ok(path)
+}
+
+fn lrc_timestamp(secs: f32) -> String {
+    let cs = (secs.max(0.0) * 100.0).round() as u64;
+    format!("[{:02}:{:02}.{:02}]", cs / 6000, cs / 100 % 60, cs % 100)
+}
+
+pub fn to_lrc(cues: &[SubtitleCue]) -> String {
+    let mut out = String::new();
+    for cue in cues {
+        // LRC has no end times, so an empty line marks where a timed cue stops
+        _ = writeln!(out, "{}{}", lrc_timestamp(cue.start), cue.text.replace('\n', " "));
+        if let Some(end) = cue.end {
+            _ = writeln!(out, "{}", lrc_timestamp(end));
+        }
+    }
+    out
+}
+
+/// Parses `mm:ss`, `mm:ss.xx` or `mm:ss:xx`.
+fn parse_lrc_timestamp(s: &str) -> Option<f32> {
+    let (minutes, rest) = s.split_once(':')?;
+    let seconds = rest.replacen(':', ".", 1);
+    Some(minutes.trim().parse::<f32>().ok()? * 60.0 + seconds.trim().parse::<f32>().ok()?)
+}
+
+pub fn parse_lrc(contents: &str) -> Vec<SubtitleCue> {
+    let mut cues = Vec::new();
+    for line in contents.lines() {
+        let mut rest = line.trim();
+        let mut times = Vec::new();
+        // A line can have several timestamps: [00:12.00][01:30.00]text
+        while let Some(tag) = rest.strip_prefix('[') {
+            let Some((tag, after)) = tag.split_once(']') else { break; };
+            // Metadata tags like [ar:...] don't parse as timestamps and are skipped
+            if let Some(time) = parse_lrc_timestamp(tag) {
+                times.push(time);
+            }
+            rest = after;
+        }
+        for time in times {
+            cues.push(SubtitleCue { start: time, end: None, text: rest.trim().to_owned() });
+        }
+    }
+    cues.sort_by(|a, b| a.start.total_cmp(&b.start));
+    cues
+}
+
+/// Parses `hh:mm:ss,mmm`.
+fn parse_srt_timestamp(s: &str) -> Option<f32> {
+    let (hms, ms) = s.trim().split_once([',', '.'])?;
+    let mut parts = hms.split(':').map(|p| p.parse::<f32>());
+    let (h, m, sec) = (parts.next()?.ok()?, parts.next()?.ok()?, parts.next()?.ok()?);
+    Some(h * 3600.0 + m * 60.0 + sec + ms.parse::<f32>().ok()? / 1000.0)
+}
+
+pub fn parse_srt(contents: &str) -> Vec<SubtitleCue> {
+    let mut cues = Vec::new();
+    let normalized = contents.replace("\r\n", "\n");
+    for block in normalized.split("\n\n") {
+        let mut lines = block.lines().skip_while(|l| l.trim().is_empty());
+        // The index line is optional in practice, so look for the timing line
+        let Some(timing) = lines.by_ref().find(|l| l.contains("-->")) else { continue; };
+        let Some((start, end)) = timing.split_once("-->") else { continue; };
+        let (Some(start), Some(end)) = (parse_srt_timestamp(start), parse_srt_timestamp(end)) else {
+            continue;
+        };
+        let text = lines.collect::<Vec<_>>().join("\n");
+        cues.push(SubtitleCue { start, end: Some(end), text });
+    }
+    cues.sort_by(|a, b| a.start.total_cmp(&b.start));
+    cues
+}
@@ -1,4 +1,5 @@
 use crate::{
+    core::Hachimi,
     il2cpp::{
         api::il2cpp_runtime_invoke,
         ext::Il2CppObjectExt,
@@ -6,12 +7,20 @@ use crate::{
             umamusume::{AudioManager, Director, LiveTimeController, LiveViewController, SceneManager},
             Cute_Cri_Assembly::{AtomSourceEx, CuteAudioSource, CuteAudioSourcePool},
             CriMw_CriWare_Runtime::CriAtomExPlayer,
+            UnityEngine_CoreModule::Time,
         },
-        symbols::{Array, IList, get_field_from_name, get_field_object_value, get_method_cached}, types::*
+        symbols::{Array, IList, Thread, get_field_from_name, get_field_object_value, get_method_cached}, types::*
     }
 };
 
-use std::{ffi::c_void, ptr::null_mut, sync::atomic::{AtomicBool, Ordering}};
+use std::{
+    ffi::c_void,
+    ptr::null_mut,
+    sync::{atomic::{AtomicBool, AtomicU32, Ordering}, Mutex}
+};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -106,16 +115,9 @@ pub fn reset_live_drag_state() {
     DRAG_IN_PROGRESS.store(false, Ordering::Release);
 }
 
-unsafe fn process_playback(
-    playback: &mut AudioPlayback,
-    audio_ctrl_dict: *mut Il2CppObject,
-    target_time: f32
-) {
+unsafe fn find_atom_source(playback: &AudioPlayback, audio_ctrl_dict: *mut Il2CppObject) -> Option<*mut Il2CppObject> {
     let dict_class = (*audio_ctrl_dict).klass();
-    let get_item_method = match get_method_cached(dict_class, c"get_Item", 1) {
-        Ok(m) => m,
-        Err(_) => return
-    };
+    let get_item_method = get_method_cached(dict_class, c"get_Item", 1).ok()?;
 
     let mut key = playback.soundGroup;
     let mut get_item_params: [*mut c_void; 1] = [&mut key as *mut _ as *mut c_void];
@@ -124,17 +126,17 @@ unsafe fn process_playback(
         get_item_method, audio_ctrl_dict as *mut c_void,
         get_item_params.as_mut_ptr(), &mut exc
     );
-    if !exc.is_null() || audio_ctrl.is_null() { return; }
+    if !exc.is_null() || audio_ctrl.is_null() { return None; }
     let audio_ctrl = audio_ctrl as *mut Il2CppObject;
 
     let pool_field = get_field_from_name((*audio_ctrl).klass(), c"pool");
     let pool = get_field_object_value::<Il2CppObject>(audio_ctrl, pool_field);
-    if pool.is_null() { return; }
+    if pool.is_null() { return None; }
 
     let source_list = CuteAudioSourcePool::get_sourceList(pool);
-    if source_list.is_null() { return; }
+    if source_list.is_null() { return None; }
 
-    let Some(list) = IList::<*mut Il2CppObject>::new(source_list) else { return; };
+    let list = IList::<*mut Il2CppObject>::new(source_list)?;
     let count = list.count();
     let mut cute_audio_source: *mut Il2CppObject = null_mut();
 
@@ -148,16 +150,26 @@ unsafe fn process_playback(
         }
     }
 
-    if cute_audio_source.is_null() { return; }
+    if cute_audio_source.is_null() { return None; }
 
     let source_list2 = CuteAudioSource::get_sourceList(cute_audio_source);
-    if source_list2.is_null() { return; }
+    if source_list2.is_null() { return None; }
 
     let using_index = CuteAudioSource::get_usingIndex(cute_audio_source);
 
-    let Some(list2) = IList::<*mut Il2CppObject>::new(source_list2) else { return; };
+    let list2 = IList::<*mut Il2CppObject>::new(source_list2)?;
     let atom_source = list2.get(using_index).unwrap_or(null_mut());
-    if atom_source.is_null() { return; }
+    if atom_source.is_null() { return None; }
+
+    Some(atom_source)
+}
+
+unsafe fn process_playback(
+    playback: &mut AudioPlayback,
+    audio_ctrl_dict: *mut Il2CppObject,
+    target_time: f32
+) {
+    let Some(atom_source) = find_atom_source(playback, audio_ctrl_dict) else { return; };
 
     let player = AtomSourceEx::get_player(atom_source);
     if player.is_null() { return; }
@@ -201,43 +213,256 @@ pub fn move_live_playback(target_time: f32) {
         LiveTimeController::set_CurrentTime_TC(time_controller, target_time);
     }
 
+    for_each_song_playback(|playback, audio_ctrl_dict| unsafe {
+        process_playback(playback, audio_ctrl_dict, target_time);
+    });
+
+    if !dragging && !was_paused {
+        Director::PauseLive(director, false);
+    }
+}
+
+/// Runs `f` on the song playback and every character vocal playback of the current live.
+fn for_each_song_playback(mut f: impl FnMut(&mut AudioPlayback, *mut Il2CppObject)) {
     let audio_manager = AudioManager::instance();
+    if audio_manager.is_null() { return; }
+
+    let cri_audio_manager = AudioManager::get_CriAudioManager();
+    if cri_audio_manager.is_null() {
+        warn!("get_CriAudioManager returned null! Skipping audio sync.");
+        return;
+    }
 
-    if !audio_manager.is_null() {
-        let cri_audio_manager = AudioManager::get_CriAudioManager();
-
-        if !cri_audio_manager.is_null() {
-            let audio_ctrl_dict_field = get_field_from_name(
-                unsafe { (*cri_audio_manager).klass() }, c"audioCtrlDict"
-            );
-            let audio_ctrl_dict = get_field_object_value::<Il2CppObject>(
-                cri_audio_manager, audio_ctrl_dict_field
-            );
-
-            if !audio_ctrl_dict.is_null() {
-                let mut song_playback = AudioManager::get__songPlayback(audio_manager);
-
-                unsafe { process_playback(&mut song_playback, audio_ctrl_dict, target_time); }
-
-                AudioManager::set__songPlayback(audio_manager, song_playback);
-
-                let song_chara_playbacks = AudioManager::get__songCharaPlaybacks(audio_manager);
-                if !song_chara_playbacks.is_null() {
-                    let chara_playbacks = Array::<AudioPlayback>::from(song_chara_playbacks);
-                    unsafe {
-                        let slice = chara_playbacks.as_slice();
-                        for i in 0..slice.len() {
-                            process_playback(&mut slice[i], audio_ctrl_dict, target_time);
-                        }
-                    }
-                }
+    let audio_ctrl_dict_field = get_field_from_name(
+        unsafe { (*cri_audio_manager).klass() }, c"audioCtrlDict"
+    );
+    let audio_ctrl_dict = get_field_object_value::<Il2CppObject>(
+        cri_audio_manager, audio_ctrl_dict_field
+    );
+    if audio_ctrl_dict.is_null() { return; }
+
+    let mut song_playback = AudioManager::get__songPlayback(audio_manager);
+    f(&mut song_playback, audio_ctrl_dict);
+    AudioManager::set__songPlayback(audio_manager, song_playback);
+
+    let song_chara_playbacks = AudioManager::get__songCharaPlaybacks(audio_manager);
+    if !song_chara_playbacks.is_null() {
+        let chara_playbacks = Array::<AudioPlayback>::from(song_chara_playbacks);
+        unsafe {
+            for playback in chara_playbacks.as_slice() {
+                f(playback, audio_ctrl_dict);
             }
-        } else {
-            warn!("get_CriAudioManager returned null! Skipping audio sync.");
         }
     }
+}
 
-    if !dragging && !was_paused {
-        Director::PauseLive(director, false);
+/// Playback speed multipliers offered in the live slider.
+pub const LIVE_SPEEDS: &[f32] = &[0.25, 0.5, 0.75, 1.0, 1.25, 1.5, 2.0];
+
+/// Length of a single step while frame stepping, the timeline runs at 60 fps.
+pub const LIVE_FRAME_STEP: f32 = 1.0 / 60.0;
+
+// f32 bits of the current speed multiplier
+static LIVE_SPEED: AtomicU32 = AtomicU32::new(0x3f80_0000); // 1.0
+static LIVE_LOOP: Mutex<LiveLoop> = Mutex::new(LiveLoop { a: None, b: None });
+static PENDING_ACTIONS: Mutex<Vec<LivePlaybackAction>> = Mutex::new(Vec::new());
+
+#[derive(Clone, Copy, Default)]
+pub struct LiveLoop {
+    pub a: Option<f32>,
+    pub b: Option<f32>,
+}
+
+impl LiveLoop {
+    /// The loop section, if both points are set and form a valid range.
+    pub fn range(&self) -> Option<(f32, f32)> {
+        match (self.a, self.b) {
+            (Some(a), Some(b)) if a < b => Some((a, b)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum LivePlaybackAction {
+    SetLoopA,
+    SetLoopB,
+    ClearLoop,
+    SpeedDown,
+    SpeedUp,
+    StepBack,
+    StepForward,
+}
+
+/// Hotkeys for the live playback controls, stored as platform key codes (virtual-key codes on
+/// Windows, Android keycodes on Android). 0 means unbound.
+#[derive(Deserialize, Serialize, Clone, Default, JsonSchema)]
+pub struct LivePlaybackKeybinds {
+    #[serde(default)]
+    pub set_loop_a: u16,
+    #[serde(default)]
+    pub set_loop_b: u16,
+    #[serde(default)]
+    pub clear_loop: u16,
+    #[serde(default)]
+    pub speed_down: u16,
+    #[serde(default)]
+    pub speed_up: u16,
+    #[serde(default)]
+    pub step_back: u16,
+    #[serde(default)]
+    pub step_forward: u16,
+}
+
+impl LivePlaybackKeybinds {
+    fn action_for(&self, key: u16) -> Option<LivePlaybackAction> {
+        if key == 0 { return None; }
+        [
+            (self.set_loop_a, LivePlaybackAction::SetLoopA),
+            (self.set_loop_b, LivePlaybackAction::SetLoopB),
+            (self.clear_loop, LivePlaybackAction::ClearLoop),
+            (self.speed_down, LivePlaybackAction::SpeedDown),
+            (self.speed_up, LivePlaybackAction::SpeedUp),
+            (self.step_back, LivePlaybackAction::StepBack),
+            (self.step_forward, LivePlaybackAction::StepForward),
+        ]
+        .into_iter()
+        .find_map(|(bound, action)| (bound == key).then_some(action))
+    }
+}
+
+/// Queues the playback action bound to `key` while a live is on screen. Returns whether the key
+/// was bound. Queued actions are applied by the live slider on its next frame.
+pub fn on_hotkey(key: u16) -> bool {
+    if Director::instance().is_null() {
+        return false;
+    }
+
+    let Some(action) = Hachimi::instance().config.load().live_playback_keybinds.action_for(key) else {
+        return false;
+    };
+    PENDING_ACTIONS.lock().unwrap().push(action);
+    true
+}
+
+pub fn take_pending_actions() -> Vec<LivePlaybackAction> {
+    std::mem::take(&mut *PENDING_ACTIONS.lock().unwrap())
+}
+
+/// Applies a playback action at the given live time. Returns the new live time if it moved.
+pub fn apply_action(action: LivePlaybackAction, current: f32, total: f32) -> Option<f32> {
+    match action {
+        LivePlaybackAction::SetLoopA => set_loop_a(current),
+        LivePlaybackAction::SetLoopB => set_loop_b(current),
+        LivePlaybackAction::ClearLoop => clear_loop(),
+        LivePlaybackAction::SpeedDown => step_live_speed(-1),
+        LivePlaybackAction::SpeedUp => step_live_speed(1),
+        LivePlaybackAction::StepBack => return step_live_frame(current, total, -1),
+        LivePlaybackAction::StepForward => return step_live_frame(current, total, 1),
+    }
+    None
+}
+
+pub fn live_loop() -> LiveLoop {
+    *LIVE_LOOP.lock().unwrap()
+}
+
+pub fn set_loop_a(time: f32) {
+    let mut live_loop = LIVE_LOOP.lock().unwrap();
+    live_loop.a = Some(time);
+    if live_loop.b.is_some_and(|b| b <= time) {
+        live_loop.b = None;
+    }
+}
+
+pub fn set_loop_b(time: f32) {
+    let mut live_loop = LIVE_LOOP.lock().unwrap();
+    live_loop.b = Some(time);
+    if live_loop.a.is_some_and(|a| a >= time) {
+        live_loop.a = None;
+    }
+}
+
+pub fn clear_loop() {
+    *LIVE_LOOP.lock().unwrap() = LiveLoop::default();
+}
+
+/// Returns the time to jump back to if playback has run past the end of the A-B section.
+/// Never loops while paused, so points can be placed and stepped past freely.
+pub fn check_live_loop(current: f32) -> Option<f32> {
+    if Director::is_live_paused() {
+        return None;
+    }
+    let (a, b) = LIVE_LOOP.lock().unwrap().range()?;
+    (current > b).then_some(a)
+}
+
+pub fn live_speed() -> f32 {
+    f32::from_bits(LIVE_SPEED.load(Ordering::Acquire))
+}
+
+/// Sets the playback speed multiplier for the timeline and the song audio. Applied on the main
+/// thread.
+pub fn set_live_speed(speed: f32) {
+    let speed = speed.clamp(LIVE_SPEEDS[0], LIVE_SPEEDS[LIVE_SPEEDS.len() - 1]);
+    LIVE_SPEED.store(speed.to_bits(), Ordering::Release);
+    Thread::main_thread().schedule(apply_live_speed);
+}
+
+fn step_live_speed(direction: i32) {
+    let speed = live_speed();
+    let index = LIVE_SPEEDS.iter()
+        .position(|s| (s - speed).abs() < f32::EPSILON)
+        .unwrap_or(LIVE_SPEEDS.len() / 2) as i32;
+    let index = (index + direction).clamp(0, LIVE_SPEEDS.len() as i32 - 1);
+    set_live_speed(LIVE_SPEEDS[index as usize]);
+}
+
+fn apply_live_speed() {
+    let speed = live_speed();
+
+    // Leave the time scale alone while paused so the pause isn't undone, sync_live_speed picks it up after resuming
+    if Director::instance().is_null() || !Director::is_live_paused() {
+        Time::set_timeScale(speed);
+    }
+
+    // CRI pitch is in cents, shifting it changes the playback rate along with the pitch
+    let cents = 1200.0 * speed.log2();
+    for_each_song_playback(|playback, audio_ctrl_dict| {
+        let Some(atom_source) = (unsafe { find_atom_source(playback, audio_ctrl_dict) }) else { return; };
+        let player = AtomSourceEx::get_player(atom_source);
+        if player.is_null() { return; }
+
+        CriAtomExPlayer::SetPitch(player, cents);
+        CriAtomExPlayer::Update(player, playback.criAtomExPlayback);
+    });
+}
+
+/// Reapplies the speed multiplier if something else (e.g. resuming the live) reset the time scale.
+pub fn sync_live_speed() {
+    let speed = live_speed();
+    if (Time::get_timeScale() - speed).abs() > f32::EPSILON {
+        Thread::main_thread().schedule(apply_live_speed);
+    }
+}
+
+/// Moves a paused live by `frames` timeline frames. Returns the new live time.
+pub fn step_live_frame(current: f32, total: f32, frames: i32) -> Option<f32> {
+    if Director::instance().is_null() || !Director::is_live_paused() {
+        return None;
+    }
+
+    let target = (current + frames as f32 * LIVE_FRAME_STEP).clamp(0.0, total);
+    move_live_playback(target);
+    Some(target)
+}
+
+/// Clears the A-B loop and restores normal speed when leaving a live.
+pub fn reset_live_playback_state() {
+    clear_loop();
+    PENDING_ACTIONS.lock().unwrap().clear();
+    if live_speed() != 1.0 {
+        LIVE_SPEED.store(1.0f32.to_bits(), Ordering::Release);
+        Thread::main_thread().schedule(|| Time::set_timeScale(1.0));
     }
 }
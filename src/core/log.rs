@@ -1,12 +1,211 @@
+use std::{collections::VecDeque, fs::File, path::PathBuf, sync::{atomic::{AtomicU64, Ordering}, Mutex, RwLock}};
+
+use chrono::{DateTime, Local};
+use fnv::FnvHashMap;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use once_cell::sync::OnceCell;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use simplelog::{ConfigBuilder, WriteLogger};
+
 use crate::log_impl;
 
-pub fn init(debug_mode: bool, file_logging: bool) {
-    let filter_level = if debug_mode {
-        log::LevelFilter::Debug
+/// Number of records kept in memory for the log viewer and bug reports.
+pub const RECENT_CAPACITY: usize = 5000;
+const CRATE_PREFIX: &str = "hachimi::";
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, JsonSchema)]
+pub enum LogLevel {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace
+}
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 6] = [
+        LogLevel::Off, LogLevel::Error, LogLevel::Warn, LogLevel::Info, LogLevel::Debug, LogLevel::Trace
+    ];
+
+    pub fn to_level_filter(self) -> LevelFilter {
+        match self {
+            LogLevel::Off => LevelFilter::Off,
+            LogLevel::Error => LevelFilter::Error,
+            LogLevel::Warn => LevelFilter::Warn,
+            LogLevel::Info => LevelFilter::Info,
+            LogLevel::Debug => LevelFilter::Debug,
+            LogLevel::Trace => LevelFilter::Trace
+        }
     }
-    else {
-        log::LevelFilter::Info
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LogLevel::Off => "Off",
+            LogLevel::Error => "Error",
+            LogLevel::Warn => "Warn",
+            LogLevel::Info => "Info",
+            LogLevel::Debug => "Debug",
+            LogLevel::Trace => "Trace"
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct LogRecord {
+    pub time: DateTime<Local>,
+    pub level: Level,
+    pub target: String,
+    pub message: String
+}
+
+impl std::fmt::Display for LogRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} [{}] {}: {}", self.time.to_rfc3339(), self.level, self.target, self.message)
+    }
+}
+
+struct Filter {
+    default: LevelFilter,
+    /// Module prefixes, longest first so the most specific one wins.
+    modules: Vec<(String, LevelFilter)>
+}
+
+impl Filter {
+    fn new(debug_mode: bool, module_levels: &FnvHashMap<String, LogLevel>) -> Filter {
+        let default = if debug_mode { LevelFilter::Debug } else { LevelFilter::Info };
+
+        let mut modules: Vec<(String, LevelFilter)> = module_levels.iter()
+            .map(|(module, level)| (module.strip_prefix(CRATE_PREFIX).unwrap_or(module).to_owned(), level.to_level_filter()))
+            .collect();
+        if !module_levels.contains_key("sqlparser") {
+            // annoying
+            modules.push(("sqlparser".to_owned(), LevelFilter::Off));
+        }
+        modules.sort_by(|a, b| b.0.len().cmp(&a.0.len()));
+
+        Filter { default, modules }
+    }
+
+    fn level_for(&self, target: &str) -> LevelFilter {
+        let target = target.strip_prefix(CRATE_PREFIX).unwrap_or(target);
+        self.modules.iter()
+            .find(|(module, _)| {
+                target.strip_prefix(module.as_str()).is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .map(|(_, level)| *level)
+            .unwrap_or(self.default)
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.modules.iter().map(|(_, level)| *level).fold(self.default, LevelFilter::max)
+    }
+}
+
+struct Logger {
+    filter: RwLock<Filter>,
+    recent: Mutex<VecDeque<LogRecord>>,
+    /// Bumped whenever `recent` changes.
+    generation: AtomicU64,
+    sink: Box<dyn Log>
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.filter.read().unwrap().level_for(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        self.sink.log(record);
+
+        let entry = LogRecord {
+            time: Local::now(),
+            level: record.level(),
+            target: record.target().to_owned(),
+            message: record.args().to_string()
+        };
+        let mut recent = self.recent.lock().unwrap();
+        if recent.len() >= RECENT_CAPACITY {
+            recent.pop_front();
+        }
+        recent.push_back(entry);
+        self.generation.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn flush(&self) {
+        self.sink.flush();
+    }
+}
+
+static LOGGER: OnceCell<&'static Logger> = OnceCell::new();
+
+pub fn log_file_path() -> PathBuf {
+    log_impl::get_log_dir().join("hachimi.log")
+}
+
+fn file_logger() -> Option<Box<dyn Log>> {
+    let file = File::create(log_file_path()).ok()?;
+    let config = ConfigBuilder::new()
+        .set_target_level(LevelFilter::Error)
+        .set_time_format_rfc3339()
+        .build();
+
+    // Filtering is done by the outer logger
+    Some(WriteLogger::new(LevelFilter::Trace, config, file))
+}
+
+pub fn init(debug_mode: bool, file_logging: bool, module_levels: &FnvHashMap<String, LogLevel>) {
+    if LOGGER.get().is_some() {
+        set_filter(debug_mode, module_levels);
+        return;
+    }
+
+    let sink = file_logging.then(file_logger).flatten().unwrap_or_else(log_impl::platform_logger);
+    let filter = Filter::new(debug_mode, module_levels);
+    let max_level = filter.max_level();
+
+    let logger: &'static Logger = Box::leak(Box::new(Logger {
+        filter: RwLock::new(filter),
+        recent: Mutex::new(VecDeque::with_capacity(RECENT_CAPACITY)),
+        generation: AtomicU64::new(0),
+        sink
+    }));
+    if log::set_logger(logger).is_ok() {
+        log::set_max_level(max_level);
+        _ = LOGGER.set(logger);
+    }
+}
+
+/// Applies new log levels. The log destination can't change after init.
+pub fn set_filter(debug_mode: bool, module_levels: &FnvHashMap<String, LogLevel>) {
+    let Some(logger) = LOGGER.get() else {
+        return;
     };
+    let filter = Filter::new(debug_mode, module_levels);
+    log::set_max_level(filter.max_level());
+    *logger.filter.write().unwrap() = filter;
+}
+
+/// Returns a copy of the records kept in memory, oldest first.
+pub fn recent_records() -> Vec<LogRecord> {
+    LOGGER.get()
+        .map(|logger| logger.recent.lock().unwrap().iter().cloned().collect())
+        .unwrap_or_default()
+}
 
-    log_impl::init(filter_level, file_logging);
-}
\ No newline at end of file
+/// Changes whenever the records kept in memory do, to tell when a copy is stale.
+pub fn recent_generation() -> u64 {
+    LOGGER.get().map(|logger| logger.generation.load(Ordering::Relaxed)).unwrap_or_default()
+}
+
+pub fn clear_recent_records() {
+    if let Some(logger) = LOGGER.get() {
+        logger.recent.lock().unwrap().clear();
+        logger.generation.fetch_add(1, Ordering::Relaxed);
+    }
+}
@@ -5,6 +5,9 @@ mod error;
 pub use error::Error;
 
 pub mod game;
+pub mod config_migration;
+pub mod config_profiles;
+pub mod config_schema;
 pub mod ext;
 pub mod template;
 
@@ -21,6 +24,9 @@ pub mod utils;
 pub mod http;
 pub mod tl_repo;
 pub mod log;
+pub mod bug_report;
+pub mod crash_report;
+pub mod hook_registry;
 mod ipc;
 
 pub mod sugoi_client;
@@ -33,3 +39,8 @@ pub mod updater;
 pub mod taskbar;
 pub mod captions;
 pub mod live_utils;
+pub mod live_subtitles;
+pub mod live_profiles;
+pub mod screenshot;
+pub mod camera_path;
+pub mod free_camera;
@@ -1,11 +1,12 @@
-use std::{ffi::{c_char, c_void, CStr, CString}, sync::atomic::AtomicI32};
+use std::{ffi::{c_char, c_void, CStr, CString}, sync::{atomic::{AtomicI32, Ordering}, Mutex}};
 
-use once_cell::sync::OnceCell;
+use fnv::FnvHashMap;
+use once_cell::sync::{Lazy, OnceCell};
 use egui::Align;
 
-use crate::{core::{utils::get_data_path, Hachimi, Interceptor, gui}, il2cpp::{self, types::{FieldInfo, Il2CppArray, Il2CppClass, Il2CppImage, Il2CppMethodPointer, Il2CppObject, Il2CppString, Il2CppThread, Il2CppTypeEnum, MethodInfo, il2cpp_array_size_t}}};
+use rust_i18n::t;
 
-const VERSION: i32 = 3;
+use crate::{core::{utils::get_data_path, Error, Hachimi, Interceptor, gui, ipc}, hachimi_impl, il2cpp::{self, hook::umamusume::GameSystem, symbols::Thread, types::{FieldInfo, Il2CppArray, Il2CppClass, Il2CppImage, Il2CppMethodPointer, Il2CppObject, Il2CppString, Il2CppThread, Il2CppTypeEnum, MethodInfo, il2cpp_array_size_t}}};
 
 static PLUGIN_VTABLE: OnceCell<Vtable> = OnceCell::new();
 static DATA_DIR_CSTR: once_cell::sync::OnceCell<CString> = once_cell::sync::OnceCell::new();
@@ -14,12 +15,16 @@ static DATA_PATH_CSTR: once_cell::sync::OnceCell<CString> = once_cell::sync::Onc
 pub type HachimiGetApiFn = extern "C" fn(name: *const c_char) -> *mut c_void;
 pub type HachimiInitFn = extern "C" fn(vtable: *const Vtable, version: i32) -> InitResult;
 pub type HachimiInitV3Fn = extern "C" fn(get_api: HachimiGetApiFn, version: i32) -> InitResult;
+pub type HachimiShutdownFn = extern "C" fn();
 pub type GuiMenuCallback = extern "C" fn(userdata: *mut c_void);
 pub type GuiMenuSectionCallback = extern "C" fn(ui: *mut c_void, userdata: *mut c_void);
 pub type GuiUiCallback = extern "C" fn(ui: *mut c_void, userdata: *mut c_void);
 pub type GameInitializedCallback = unsafe extern "C" fn(userdata: *mut c_void);
 pub type PresentCallback = unsafe extern "C" fn(swapchain: *mut c_void, userdata: *mut c_void);
 pub type GuiWindowCallback = extern "C" fn(ui: *mut c_void, userdata: *mut c_void);
+pub type ConfigChangedCallback = unsafe extern "C" fn(userdata: *mut c_void);
+pub type IpcCommandCallback = ipc::PluginCommandCallback;
+pub type GuiTableCellCallback = extern "C" fn(ui: *mut c_void, row: usize, column: usize, userdata: *mut c_void);
 
 static NEXT_PLUGIN_WINDOW_ID: AtomicI32 = AtomicI32::new(0);
 
@@ -727,6 +732,440 @@ unsafe extern "C" fn gui_close_window(id: i32) {
     gui::close_plugin_window(id);
 }
 
+unsafe extern "C" fn gui_ui_slider_f32(
+    ui: *mut c_void,
+    text: *const c_char,
+    value: *mut f32,
+    min: f32,
+    max: f32
+) -> bool {
+    let Some(ui) = ui_from_ptr(ui) else { return false; };
+    if value.is_null() { return false; }
+    ui.add(egui::Slider::new(&mut *value, min..=max).text(cstr_or_empty(text))).changed()
+}
+
+unsafe extern "C" fn gui_ui_slider_i32(
+    ui: *mut c_void,
+    text: *const c_char,
+    value: *mut i32,
+    min: i32,
+    max: i32
+) -> bool {
+    let Some(ui) = ui_from_ptr(ui) else { return false; };
+    if value.is_null() { return false; }
+    ui.add(egui::Slider::new(&mut *value, min..=max).text(cstr_or_empty(text))).changed()
+}
+
+unsafe extern "C" fn gui_ui_drag_value_f32(
+    ui: *mut c_void,
+    value: *mut f32,
+    speed: f32,
+    min: f32,
+    max: f32
+) -> bool {
+    let Some(ui) = ui_from_ptr(ui) else { return false; };
+    if value.is_null() { return false; }
+    ui.add(egui::DragValue::new(&mut *value).speed(speed).range(min..=max)).changed()
+}
+
+unsafe extern "C" fn gui_ui_drag_value_i32(
+    ui: *mut c_void,
+    value: *mut i32,
+    speed: f32,
+    min: i32,
+    max: i32
+) -> bool {
+    let Some(ui) = ui_from_ptr(ui) else { return false; };
+    if value.is_null() { return false; }
+    ui.add(egui::DragValue::new(&mut *value).speed(speed).range(min..=max)).changed()
+}
+
+unsafe extern "C" fn gui_ui_text_edit_multiline(
+    ui: *mut c_void,
+    buffer: *mut c_char,
+    buffer_len: usize,
+    desired_rows: usize
+) -> bool {
+    let Some(ui) = ui_from_ptr(ui) else { return false; };
+    if buffer.is_null() || buffer_len == 0 { return false; }
+
+    let bytes = std::slice::from_raw_parts_mut(buffer as *mut u8, buffer_len);
+    let end = bytes.iter().position(|b| *b == 0).unwrap_or(buffer_len);
+
+    let id = ui.make_persistent_id(buffer as usize);
+    let mut value = ui.memory(|mem| {
+        mem.data.get_temp::<String>(id)
+    }).unwrap_or_else(|| String::from_utf8_lossy(&bytes[..end]).into_owned());
+    let original_value = value.clone();
+
+    let response = ui.add(
+        egui::TextEdit::multiline(&mut value)
+            .id(id)
+            .desired_rows(desired_rows.max(1))
+            .desired_width(f32::INFINITY)
+    );
+    #[cfg(target_os = "android")]
+    gui::handle_android_keyboard(&response, &mut value);
+
+    if response.gained_focus() {
+        response.scroll_to_me(Some(Align::Center));
+    }
+
+    ui.memory_mut(|mem| mem.data.insert_temp(id, value.clone()));
+
+    let changed = value != original_value;
+    if changed {
+        bytes.fill(0);
+        let src = value.as_bytes();
+        let copy_len = src.len().min(buffer_len.saturating_sub(1));
+        bytes[..copy_len].copy_from_slice(&src[..copy_len]);
+    }
+
+    changed
+}
+
+unsafe extern "C" fn gui_ui_collapsing(
+    ui: *mut c_void,
+    title: *const c_char,
+    default_open: bool,
+    callback: Option<GuiUiCallback>,
+    userdata: *mut c_void
+) -> bool {
+    let Some(ui) = ui_from_ptr(ui) else { return false; };
+    let Some(callback) = callback else { return false; };
+    egui::CollapsingHeader::new(cstr_or_empty(title))
+        .default_open(default_open)
+        .show(ui, |ui| {
+            callback(ui as *mut _ as *mut c_void, userdata);
+        })
+        .body_returned
+        .is_some()
+}
+
+unsafe extern "C" fn gui_ui_scroll_area(
+    ui: *mut c_void,
+    id: *const c_char,
+    max_height: f32,
+    horizontal: bool,
+    callback: Option<GuiUiCallback>,
+    userdata: *mut c_void
+) -> bool {
+    let Some(ui) = ui_from_ptr(ui) else { return false; };
+    let Some(callback) = callback else { return false; };
+    let scroll_area = if horizontal {
+        egui::ScrollArea::both()
+    }
+    else {
+        egui::ScrollArea::vertical()
+    };
+    scroll_area
+        .id_salt(cstr_or_empty(id))
+        .max_height(if max_height > 0.0 { max_height } else { f32::INFINITY })
+        .show(ui, |ui| {
+            callback(ui as *mut _ as *mut c_void, userdata);
+        });
+    true
+}
+
+unsafe extern "C" fn gui_ui_progress_bar(ui: *mut c_void, progress: f32, text: *const c_char) -> bool {
+    let Some(ui) = ui_from_ptr(ui) else { return false; };
+    let mut bar = egui::ProgressBar::new(progress.clamp(0.0, 1.0));
+    let text = cstr_or_empty(text);
+    bar = if text.is_empty() {
+        bar.show_percentage()
+    }
+    else {
+        bar.text(text)
+    };
+    ui.add(bar);
+    true
+}
+
+struct PluginImage {
+    pending: Option<egui::ColorImage>,
+    texture: Option<egui::TextureHandle>
+}
+
+static PLUGIN_IMAGES: Lazy<Mutex<FnvHashMap<String, PluginImage>>> = Lazy::new(|| Mutex::default());
+
+unsafe extern "C" fn gui_set_image_rgba(
+    id: *const c_char,
+    rgba: *const u8,
+    width: usize,
+    height: usize
+) -> bool {
+    if id.is_null() || rgba.is_null() || width == 0 || height == 0 {
+        return false;
+    }
+    let Ok(id) = CStr::from_ptr(id).to_str() else {
+        return false;
+    };
+    let Some(len) = width.checked_mul(height).and_then(|v| v.checked_mul(4)) else {
+        return false;
+    };
+    let bytes = std::slice::from_raw_parts(rgba, len);
+    let image = egui::ColorImage::from_rgba_unmultiplied([width, height], bytes);
+
+    let mut images = PLUGIN_IMAGES.lock().unwrap();
+    images.entry(id.to_owned())
+        .and_modify(|e| e.pending = Some(image.clone()))
+        .or_insert(PluginImage { pending: Some(image), texture: None });
+    true
+}
+
+unsafe extern "C" fn gui_remove_image(id: *const c_char) -> bool {
+    if id.is_null() { return false; }
+    let Ok(id) = CStr::from_ptr(id).to_str() else {
+        return false;
+    };
+    PLUGIN_IMAGES.lock().unwrap().remove(id).is_some()
+}
+
+unsafe extern "C" fn gui_ui_image(ui: *mut c_void, id: *const c_char, width: f32, height: f32) -> bool {
+    let Some(ui) = ui_from_ptr(ui) else { return false; };
+    let id = cstr_or_empty(id);
+
+    let mut images = PLUGIN_IMAGES.lock().unwrap();
+    let Some(image) = images.get_mut(id) else {
+        return false;
+    };
+    if let Some(pending) = image.pending.take() {
+        match &mut image.texture {
+            Some(texture) => texture.set(pending, egui::TextureOptions::LINEAR),
+            None => image.texture = Some(ui.ctx().load_texture(
+                format!("plugin-image/{}", id), pending, egui::TextureOptions::LINEAR
+            ))
+        }
+    }
+    let Some(texture) = &image.texture else {
+        return false;
+    };
+
+    let size = if width > 0.0 && height > 0.0 {
+        egui::vec2(width, height)
+    }
+    else {
+        texture.size_vec2()
+    };
+    ui.add(egui::Image::new((texture.id(), size)));
+    true
+}
+
+unsafe extern "C" fn gui_ui_table(
+    ui: *mut c_void,
+    id: *const c_char,
+    headers: *const *const c_char,
+    column_count: usize,
+    row_count: usize,
+    row_height: f32,
+    callback: Option<GuiTableCellCallback>,
+    userdata: *mut c_void
+) -> bool {
+    let Some(ui) = ui_from_ptr(ui) else { return false; };
+    let Some(callback) = callback else { return false; };
+    if column_count == 0 { return false; }
+
+    let row_height = if row_height > 0.0 { row_height } else { ui.spacing().interact_size.y };
+    ui.push_id(cstr_or_empty(id), |ui| {
+        let table = egui_extras::TableBuilder::new(ui)
+            .striped(true)
+            .columns(egui_extras::Column::auto().resizable(true), column_count - 1)
+            .column(egui_extras::Column::remainder());
+
+        let body = |body: egui_extras::TableBody| {
+            body.rows(row_height, row_count, |mut row| {
+                let row_index = row.index();
+                for column in 0..column_count {
+                    row.col(|ui| {
+                        callback(ui as *mut _ as *mut c_void, row_index, column, userdata);
+                    });
+                }
+            });
+        };
+
+        if headers.is_null() {
+            table.body(body);
+        }
+        else {
+            table
+                .header(row_height, |mut header| {
+                    for column in 0..column_count {
+                        header.col(|ui| {
+                            ui.strong(cstr_or_empty(*headers.add(column)));
+                        });
+                    }
+                })
+                .body(body);
+        }
+    });
+    true
+}
+
+unsafe extern "C" fn gui_ui_tooltip(
+    ui: *mut c_void,
+    text: *const c_char,
+    callback: Option<GuiUiCallback>,
+    userdata: *mut c_void
+) -> bool {
+    let Some(ui) = ui_from_ptr(ui) else { return false; };
+    let Some(callback) = callback else { return false; };
+    ui.scope(|ui| {
+        callback(ui as *mut _ as *mut c_void, userdata);
+    })
+    .response
+    .on_hover_text(cstr_or_empty(text))
+    .hovered()
+}
+
+// Copies `value` into a caller-provided buffer, NUL terminated and truncated to fit.
+// `out_len` receives the full length (without the terminator) so callers can retry with a bigger buffer.
+unsafe fn write_str_to_buffer(value: &str, buffer: *mut c_char, buffer_len: usize, out_len: *mut usize) {
+    if !out_len.is_null() {
+        *out_len = value.len();
+    }
+    if buffer.is_null() || buffer_len == 0 {
+        return;
+    }
+    let bytes = std::slice::from_raw_parts_mut(buffer as *mut u8, buffer_len);
+    let copy_len = value.len().min(buffer_len - 1);
+    bytes[..copy_len].copy_from_slice(&value.as_bytes()[..copy_len]);
+    bytes[copy_len] = 0;
+}
+
+unsafe extern "C" fn localized_data_get_localize_text(
+    key: *const c_char,
+    buffer: *mut c_char,
+    buffer_len: usize,
+    out_len: *mut usize
+) -> bool {
+    if key.is_null() { return false; }
+    let Ok(key) = CStr::from_ptr(key).to_str() else {
+        return false;
+    };
+    let localized_data = Hachimi::instance().localized_data.load();
+    let Some(text) = localized_data.localize_dict.get(key) else {
+        return false;
+    };
+    write_str_to_buffer(text, buffer, buffer_len, out_len);
+    true
+}
+
+unsafe extern "C" fn localized_data_get_text_data(
+    category: i32,
+    index: i32,
+    buffer: *mut c_char,
+    buffer_len: usize,
+    out_len: *mut usize
+) -> bool {
+    let localized_data = Hachimi::instance().localized_data.load();
+    let Some(text) = localized_data.text_data_dict.get(&category).and_then(|c| c.get(&index)) else {
+        return false;
+    };
+    write_str_to_buffer(text, buffer, buffer_len, out_len);
+    true
+}
+
+unsafe extern "C" fn localized_data_get_character_system_text(
+    character_id: i32,
+    voice_id: i32,
+    buffer: *mut c_char,
+    buffer_len: usize,
+    out_len: *mut usize
+) -> bool {
+    let localized_data = Hachimi::instance().localized_data.load();
+    let Some(text) = localized_data.character_system_text_dict.get(&character_id).and_then(|c| c.get(&voice_id)) else {
+        return false;
+    };
+    write_str_to_buffer(text, buffer, buffer_len, out_len);
+    true
+}
+
+unsafe extern "C" fn hachimi_get_active_tl_dir(buffer: *mut c_char, buffer_len: usize, out_len: *mut usize) -> bool {
+    let Some(path) = Hachimi::instance().get_active_tl_dir() else {
+        return false;
+    };
+    write_str_to_buffer(&path.to_string_lossy(), buffer, buffer_len, out_len);
+    true
+}
+
+unsafe extern "C" fn hachimi_get_language(buffer: *mut c_char, buffer_len: usize, out_len: *mut usize) -> bool {
+    let language = Hachimi::instance().config.load().language;
+    write_str_to_buffer(language.locale_str(), buffer, buffer_len, out_len);
+    true
+}
+
+unsafe extern "C" fn hachimi_get_config_json(buffer: *mut c_char, buffer_len: usize, out_len: *mut usize) -> bool {
+    let config = Hachimi::instance().config.load();
+    let Ok(json) = serde_json::to_string(config.as_ref()) else {
+        return false;
+    };
+    write_str_to_buffer(&json, buffer, buffer_len, out_len);
+    true
+}
+
+unsafe extern "C" fn template_eval(
+    input: *const c_char,
+    buffer: *mut c_char,
+    buffer_len: usize,
+    out_len: *mut usize
+) -> bool {
+    if input.is_null() { return false; }
+    let Ok(input) = CStr::from_ptr(input).to_str() else {
+        return false;
+    };
+    let output = Hachimi::instance().template_parser.eval(input);
+    write_str_to_buffer(&output, buffer, buffer_len, out_len);
+    true
+}
+
+unsafe extern "C" fn hachimi_register_on_config_changed(
+    callback: Option<ConfigChangedCallback>,
+    userdata: *mut c_void
+) -> bool {
+    let Some(callback) = callback else {
+        return false;
+    };
+    let hachimi = Hachimi::instance();
+    let mut callbacks = hachimi.config_changed_callbacks.lock().unwrap();
+    callbacks.push((callback as usize, userdata as usize));
+    true
+}
+
+unsafe extern "C" fn ipc_register_command(
+    name: *const c_char,
+    callback: Option<IpcCommandCallback>,
+    main_thread: bool,
+    userdata: *mut c_void
+) -> bool {
+    let Some(callback) = callback else {
+        return false;
+    };
+    if name.is_null() { return false; }
+    let Ok(name) = CStr::from_ptr(name).to_str() else {
+        return false;
+    };
+    ipc::register_plugin_command(name.to_owned(), callback, userdata as usize, main_thread)
+}
+
+unsafe extern "C" fn ipc_unregister_command(name: *const c_char) -> bool {
+    if name.is_null() { return false; }
+    let Ok(name) = CStr::from_ptr(name).to_str() else {
+        return false;
+    };
+    ipc::unregister_plugin_command(name)
+}
+
+unsafe extern "C" fn ipc_response_write(response: *mut c_void, json: *const c_char) -> bool {
+    if response.is_null() || json.is_null() { return false; }
+    let Ok(json) = CStr::from_ptr(json).to_str() else {
+        return false;
+    };
+    let response = &mut *(response as *mut String);
+    response.clear();
+    response.push_str(json);
+    true
+}
+
 #[cfg(target_os = "android")]
 unsafe extern "C" fn android_dex_load(dex_ptr: *const u8, dex_len: usize, class_name: *const c_char) -> u64 {
     crate::android::dex_bridge::dex_load(dex_ptr, dex_len, class_name)
@@ -967,6 +1406,62 @@ pub struct Vtable {
     pub gui_set_menu_width: unsafe extern "C" fn(width: f32),
     pub hachimi_get_base_dir: unsafe extern "C" fn() -> *const c_char,
     pub hachimi_get_data_path: unsafe extern "C" fn() -> *const c_char,
+    // Extended widgets (version >= 4)
+    pub gui_ui_slider_f32: unsafe extern "C" fn(
+        ui: *mut c_void, text: *const c_char, value: *mut f32, min: f32, max: f32
+    ) -> bool,
+    pub gui_ui_slider_i32: unsafe extern "C" fn(
+        ui: *mut c_void, text: *const c_char, value: *mut i32, min: i32, max: i32
+    ) -> bool,
+    pub gui_ui_drag_value_f32: unsafe extern "C" fn(
+        ui: *mut c_void, value: *mut f32, speed: f32, min: f32, max: f32
+    ) -> bool,
+    pub gui_ui_drag_value_i32: unsafe extern "C" fn(
+        ui: *mut c_void, value: *mut i32, speed: f32, min: i32, max: i32
+    ) -> bool,
+    pub gui_ui_text_edit_multiline: unsafe extern "C" fn(
+        ui: *mut c_void,
+        buffer: *mut c_char,
+        buffer_len: usize,
+        desired_rows: usize
+    ) -> bool,
+    pub gui_ui_collapsing: unsafe extern "C" fn(
+        ui: *mut c_void,
+        title: *const c_char,
+        default_open: bool,
+        callback: Option<GuiUiCallback>,
+        userdata: *mut c_void
+    ) -> bool,
+    pub gui_ui_scroll_area: unsafe extern "C" fn(
+        ui: *mut c_void,
+        id: *const c_char,
+        max_height: f32,
+        horizontal: bool,
+        callback: Option<GuiUiCallback>,
+        userdata: *mut c_void
+    ) -> bool,
+    pub gui_ui_progress_bar: unsafe extern "C" fn(ui: *mut c_void, progress: f32, text: *const c_char) -> bool,
+    pub gui_set_image_rgba: unsafe extern "C" fn(
+        id: *const c_char, rgba: *const u8, width: usize, height: usize
+    ) -> bool,
+    pub gui_remove_image: unsafe extern "C" fn(id: *const c_char) -> bool,
+    pub gui_ui_image: unsafe extern "C" fn(ui: *mut c_void, id: *const c_char, width: f32, height: f32) -> bool,
+    pub gui_ui_table: unsafe extern "C" fn(
+        ui: *mut c_void,
+        id: *const c_char,
+        headers: *const *const c_char,
+        column_count: usize,
+        row_count: usize,
+        row_height: f32,
+        callback: Option<GuiTableCellCallback>,
+        userdata: *mut c_void
+    ) -> bool,
+    pub gui_ui_tooltip: unsafe extern "C" fn(
+        ui: *mut c_void,
+        text: *const c_char,
+        callback: Option<GuiUiCallback>,
+        userdata: *mut c_void
+    ) -> bool,
 }
 
 impl Vtable {
@@ -1037,6 +1532,19 @@ impl Vtable {
         gui_set_menu_width,
         hachimi_get_base_dir,
         hachimi_get_data_path,
+        gui_ui_slider_f32,
+        gui_ui_slider_i32,
+        gui_ui_drag_value_f32,
+        gui_ui_drag_value_i32,
+        gui_ui_text_edit_multiline,
+        gui_ui_collapsing,
+        gui_ui_scroll_area,
+        gui_ui_progress_bar,
+        gui_set_image_rgba,
+        gui_remove_image,
+        gui_ui_image,
+        gui_ui_table,
+        gui_ui_tooltip,
     };
 
     pub fn instantiate() -> Self {
@@ -1044,85 +1552,42 @@ impl Vtable {
     }
 }
 
-pub extern "C" fn hachimi_get_api(name: *const c_char) -> *mut c_void {
-    if name.is_null() {
-        return std::ptr::null_mut();
-    }
-    let Ok(func_name) = (unsafe { CStr::from_ptr(name).to_str() }) else {
-        return std::ptr::null_mut();
+// Builds `hachimi_get_api` from the shared API table. Each entry is also checked against
+// the signature of the function that implements it, so the table, the generated header
+// and the SDK can't disagree with what Hachimi actually exports.
+macro_rules! hachimi_plugin_api {
+    (
+        version = $version:literal;
+        $(
+            #[since = $since:literal]
+            fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)?;
+        )*
+    ) => {
+        const VERSION: i32 = $version;
+
+        pub extern "C" fn hachimi_get_api(name: *const c_char) -> *mut c_void {
+            if name.is_null() {
+                return std::ptr::null_mut();
+            }
+            let Ok(func_name) = (unsafe { CStr::from_ptr(name).to_str() }) else {
+                return std::ptr::null_mut();
+            };
+
+            match func_name {
+                $(
+                    stringify!($name) => {
+                        let func: unsafe extern "C" fn($($ty),*) $(-> $ret)? = $name;
+                        func as *mut c_void
+                    }
+                )*
+                _ => std::ptr::null_mut(),
+            }
+        }
     };
-
-    match func_name {
-        "hachimi_instance" => hachimi_instance as *mut c_void,
-        "hachimi_get_interceptor" => hachimi_get_interceptor as *mut c_void,
-        "interceptor_hook" => interceptor_hook as *mut c_void,
-        "interceptor_hook_vtable" => interceptor_hook_vtable as *mut c_void,
-        "interceptor_get_trampoline_addr" => interceptor_get_trampoline_addr as *mut c_void,
-        "interceptor_unhook" => interceptor_unhook as *mut c_void,
-        "il2cpp_resolve_symbol" => il2cpp_resolve_symbol as *mut c_void,
-        "il2cpp_get_assembly_image" => il2cpp_get_assembly_image as *mut c_void,
-        "il2cpp_get_class" => il2cpp_get_class as *mut c_void,
-        "il2cpp_get_method" => il2cpp_get_method as *mut c_void,
-        "il2cpp_get_method_overload" => il2cpp_get_method_overload as *mut c_void,
-        "il2cpp_get_method_addr" => il2cpp_get_method_addr as *mut c_void,
-        "il2cpp_get_method_overload_addr" => il2cpp_get_method_overload_addr as *mut c_void,
-        "il2cpp_get_method_cached" => il2cpp_get_method_cached as *mut c_void,
-        "il2cpp_get_method_addr_cached" => il2cpp_get_method_addr_cached as *mut c_void,
-        "il2cpp_find_nested_class" => il2cpp_find_nested_class as *mut c_void,
-        "il2cpp_resolve_icall" => il2cpp_resolve_icall as *mut c_void,
-        "il2cpp_class_get_methods" => il2cpp_class_get_methods as *mut c_void,
-        "il2cpp_get_field_from_name" => il2cpp_get_field_from_name as *mut c_void,
-        "il2cpp_get_field_value" => il2cpp_get_field_value as *mut c_void,
-        "il2cpp_set_field_value" => il2cpp_set_field_value as *mut c_void,
-        "il2cpp_get_static_field_value" => il2cpp_get_static_field_value as *mut c_void,
-        "il2cpp_set_static_field_value" => il2cpp_set_static_field_value as *mut c_void,
-        "il2cpp_object_new" => il2cpp_object_new as *mut c_void,
-        "il2cpp_unbox" => il2cpp_unbox as *mut c_void,
-        "il2cpp_get_main_thread" => il2cpp_get_main_thread as *mut c_void,
-        "il2cpp_get_attached_threads" => il2cpp_get_attached_threads as *mut c_void,
-        "il2cpp_schedule_on_thread" => il2cpp_schedule_on_thread as *mut c_void,
-        "il2cpp_create_array" => il2cpp_create_array as *mut c_void,
-        "il2cpp_get_singleton_like_instance" => il2cpp_get_singleton_like_instance as *mut c_void,
-        "log" => log as *mut c_void,
-        "gui_register_menu_item" => gui_register_menu_item as *mut c_void,
-        "gui_register_menu_section" => gui_register_menu_section as *mut c_void,
-        "gui_show_notification" => gui_show_notification as *mut c_void,
-        "gui_ui_heading" => gui_ui_heading as *mut c_void,
-        "gui_ui_label" => gui_ui_label as *mut c_void,
-        "gui_ui_small" => gui_ui_small as *mut c_void,
-        "gui_ui_separator" => gui_ui_separator as *mut c_void,
-        "gui_ui_button" => gui_ui_button as *mut c_void,
-        "gui_ui_small_button" => gui_ui_small_button as *mut c_void,
-        "gui_ui_checkbox" => gui_ui_checkbox as *mut c_void,
-        "gui_ui_text_edit_singleline" => gui_ui_text_edit_singleline as *mut c_void,
-        "gui_ui_horizontal" => gui_ui_horizontal as *mut c_void,
-        "gui_ui_grid" => gui_ui_grid as *mut c_void,
-        "gui_ui_end_row" => gui_ui_end_row as *mut c_void,
-        "gui_ui_colored_label" => gui_ui_colored_label as *mut c_void,
-        "gui_register_menu_item_icon" => gui_register_menu_item_icon as *mut c_void,
-        "gui_register_menu_section_with_icon" => gui_register_menu_section_with_icon as *mut c_void,
-        "gui_new_window_id" => gui_new_window_id as *mut c_void,
-        "gui_show_window" => gui_show_window as *mut c_void,
-        "gui_close_window" => gui_close_window as *mut c_void,
-        "android_dex_load" => android_dex_load as *mut c_void,
-        "android_dex_unload" => android_dex_unload as *mut c_void,
-        "android_dex_call_static_noargs" => android_dex_call_static_noargs as *mut c_void,
-        "android_dex_call_static_string" => android_dex_call_static_string as *mut c_void,
-        "il2cpp_runtime_object_init" => il2cpp_runtime_object_init as *mut c_void,
-        "il2cpp_string_new" => il2cpp_string_new as *mut c_void,
-        "il2cpp_string_chars" => il2cpp_string_chars as *mut c_void,
-        "il2cpp_string_length" => il2cpp_string_length as *mut c_void,
-        "gui_ui_combo_menu" => gui_ui_combo_menu as *mut c_void,
-        "hachimi_register_on_game_initialized" => hachimi_register_on_game_initialized as *mut c_void,
-        "hachimi_register_present_callback" => hachimi_register_present_callback as *mut c_void,
-        "gui_get_menu_width" => gui_get_menu_width as *mut c_void,
-        "gui_set_menu_width" => gui_set_menu_width as *mut c_void,
-        "hachimi_get_base_dir" => hachimi_get_base_dir as *mut c_void,
-        "hachimi_get_data_path" => hachimi_get_data_path as *mut c_void,
-        _ => std::ptr::null_mut(),
-    }
 }
 
+include!("../../plugin_sdk/src/api_table.rs");
+
 pub enum PluginInit {
     V2(HachimiInitFn),
     V3(HachimiInitV3Fn),
@@ -1130,10 +1595,20 @@ pub enum PluginInit {
 
 pub struct Plugin {
     pub name: String,
-    pub init_fn: PluginInit
+    pub init_fn: PluginInit,
+    /// Optional `hachimi_shutdown` export, called before the plugin is unloaded.
+    pub shutdown_fn: Option<HachimiShutdownFn>,
+    /// Platform library handle (HMODULE or dlopen handle).
+    pub handle: usize,
+    /// Base address of the loaded module, used to tell which registrations belong to it.
+    pub base: usize
 }
 
 impl Plugin {
+    pub fn owns(&self, addr: usize) -> bool {
+        addr != 0 && hachimi_impl::module_base(addr) == Some(self.base)
+    }
+
     pub fn init(&self) -> InitResult {
         match &self.init_fn {
             PluginInit::V2(init) => {
@@ -1145,4 +1620,129 @@ impl Plugin {
             }
         }
     }
+
+    /// Calls the plugin's shutdown export and removes everything it registered with the host:
+    /// hooks, menu items, windows, IPC commands and callbacks. The library itself stays loaded.
+    fn detach(&self) {
+        if let Some(shutdown) = self.shutdown_fn {
+            info!("Shutting down plugin: {}", self.name);
+            shutdown();
+        }
+
+        let owns = |addr: usize| self.owns(addr);
+        let hachimi = Hachimi::instance();
+        let unhooked = hachimi.interceptor.unhook_where(owns);
+        if unhooked != 0 {
+            info!("Removed {} hooks installed by {}", unhooked, self.name);
+        }
+
+        gui::remove_plugin_registrations(&owns);
+        ipc::unregister_plugin_commands_where(&owns);
+        hachimi.plugin_init_callbacks.lock().unwrap().retain(|(callback, _)| !owns(*callback));
+        #[cfg(target_os = "windows")]
+        hachimi.present_callbacks.lock().unwrap().retain(|(callback, _)| !owns(*callback));
+        hachimi.config_changed_callbacks.lock().unwrap().retain(|(callback, _)| !owns(*callback));
+    }
+}
+
+/// Plugins that were unloaded for a reload that then failed. They stay listed so they can be
+/// reloaded again once the library is fixed.
+static UNLOADED_PLUGINS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Loaded plugins, followed by the ones whose reload failed.
+pub fn plugin_names() -> Vec<String> {
+    let mut names: Vec<String> = Hachimi::instance().plugins.lock().unwrap().iter().map(|p| p.name.clone()).collect();
+    names.extend(UNLOADED_PLUGINS.lock().unwrap().iter().cloned());
+    names
+}
+
+/// Whether the plugin's last reload failed and it's currently not loaded.
+pub fn is_plugin_unloaded(name: &str) -> bool {
+    UNLOADED_PLUGINS.lock().unwrap().iter().any(|n| n == name)
+}
+
+fn load_and_init_plugin(name: &str) -> Result<Plugin, Error> {
+    let hachimi = Hachimi::instance();
+    let Some(plugin) = hachimi_impl::load_plugin(name) else {
+        return Err(Error::RuntimeError(format!("Failed to load library: {}", name)));
+    };
+
+    let init_callbacks_before = hachimi.plugin_init_callbacks.lock().unwrap().len();
+    if !plugin.init().is_ok() {
+        plugin.detach();
+        hachimi_impl::unload_plugin(&plugin);
+        return Err(Error::RuntimeError(format!("Plugin init failed: {}", name)));
+    }
+
+    // The game won't initialize again, so run the callbacks the plugin just registered now
+    if GameSystem::GAME_INITIALIZED.load(Ordering::Relaxed) {
+        let callbacks = hachimi.plugin_init_callbacks.lock().unwrap()[init_callbacks_before..].to_vec();
+        for (callback, userdata) in callbacks {
+            let callback: GameInitializedCallback = unsafe { std::mem::transmute(callback) };
+            unsafe { callback(userdata as *mut c_void); }
+        }
+    }
+
+    Ok(plugin)
+}
+
+/// Unloads a plugin and loads it again from the same path. Must be called on the main thread,
+/// since most hooks run there and none of them may be executing while the library is freed.
+pub fn reload_plugin(name: &str) -> Result<(), Error> {
+    let hachimi = Hachimi::instance();
+    // Take the plugin out of the list instead of holding the lock; detaching locks the GUI,
+    // and the GUI reads the plugin list while it's locked.
+    let plugin = {
+        let mut plugins = hachimi.plugins.lock().unwrap();
+        match plugins.iter().position(|p| p.name == name) {
+            Some(index) => Some(plugins.remove(index)),
+            None if is_plugin_unloaded(name) => None,
+            None => return Err(Error::RuntimeError(format!("Plugin not loaded: {}", name)))
+        }
+    };
+
+    match plugin {
+        Some(plugin) => {
+            info!("Reloading plugin: {}", plugin.name);
+            plugin.detach();
+            hachimi_impl::unload_plugin(&plugin);
+        },
+        None => info!("Loading plugin again after a failed reload: {}", name)
+    }
+
+    match load_and_init_plugin(name) {
+        Ok(plugin) => {
+            UNLOADED_PLUGINS.lock().unwrap().retain(|n| n != name);
+            hachimi.plugins.lock().unwrap().push(plugin);
+            Ok(())
+        },
+        Err(e) => {
+            let mut unloaded = UNLOADED_PLUGINS.lock().unwrap();
+            if !unloaded.iter().any(|n| n == name) {
+                unloaded.push(name.to_owned());
+            }
+            Err(e)
+        }
+    }
+}
+
+static PENDING_RELOADS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Queues a plugin reload on the main thread and reports the result with a notification.
+pub fn request_reload(name: String) {
+    PENDING_RELOADS.lock().unwrap().push(name);
+    Thread::main_thread().schedule(|| {
+        let names = std::mem::take(&mut *PENDING_RELOADS.lock().unwrap());
+        for name in names {
+            match reload_plugin(&name) {
+                Ok(()) => gui::enqueue_plugin_notification(t!("notification.plugin_reloaded", name = name).into_owned()),
+                Err(e) => {
+                    error!("Failed to reload plugin {}: {}", name, e);
+                    gui::enqueue_plugin_notification(
+                        t!("notification.plugin_reload_failed", name = name, reason = e.to_string()).into_owned()
+                    );
+                }
+            }
+        }
+    });
 }
@@ -0,0 +1,236 @@
+use std::{fs, io::BufWriter, path::PathBuf, thread};
+
+use chrono::{DateTime, Local};
+use rust_i18n::t;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::il2cpp::{
+    ext::Il2CppStringExt,
+    hook::UnityEngine_CoreModule::{
+        Behaviour, Camera, Object, RenderTexture, Scene, SceneManager, Screen, Texture2D
+    },
+    symbols::Thread,
+    types::*
+};
+
+use super::{free_camera, utils, Error, Gui, Hachimi};
+
+pub const MAX_MULTIPLIER: u32 = 8;
+/// Largest width or height a capture may have, most GPUs can't allocate render textures above this.
+const MAX_DIMENSION: i32 = 16384;
+const DEPTH_BUFFER_BITS: i32 = 24;
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(default)]
+pub struct ScreenshotConfig {
+    /// Render resolution as a multiple of the current screen resolution.
+    pub multiplier: u32,
+    /// Hides the game's UI while rendering. Hachimi's own overlays are drawn outside of Unity
+    /// and never end up in a capture.
+    pub hide_game_ui: bool,
+    /// Platform key code (Windows virtual key or Android keycode) that takes a screenshot, 0 if unbound.
+    pub hotkey: u16,
+}
+
+impl Default for ScreenshotConfig {
+    fn default() -> Self {
+        Self {
+            multiplier: 1,
+            hide_game_ui: true,
+            hotkey: 0,
+        }
+    }
+}
+
+pub struct CaptureMetadata {
+    pub scene: String,
+    /// Set while a live is playing.
+    pub music_id: Option<i32>,
+    pub timestamp: DateTime<Local>,
+}
+
+/// A rendered frame, rows ordered top to bottom as RGBA.
+pub struct Capture {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>,
+    pub metadata: CaptureMetadata,
+}
+
+impl Capture {
+    /// Encodes the capture as a PNG in the screenshots folder and returns its path.
+    pub fn save(&self) -> Result<PathBuf, Error> {
+        let dir = Hachimi::instance().get_data_path("screenshots");
+        fs::create_dir_all(&dir)?;
+
+        let filename = format!("screenshot_{}.png", self.metadata.timestamp.format("%Y%m%d_%H%M%S_%3f"));
+        let path = dir.join(filename);
+        let file = fs::File::create(&path)?;
+
+        let mut encoder = png::Encoder::new(BufWriter::new(file), self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut text = vec![
+            ("Software", "Hachimi".to_owned()),
+            ("Scene", self.metadata.scene.clone()),
+            ("Creation Time", self.metadata.timestamp.to_rfc3339()),
+        ];
+        if let Some(music_id) = self.metadata.music_id {
+            text.push(("MusicId", music_id.to_string()));
+        }
+        for (keyword, value) in text {
+            encoder.add_text_chunk(keyword.to_owned(), value)
+                .map_err(|e| Error::RuntimeError(e.to_string()))?;
+        }
+
+        let mut writer = encoder.write_header().map_err(|e| Error::RuntimeError(e.to_string()))?;
+        writer.write_image_data(&self.pixels).map_err(|e| Error::RuntimeError(e.to_string()))?;
+        writer.finish().map_err(|e| Error::RuntimeError(e.to_string()))?;
+
+        Ok(path)
+    }
+}
+
+fn active_scene_name() -> String {
+    let scene = SceneManager::GetActiveScene();
+    let name_ptr = Scene::GetNameInternal(scene.handle);
+    if name_ptr.is_null() { String::new() } else { unsafe { (*name_ptr).as_utf16str().to_string() } }
+}
+
+/// Cameras to render, in the order the game would draw them.
+fn capture_cameras(main_only: bool) -> Vec<*mut Il2CppObject> {
+    let all_cameras = Camera::get_allCameras();
+    let mut cameras: Vec<_> = if all_cameras.this.is_null() {
+        Vec::new()
+    }
+    else {
+        unsafe { all_cameras.as_slice() }.iter()
+            .copied()
+            .filter(|&c| !c.is_null() && Behaviour::get_enabled(c))
+            .collect()
+    };
+    cameras.sort_by(|&a, &b| Camera::get_depth(a).total_cmp(&Camera::get_depth(b)));
+
+    if main_only {
+        let main = Camera::get_main();
+        if !main.is_null() {
+            return vec![main];
+        }
+        // No camera is tagged as main, the scene's deepest camera is the closest match
+        return cameras.pop().into_iter().collect();
+    }
+    cameras
+}
+
+/// Renders the current frame at `multiplier` times the screen resolution. Must be called from
+/// the main thread.
+pub fn capture(multiplier: u32, hide_game_ui: bool) -> Result<Capture, Error> {
+    let screen_width = Screen::get_width();
+    let screen_height = Screen::get_height();
+    if screen_width <= 0 || screen_height <= 0 {
+        return Err(Error::RuntimeError("Invalid screen size".to_owned()));
+    }
+
+    let max_multiplier = (MAX_DIMENSION / screen_width.max(screen_height)).max(1) as u32;
+    let multiplier = multiplier.clamp(1, MAX_MULTIPLIER).min(max_multiplier) as i32;
+    let width = screen_width * multiplier;
+    let height = screen_height * multiplier;
+
+    let cameras = capture_cameras(hide_game_ui);
+    if cameras.is_empty() {
+        return Err(Error::RuntimeError(t!("screenshot.no_camera").into_owned()));
+    }
+
+    let toggle_ui = hide_game_ui && !Gui::is_game_ui_hidden();
+    if toggle_ui {
+        Gui::toggle_game_ui();
+    }
+
+    let render_texture = RenderTexture::GetTemporary3(width, height, DEPTH_BUFFER_BITS);
+    for &camera in &cameras {
+        let prev_target = Camera::get_targetTexture(camera);
+        Camera::set_targetTexture(camera, render_texture);
+        Camera::Render(camera);
+        Camera::set_targetTexture(camera, prev_target);
+    }
+
+    let prev_active = RenderTexture::GetActive();
+    RenderTexture::SetActive(render_texture);
+
+    let texture = Texture2D::new(width, height);
+    Texture2D::ReadPixels(
+        texture,
+        Rect_t { x: 0.0, y: 0.0, width: width as f32, height: height as f32 },
+        0, 0
+    );
+
+    RenderTexture::SetActive(prev_active);
+    RenderTexture::ReleaseTemporary(render_texture);
+
+    if toggle_ui {
+        Gui::toggle_game_ui();
+    }
+
+    // Unity textures start at the bottom row
+    let pixels_array = Texture2D::GetPixels32(texture, 0);
+    let src = unsafe { pixels_array.as_slice() };
+    let row_len = width as usize;
+    let mut pixels = Vec::with_capacity(src.len() * 4);
+    for row in src.chunks_exact(row_len).rev() {
+        for pixel in row {
+            pixels.extend_from_slice(pixel.as_slice());
+        }
+    }
+    Object::Destroy(texture);
+
+    let scene = active_scene_name();
+    let music_id = (scene == "Live").then(free_camera::live_music_id).filter(|&id| id != 0);
+
+    Ok(Capture {
+        width: width as u32,
+        height: height as u32,
+        pixels,
+        metadata: CaptureMetadata {
+            scene,
+            music_id,
+            timestamp: Local::now()
+        }
+    })
+}
+
+/// Takes a screenshot with the configured settings. The frame is rendered on the main thread
+/// and encoded in the background, a notification reports where it was saved.
+pub fn request_capture() {
+    Thread::main_thread().schedule(|| {
+        let config = Hachimi::instance().config.load();
+        let capture = match capture(config.screenshot.multiplier, config.screenshot.hide_game_ui) {
+            Ok(v) => v,
+            Err(e) => {
+                utils::notify_error(t!("screenshot.failed", error = e.to_string()));
+                return;
+            }
+        };
+
+        thread::spawn(move || match capture.save() {
+            Ok(path) => {
+                info!("Saved screenshot: {}", path.display());
+                if let Some(mutex) = Gui::instance() {
+                    mutex.lock().unwrap().show_notification(&t!("screenshot.saved", path = path.display().to_string()));
+                }
+            },
+            Err(e) => utils::notify_error(t!("screenshot.failed", error = e.to_string()))
+        });
+    });
+}
+
+/// Takes a screenshot if `key` is the configured hotkey. Returns whether it was.
+pub fn on_hotkey(key: u16) -> bool {
+    let hotkey = Hachimi::instance().config.load().screenshot.hotkey;
+    if hotkey == 0 || key != hotkey {
+        return false;
+    }
+    request_capture();
+    true
+}
@@ -4,7 +4,7 @@ use fnv::{FnvHashMap, FnvHashSet};
 use once_cell::sync::Lazy;
 use serde::Serialize;
 
-use super::{Error, Hachimi};
+use super::{http, Error, Hachimi};
 
 pub struct SugoiClient {
     agent: ureq::Agent,
@@ -13,11 +13,12 @@ pub struct SugoiClient {
 }
 
 static INSTANCE: Lazy<Arc<SugoiClient>> = Lazy::new(|| {
+    let url = Hachimi::instance().config.load().sugoi_url.as_ref()
+        .map(|s| s.clone())
+        .unwrap_or_else(|| "http://127.0.0.1:14366".to_owned());
     Arc::new(SugoiClient {
-        agent: ureq::Agent::new_with_defaults(),
-        url: Hachimi::instance().config.load().sugoi_url.as_ref()
-            .map(|s| s.clone())
-            .unwrap_or_else(|| "http://127.0.0.1:14366".to_owned()),
+        agent: ureq::Agent::new_with_config(http::ureq_config()),
+        url,
         request_lock: Mutex::new(()),
     })
 });
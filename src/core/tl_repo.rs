@@ -290,15 +290,30 @@ fn store_progress(
     }
 }
 
-/// RAII guard that ensures a temporary ZIP file is cleaned up, even if the function returns early via `?` or panics
-struct ZipCleanupGuard<'a>(&'a Path);
+/// RAII guard that ensures a temporary ZIP file is cleaned up, even if the function returns early via `?` or panics.
+/// Partial downloads can be kept so the next update resumes them.
+struct ZipCleanupGuard<'a> {
+    path: &'a Path,
+    keep_partial: bool
+}
+
+impl<'a> ZipCleanupGuard<'a> {
+    fn new(path: &'a Path) -> Self {
+        Self { path, keep_partial: false }
+    }
+}
+
 impl Drop for ZipCleanupGuard<'_> {
     fn drop(&mut self) {
-        if self.0.exists() {
-            if let Err(e) = fs::remove_file(self.0) {
-                error!("Failed to clean up temporary ZIP file '{}': {}", self.0.display(), e);
+        if self.keep_partial {
+            return;
+        }
+        if self.path.exists() {
+            if let Err(e) = fs::remove_file(self.path) {
+                error!("Failed to clean up temporary ZIP file '{}': {}", self.path.display(), e);
             }
         }
+        _ = fs::remove_file(http::download_state_path(self.path));
     }
 }
 
@@ -363,6 +378,16 @@ impl Updater {
         self.progress.load().is_some()
     }
 
+    pub fn is_paused(&self) -> bool {
+        http::downloads_paused()
+    }
+
+    /// Pauses or resumes the update's downloads. Extraction isn't affected.
+    pub fn set_paused(&self, paused: bool) {
+        info!("Update {}", if paused { "paused" } else { "resumed" });
+        http::set_downloads_paused(paused);
+    }
+
     pub fn check_for_updates(self: Arc<Self>, pedantic: bool, silent: bool) {
         std::thread::spawn(move || {
             if let Err(e) = self.check_for_updates_internal(pedantic, silent) {
@@ -479,7 +504,7 @@ impl Updater {
             None
         };
 
-        let mut request = ureq::agent().get(index_url);
+        let mut request = ureq::Agent::new_with_config(ureq_config()).get(index_url);
         if let Some(etag) = &etag_for_request {
             request = request.header("If-None-Match", etag);
         }
@@ -664,7 +689,10 @@ impl Updater {
                 index_etag: new_etag.clone(),
             })));
 
-            if silent {
+            let confirm_size = config.tl_update_confirm_size as usize * 1024 * 1024;
+            let needs_confirmation = confirm_size != 0 && actual_download_size > confirm_size;
+
+            if silent && !needs_confirmation {
                 // don't auto-apply while another update is already in progress
                 if self.progress.load().is_some() {
                     info!("Silent update skipped, another update is already in progress.");
@@ -672,6 +700,10 @@ impl Updater {
                     Hachimi::instance().tl_updater.clone().run();
                 }
             } else if let Some(mutex) = Gui::instance() {
+                if silent {
+                    info!("Silent update deferred, {} needs confirmation", Size::from_bytes(actual_download_size));
+                }
+
                 // Determine the dialog message based on download strategy
                 let dialog_message = if will_use_zip && update_size > 0 {
                     let size_ratio = total_size as f64 / update_size.max(1) as f64;
@@ -768,6 +800,8 @@ impl Updater {
                         mutex.lock().unwrap().show_notification(&t!("notification.update_failed", reason = e.to_string()));
                     }
                 }
+                // Don't leave the next update paused
+                http::set_downloads_paused(false);
             })
             .expect("Failed to spawn updater thread");
     }
@@ -914,6 +948,7 @@ impl Updater {
                                 Self::create_dir(parent, false)?;
                             }
                             let mut file = fs::File::create(&file_path)?;
+                            http::wait_while_downloads_paused();
                             let res = job.agent.get(&url).call()?;
 
                             http::download_file_buffered(res, &mut file, &mut job.buffer, |bytes| {
@@ -974,7 +1009,7 @@ impl Updater {
         cached_files: Arc<Mutex<FnvHashMap<String, String>>>
     ) -> Result<usize, Error> {
         let zip_path = localized_data_dir.join(".tmp.zip");
-        let _zip_cleanup = ZipCleanupGuard(&zip_path);
+        let mut zip_cleanup = ZipCleanupGuard::new(&zip_path);
         // idk compiler going monkey mode unless i add this
         #[allow(unused_assignments)]
         let mut error_count = 0;
@@ -1010,14 +1045,18 @@ impl Updater {
                 store_progress(&self_clone.progress, &self_clone.last_progress_ms, current, progress_total);
             });
 
-            http::download_file_parallel(
+            if let Err(e) = http::download_file_parallel(
                 &update_info.zip_url,
                 &zip_path,
                 *NUM_THREADS,
                 MIN_CHUNK_SIZE,
                 CHUNK_SIZE,
                 progress_bar
-            )?;
+            ) {
+                // Progress is saved next to the ZIP, the next update picks up from there
+                zip_cleanup.keep_partial = true;
+                return Err(e);
+            }
 
             let files_to_extract = Arc::new(
                 update_info.files.iter()
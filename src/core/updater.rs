@@ -142,7 +142,8 @@ impl Updater {
         // Download the installer
         let installer_path = utils::get_tmp_installer_path();
 
-        let res = ureq::get(&asset.browser_download_url).call()?;
+        let agent = ureq::Agent::new_with_config(http::ureq_config());
+        let res = agent.get(&asset.browser_download_url).call()?;
         let mut reader = res.into_body().into_reader();
         std::io::copy(&mut reader, &mut File::create(&installer_path)?)?;
 
@@ -8,6 +8,7 @@ use crate::{
 
 type DisposeFn = extern "C" fn(this: *mut Il2CppObject);
 extern "C" fn Dispose(this: *mut Il2CppObject) {
+    hook_scope!(Dispose);
     get_orig_fn!(Dispose, DisposeFn)(this);
     captions::Captions::cleanup();
 }
@@ -30,9 +30,14 @@ impl_addr_wrapper_fn!(Update, UPDATE_ADDR, (), this: *mut Il2CppObject, playback
 static mut PAUSE_ADDR: usize = 0;
 impl_addr_wrapper_fn!(Pause, PAUSE_ADDR, (), this: *mut Il2CppObject, sw: bool);
 
+// public Void SetPitch(Single pitch) { }
+static mut SETPITCH_ADDR: usize = 0;
+impl_addr_wrapper_fn!(SetPitch, SETPITCH_ADDR, (), this: *mut Il2CppObject, pitch: f32);
+
 // public Void Stop()
 type StopHookFn = extern "C" fn(this: *mut Il2CppObject);
 pub extern "C" fn StopHook(this: *mut Il2CppObject) {
+    hook_scope!(StopHook);
     get_orig_fn!(StopHook, StopHookFn)(this);
     captions::Captions::cleanup();
 }
@@ -40,6 +45,7 @@ pub extern "C" fn StopHook(this: *mut Il2CppObject) {
 // public void StopWithoutReleaseTime()
 pub type StopWithoutReleaseTimeHookFn = extern "C" fn(this: *mut Il2CppObject);
 pub extern "C" fn StopWithoutReleaseTimeHook(this: *mut Il2CppObject) {
+    hook_scope!(StopWithoutReleaseTimeHook);
     get_orig_fn!(StopWithoutReleaseTimeHook, StopWithoutReleaseTimeHookFn)(this);
     captions::Captions::cleanup();
 }
@@ -47,6 +53,7 @@ pub extern "C" fn StopWithoutReleaseTimeHook(this: *mut Il2CppObject) {
 // public Void Pause() { }
 type PauseHookFn = extern "C" fn(this: *mut Il2CppObject, sw: bool);
 pub extern "C" fn PauseHook(this: *mut Il2CppObject, sw: bool) {
+    hook_scope!(PauseHook);
     get_orig_fn!(PauseHook, PauseHookFn)(this, sw);
     if !sw {
         captions::Captions::cleanup();
@@ -63,6 +70,7 @@ pub fn init(CriMw_CriWare_Runtime: *const Il2CppImage) {
         PAUSE_ADDR = get_method_addr(CriAtomExPlayer, c"Pause", 1);
         SETSTARTTIME_ADDR = get_method_addr(CriAtomExPlayer, c"SetStartTime", 1);
         UPDATE_ADDR = get_method_addr(CriAtomExPlayer, c"Update", 1);
+        SETPITCH_ADDR = get_method_addr(CriAtomExPlayer, c"SetPitch", 1);
     }
 
     let stop_addr = get_method_addr(CriAtomExPlayer, c"Stop", 0);
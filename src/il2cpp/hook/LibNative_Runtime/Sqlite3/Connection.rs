@@ -100,6 +100,7 @@ fn parse_query(query: *mut Il2CppObject, sql: *const Il2CppString) {
 
 type QueryFn = extern "C" fn(this: *mut Il2CppObject, sql: *const Il2CppString) -> *mut Il2CppObject;
 pub extern "C" fn Query(this: *mut Il2CppObject, sql: *const Il2CppString) -> *mut Il2CppObject {
+    hook_scope!(Query);
     trace!("Query");
     let query = get_orig_fn!(Query, QueryFn)(this, sql);
     parse_query(query, sql);
@@ -108,6 +109,7 @@ pub extern "C" fn Query(this: *mut Il2CppObject, sql: *const Il2CppString) -> *m
 
 type PreparedQueryFn = extern "C" fn(this: *mut Il2CppObject, sql: *const Il2CppString) -> *mut Il2CppObject;
 extern "C" fn PreparedQuery(this: *mut Il2CppObject, sql: *const Il2CppString) -> *mut Il2CppObject {
+    hook_scope!(PreparedQuery);
     trace!("PreparedQuery");
     let query = get_orig_fn!(PreparedQuery, PreparedQueryFn)(this, sql);
     parse_query(query, sql);
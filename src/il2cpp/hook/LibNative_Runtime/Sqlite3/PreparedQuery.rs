@@ -9,6 +9,7 @@ use super::Connection::SELECT_QUERIES;
  */
 type BindIntFn = extern "C" fn(this: *mut Il2CppObject, idx: i32, value: i32) -> bool;
 extern "C" fn BindInt(this: *mut Il2CppObject, idx: i32, value: i32) -> bool {
+    hook_scope!(BindInt);
     if let Some(query) = SELECT_QUERIES.lock().unwrap().get_mut(&(this as usize)) {
         query.bind_int(idx, value);
     }
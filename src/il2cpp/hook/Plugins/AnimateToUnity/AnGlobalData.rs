@@ -2,6 +2,7 @@ use crate::{core::Hachimi, il2cpp::{ext::LocalizedDataExt, symbols::get_method_a
 
 type GetFontFn = extern "C" fn(this: *mut Il2CppObject, font_name: *mut Il2CppString) -> *mut Il2CppObject;
 extern "C" fn _GetFont(this: *mut Il2CppObject, font_name: *mut Il2CppString) -> *mut Il2CppObject {
+    hook_scope!(_GetFont);
     let font = Hachimi::instance().localized_data.load().load_replacement_font();
     if !font.is_null() {
         return font;
@@ -11,6 +12,7 @@ extern "C" fn _GetFont(this: *mut Il2CppObject, font_name: *mut Il2CppString) ->
 
 type GetFontFromCommonFn = extern "C" fn(this: *mut Il2CppObject, font_name: *mut Il2CppString)  -> *mut Il2CppObject;
 extern "C" fn _GetFontFromCommon(this: *mut Il2CppObject, font_name: *mut Il2CppString) -> *mut Il2CppObject {
+    hook_scope!(_GetFontFromCommon);
     let font = Hachimi::instance().localized_data.load().load_replacement_font();
     if !font.is_null() {
         return font;
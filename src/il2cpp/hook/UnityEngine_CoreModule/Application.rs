@@ -4,6 +4,7 @@ use crate::{core::Hachimi, il2cpp::{api::il2cpp_resolve_icall, symbols::get_meth
 
 type SetTargetFrameRateFn = extern "C" fn(value: i32);
 pub extern "C" fn set_targetFrameRate(mut value: i32) {
+    hook_scope!(set_targetFrameRate);
     let target_fps = Hachimi::instance().target_fps.load(atomic::Ordering::Relaxed);
     if target_fps != -1 {
         value = target_fps;
@@ -15,6 +16,7 @@ pub extern "C" fn set_targetFrameRate(mut value: i32) {
 type OpenURLFn = extern "C" fn(il2cpp_url:*mut Il2CppString);
 #[cfg(target_os = "windows")]
 pub extern "C" fn OpenURL(url: *mut Il2CppString){
+    hook_scope!(OpenURL);
     if !crate::windows::webview::open(url){
         get_orig_fn!(OpenURL, OpenURLFn)(url);
     }
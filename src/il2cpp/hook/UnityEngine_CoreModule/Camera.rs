@@ -1,23 +1,39 @@
-use crate::il2cpp::{api::il2cpp_resolve_icall, types::*};
+use crate::{
+    core::free_camera::{self, CameraScene},
+    il2cpp::{api::il2cpp_resolve_icall, symbols::{get_method_addr, Array}, types::*}
+};
 
-#[cfg(target_os = "windows")]
-use crate::windows::free_camera::{self, CameraScene};
-#[cfg(target_os = "windows")]
 type CameraGetFloatFn = extern "C" fn(this: *mut Il2CppObject) -> f32;
-#[cfg(target_os = "windows")]
 type CameraSetFloatFn = extern "C" fn(this: *mut Il2CppObject, value: f32);
 
 static mut SET_FIELD_OF_VIEW_ADDR: usize = 0;
 impl_addr_wrapper_fn!(set_fieldOfView, SET_FIELD_OF_VIEW_ADDR, (), this: *mut Il2CppObject, value: f32);
 
-#[cfg(target_os = "windows")]
+static mut GET_MAIN_ADDR: usize = 0;
+impl_addr_wrapper_fn!(get_main, GET_MAIN_ADDR, *mut Il2CppObject,);
+
+static mut GET_ALLCAMERAS_ADDR: usize = 0;
+impl_addr_wrapper_fn!(get_allCameras, GET_ALLCAMERAS_ADDR, Array<*mut Il2CppObject>,);
+
+static mut GET_DEPTH_ADDR: usize = 0;
+impl_addr_wrapper_fn!(get_depth, GET_DEPTH_ADDR, f32, this: *mut Il2CppObject);
+
+static mut GET_TARGETTEXTURE_ADDR: usize = 0;
+impl_addr_wrapper_fn!(get_targetTexture, GET_TARGETTEXTURE_ADDR, *mut Il2CppObject, this: *mut Il2CppObject);
+
+static mut SET_TARGETTEXTURE_ADDR: usize = 0;
+impl_addr_wrapper_fn!(set_targetTexture, SET_TARGETTEXTURE_ADDR, (), this: *mut Il2CppObject, value: *mut Il2CppObject);
+
+static mut RENDER_ADDR: usize = 0;
+impl_addr_wrapper_fn!(Render, RENDER_ADDR, (), this: *mut Il2CppObject);
+
 fn should_override_near_clip() -> bool {
     free_camera::is_scene_enabled(CameraScene::Live) ||
         free_camera::is_scene_enabled(CameraScene::Race)
 }
 
-#[cfg(target_os = "windows")]
 extern "C" fn Camera_get_fieldOfView(this: *mut Il2CppObject) -> f32 {
+    hook_scope!(Camera_get_fieldOfView);
     let scene = free_camera::scene();
     if let Some(fov) = free_camera::fov_for_scene(scene) {
         return fov;
@@ -25,58 +41,67 @@ extern "C" fn Camera_get_fieldOfView(this: *mut Il2CppObject) -> f32 {
     get_orig_fn!(Camera_get_fieldOfView, CameraGetFloatFn)(this)
 }
 
-#[cfg(target_os = "windows")]
 extern "C" fn Camera_set_nearClipPlane(this: *mut Il2CppObject, mut value: f32) {
+    hook_scope!(Camera_set_nearClipPlane);
     if should_override_near_clip() {
         value = 0.001;
     }
     get_orig_fn!(Camera_set_nearClipPlane, CameraSetFloatFn)(this, value);
 }
 
-#[cfg(target_os = "windows")]
 extern "C" fn Camera_get_nearClipPlane(this: *mut Il2CppObject) -> f32 {
+    hook_scope!(Camera_get_nearClipPlane);
     if should_override_near_clip() {
         return 0.001;
     }
     get_orig_fn!(Camera_get_nearClipPlane, CameraGetFloatFn)(this)
 }
 
-#[cfg(target_os = "windows")]
 extern "C" fn Camera_set_farClipPlane(this: *mut Il2CppObject, mut value: f32) {
+    hook_scope!(Camera_set_farClipPlane);
     if free_camera::is_scene_enabled(CameraScene::Live) || free_camera::is_scene_enabled(CameraScene::Race) {
         value = 2500.0;
     }
     get_orig_fn!(Camera_set_farClipPlane, CameraSetFloatFn)(this, value);
 }
 
-#[cfg(target_os = "windows")]
 extern "C" fn Camera_get_farClipPlane(this: *mut Il2CppObject) -> f32 {
+    hook_scope!(Camera_get_farClipPlane);
     if free_camera::is_scene_enabled(CameraScene::Live) || free_camera::is_scene_enabled(CameraScene::Race) {
         return 2500.0;
     }
     get_orig_fn!(Camera_get_farClipPlane, CameraGetFloatFn)(this)
 }
 
-pub fn init(_UnityEngine_CoreModule: *const Il2CppImage) {
+pub fn init(UnityEngine_CoreModule: *const Il2CppImage) {
     unsafe {
         SET_FIELD_OF_VIEW_ADDR = il2cpp_resolve_icall(c"UnityEngine.Camera::set_fieldOfView(System.Single)".as_ptr());
+        GET_MAIN_ADDR = il2cpp_resolve_icall(c"UnityEngine.Camera::get_main()".as_ptr());
+        GET_DEPTH_ADDR = il2cpp_resolve_icall(c"UnityEngine.Camera::get_depth()".as_ptr());
+        GET_TARGETTEXTURE_ADDR = il2cpp_resolve_icall(c"UnityEngine.Camera::get_targetTexture()".as_ptr());
+        SET_TARGETTEXTURE_ADDR = il2cpp_resolve_icall(
+            c"UnityEngine.Camera::set_targetTexture(UnityEngine.RenderTexture)".as_ptr()
+        );
+        RENDER_ADDR = il2cpp_resolve_icall(c"UnityEngine.Camera::Render()".as_ptr());
     }
 
-    #[cfg(target_os = "windows")]
-    {
-        let get_fieldOfView_addr = il2cpp_resolve_icall(c"UnityEngine.Camera::get_fieldOfView()".as_ptr());
-        new_hook!(get_fieldOfView_addr, Camera_get_fieldOfView);
+    let get_fieldOfView_addr = il2cpp_resolve_icall(c"UnityEngine.Camera::get_fieldOfView()".as_ptr());
+    new_hook!(get_fieldOfView_addr, Camera_get_fieldOfView);
 
-        let set_nearClipPlane_addr = il2cpp_resolve_icall(c"UnityEngine.Camera::set_nearClipPlane(System.Single)".as_ptr());
-        new_hook!(set_nearClipPlane_addr, Camera_set_nearClipPlane);
+    let set_nearClipPlane_addr = il2cpp_resolve_icall(c"UnityEngine.Camera::set_nearClipPlane(System.Single)".as_ptr());
+    new_hook!(set_nearClipPlane_addr, Camera_set_nearClipPlane);
 
-        let get_nearClipPlane_addr = il2cpp_resolve_icall(c"UnityEngine.Camera::get_nearClipPlane()".as_ptr());
-        new_hook!(get_nearClipPlane_addr, Camera_get_nearClipPlane);
+    let get_nearClipPlane_addr = il2cpp_resolve_icall(c"UnityEngine.Camera::get_nearClipPlane()".as_ptr());
+    new_hook!(get_nearClipPlane_addr, Camera_get_nearClipPlane);
 
-        let set_farClipPlane_addr = il2cpp_resolve_icall(c"UnityEngine.Camera::set_farClipPlane(System.Single)".as_ptr());
-        new_hook!(set_farClipPlane_addr, Camera_set_farClipPlane);
+    let set_farClipPlane_addr = il2cpp_resolve_icall(c"UnityEngine.Camera::set_farClipPlane(System.Single)".as_ptr());
+    new_hook!(set_farClipPlane_addr, Camera_set_farClipPlane);
 
-        let get_farClipPlane_addr = il2cpp_resolve_icall(c"UnityEngine.Camera::get_farClipPlane()".as_ptr());
-        new_hook!(get_farClipPlane_addr, Camera_get_farClipPlane);
+    let get_farClipPlane_addr = il2cpp_resolve_icall(c"UnityEngine.Camera::get_farClipPlane()".as_ptr());
+    new_hook!(get_farClipPlane_addr, Camera_get_farClipPlane);
+
+    get_class_or_return!(UnityEngine_CoreModule, UnityEngine, Camera);
+    unsafe {
+        GET_ALLCAMERAS_ADDR = get_method_addr(Camera, c"get_allCameras", 0);
     }
 }
@@ -122,6 +122,7 @@ fn customize(component: *mut Il2CppObject) {
 
 type Internal_AddComponentWithTypeFn = extern "C" fn(this: *mut Il2CppObject, componentType: *mut Il2CppType) -> *mut Il2CppObject;
 extern "C" fn Internal_AddComponentWithType(this: *mut Il2CppObject, componentType: *mut Il2CppType) -> *mut Il2CppObject {
+    hook_scope!(Internal_AddComponentWithType);
     let component = get_orig_fn!(Internal_AddComponentWithType, Internal_AddComponentWithTypeFn)(this, componentType);
     if !component.is_null() {
         customize(component);
@@ -137,6 +138,7 @@ struct FastPath {
 
 type TryGetComponentFastPathFn = extern "C" fn(this: *mut Il2CppObject, type_: *mut Il2CppType, oneFurtherThanResultValue: usize);
 extern "C" fn TryGetComponentFastPath(this: *mut Il2CppObject, type_: *mut Il2CppType, oneFurtherThanResultValue: usize) {
+    hook_scope!(TryGetComponentFastPath);
     get_orig_fn!(TryGetComponentFastPath, TryGetComponentFastPathFn)(this, type_, oneFurtherThanResultValue);
     let fastPath = (oneFurtherThanResultValue - std::mem::size_of::<*mut Il2CppObject>()) as *mut FastPath;
     let component = unsafe { (*fastPath).component };
@@ -3,6 +3,9 @@ use crate::il2cpp::{api::il2cpp_resolve_icall, symbols::get_method_addr, types::
 static mut GETTEMPORARY_ADDR: usize = 0;
 impl_addr_wrapper_fn!(GetTemporary, GETTEMPORARY_ADDR, *mut Il2CppObject, width: i32, height: i32);
 
+static mut GETTEMPORARY3_ADDR: usize = 0;
+impl_addr_wrapper_fn!(GetTemporary3, GETTEMPORARY3_ADDR, *mut Il2CppObject, width: i32, height: i32, depth_buffer: i32);
+
 static mut RELEASETEMPORARY_ADDR: usize = 0;
 impl_addr_wrapper_fn!(ReleaseTemporary, RELEASETEMPORARY_ADDR, (), temp: *mut Il2CppObject);
 
@@ -17,6 +20,7 @@ pub fn init(UnityEngine_CoreModule: *const Il2CppImage) {
 
     unsafe {
         GETTEMPORARY_ADDR = get_method_addr(RenderTexture, c"GetTemporary", 2);
+        GETTEMPORARY3_ADDR = get_method_addr(RenderTexture, c"GetTemporary", 3);
         RELEASETEMPORARY_ADDR = il2cpp_resolve_icall(
             c"UnityEngine.RenderTexture::ReleaseTemporary(UnityEngine.RenderTexture)".as_ptr()
         );
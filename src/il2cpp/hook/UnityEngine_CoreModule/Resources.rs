@@ -13,6 +13,7 @@ impl_addr_wrapper_fn!(Load, LOAD_ADDR, *mut Il2CppObject, path: *mut Il2CppStrin
 
 type UnloadUnusedAssetsFn = extern "C" fn() -> *mut Il2CppObject;
 extern "C" fn UnloadUnusedAssets() -> *mut Il2CppObject {
+    hook_scope!(UnloadUnusedAssets);
     let res = get_orig_fn!(UnloadUnusedAssets, UnloadUnusedAssetsFn)();
     let delegate = create_delegate(unsafe { AsyncOperation::ACTION_ASYNCOPERATION_CLASS }, 1, || {
         TextFrame::PROCESSED.lock().unwrap().retain(retain_object_gc_handle);
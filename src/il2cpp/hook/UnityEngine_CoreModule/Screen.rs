@@ -56,6 +56,7 @@ pub fn set_resolution_direct(width: i32, height: i32, fullscreen_mode: i32, pref
 
 #[cfg(target_os = "windows")]
 extern "C" fn SetResolution_Injected(width: i32, height: i32, full_screen_mode: i32, preferred_refresh_rate: *const RefreshRate) {
+    hook_scope!(SetResolution_Injected);
     let windows_config = &Hachimi::instance().config.load().windows;
     if windows_config.freeform_window {
         crate::il2cpp::hook::umamusume::StandaloneWindowResize::set_is_prevent_reshape(true);
@@ -75,6 +76,7 @@ extern "C" fn SetResolution_Injected(width: i32, height: i32, full_screen_mode:
 type RequestOrientationFn = extern "C" fn(orientation: ScreenOrientation);
 #[cfg(target_os = "windows")]
 extern "C" fn RequestOrientation(orientation: ScreenOrientation) {
+    hook_scope!(RequestOrientation);
     if Hachimi::instance().config.load().windows.freeform_window {
         return;
     }
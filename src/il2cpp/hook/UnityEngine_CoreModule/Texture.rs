@@ -1,3 +1,4 @@
+use schemars::JsonSchema;
 use serde::{Serialize, Deserialize};
 
 use crate::{core::Hachimi, il2cpp::{api::il2cpp_resolve_icall, types::*}};
@@ -11,7 +12,7 @@ enum FilterMode {
     Trilinear
 }
 
-#[derive(Default, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Default, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, JsonSchema)]
 #[repr(i32)]
 pub enum AnisoLevel {
     #[default] Default,
@@ -33,6 +34,7 @@ impl_addr_wrapper_fn!(SetAnisoLevel, SETANISOLEVEL_ADDR, (), this: *mut Il2CppOb
 #[allow(non_camel_case_types)]
 type set_filterModeFn = extern "C" fn(this: *mut Il2CppObject, filterMode: FilterMode);
 extern "C" fn set_filterMode(this: *mut Il2CppObject, filterMode: FilterMode) {
+    hook_scope!(set_filterMode);
     let level = Hachimi::instance().config.load().aniso_level;
     if level == AnisoLevel::Default {
         return get_orig_fn!(set_filterMode, set_filterModeFn)(this, filterMode);
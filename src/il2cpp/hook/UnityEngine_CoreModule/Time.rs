@@ -0,0 +1,16 @@
+use crate::il2cpp::{symbols::get_method_addr, types::*};
+
+static mut GET_TIMESCALE_ADDR: usize = 0;
+impl_addr_wrapper_fn!(get_timeScale, GET_TIMESCALE_ADDR, f32,);
+
+static mut SET_TIMESCALE_ADDR: usize = 0;
+impl_addr_wrapper_fn!(set_timeScale, SET_TIMESCALE_ADDR, (), value: f32);
+
+pub fn init(UnityEngine_CoreModule: *const Il2CppImage) {
+    get_class_or_return!(UnityEngine_CoreModule, UnityEngine, Time);
+
+    unsafe {
+        GET_TIMESCALE_ADDR = get_method_addr(Time, c"get_timeScale", 0);
+        SET_TIMESCALE_ADDR = get_method_addr(Time, c"set_timeScale", 1);
+    }
+}
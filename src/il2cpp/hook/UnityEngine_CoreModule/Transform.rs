@@ -1,7 +1,7 @@
-#[cfg(target_os = "windows")]
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use crate::{
+    core::free_camera::{self, CameraScene},
     il2cpp::{
         api::{il2cpp_class_get_type, il2cpp_resolve_icall, il2cpp_type_get_object},
         symbols::get_method_addr,
@@ -9,17 +9,12 @@ use crate::{
     }
 };
 
-#[cfg(target_os = "windows")]
-use crate::windows::free_camera::{self, CameraScene};
-
 static mut TYPE_OBJECT: *mut Il2CppObject = 0 as _;
 pub fn type_object() -> *mut Il2CppObject {
     unsafe { TYPE_OBJECT }
 }
 
-#[cfg(target_os = "windows")]
 static UPDATE_RACE_CAMERA: AtomicBool = AtomicBool::new(false);
-#[cfg(target_os = "windows")]
 pub fn set_update_race_camera(value: bool) {
     UPDATE_RACE_CAMERA.store(value, Ordering::Relaxed);
 }
@@ -87,19 +82,16 @@ impl_addr_wrapper_fn!(
     world_up: *mut Vector3_t
 );
 
-#[cfg(target_os = "windows")]
 type TransformSetVectorFn = extern "C" fn(this: *mut Il2CppObject, value: *mut Vector3_t);
-#[cfg(target_os = "windows")]
 type TransformLookAtFn = extern "C" fn(
     this: *mut Il2CppObject,
     world_position: *mut Vector3_t,
     world_up: *mut Vector3_t,
 );
-#[cfg(target_os = "windows")]
 type TransformSetQuaternionFn = extern "C" fn(this: *mut Il2CppObject, value: *mut Quaternion_t);
 
-#[cfg(target_os = "windows")]
 extern "C" fn Transform_set_position_Injected(this: *mut Il2CppObject, value: *mut Vector3_t) {
+    hook_scope!(Transform_set_position_Injected);
     if UPDATE_RACE_CAMERA.load(Ordering::Relaxed) &&
         free_camera::is_scene_enabled(CameraScene::Race) &&
         !value.is_null()
@@ -109,8 +101,8 @@ extern "C" fn Transform_set_position_Injected(this: *mut Il2CppObject, value: *m
     get_orig_fn!(Transform_set_position_Injected, TransformSetVectorFn)(this, value);
 }
 
-#[cfg(target_os = "windows")]
 extern "C" fn Transform_set_localPosition_Injected(this: *mut Il2CppObject, value: *mut Vector3_t) {
+    hook_scope!(Transform_set_localPosition_Injected);
     if UPDATE_RACE_CAMERA.load(Ordering::Relaxed) &&
         free_camera::is_scene_enabled(CameraScene::Race) &&
         !value.is_null()
@@ -120,12 +112,12 @@ extern "C" fn Transform_set_localPosition_Injected(this: *mut Il2CppObject, valu
     get_orig_fn!(Transform_set_localPosition_Injected, TransformSetVectorFn)(this, value);
 }
 
-#[cfg(target_os = "windows")]
 extern "C" fn Transform_Internal_LookAt_Injected(
     this: *mut Il2CppObject,
     world_position: *mut Vector3_t,
     world_up: *mut Vector3_t,
 ) {
+    hook_scope!(Transform_Internal_LookAt_Injected);
     if UPDATE_RACE_CAMERA.load(Ordering::Relaxed) && free_camera::is_scene_enabled(CameraScene::Race) {
         if let Some(mut rot) = free_camera::camera_rotation() {
             get_orig_fn!(Transform_set_rotation_Injected, TransformSetQuaternionFn)(this, &mut rot);
@@ -139,13 +131,13 @@ extern "C" fn Transform_Internal_LookAt_Injected(
     get_orig_fn!(Transform_Internal_LookAt_Injected, TransformLookAtFn)(this, world_position, world_up);
 }
 
-#[cfg(target_os = "windows")]
 extern "C" fn Transform_set_rotation_Injected(this: *mut Il2CppObject, value: *mut Quaternion_t) {
+    hook_scope!(Transform_set_rotation_Injected);
     get_orig_fn!(Transform_set_rotation_Injected, TransformSetQuaternionFn)(this, value);
 }
 
-#[cfg(target_os = "windows")]
 extern "C" fn Transform_set_localRotation_Injected(this: *mut Il2CppObject, value: *mut Quaternion_t) {
+    hook_scope!(Transform_set_localRotation_Injected);
     if UPDATE_RACE_CAMERA.load(Ordering::Relaxed) && free_camera::is_scene_enabled(CameraScene::Race) {
         return;
     }
@@ -178,18 +170,15 @@ pub fn init(UnityEngine_CoreModule: *const Il2CppImage) {
         INTERNAL_LOOKAT_INJECTED_ADDR = il2cpp_resolve_icall(c"UnityEngine.Transform::Internal_LookAt_Injected(UnityEngine.Vector3&,UnityEngine.Vector3&)".as_ptr());
     }
 
-    #[cfg(target_os = "windows")]
-    {
-        let set_position_Injected_addr = unsafe { SET_POSITION_INJECTED_ADDR };
-        let set_localPosition_Injected_addr = unsafe { SET_LOCALPOSITION_INJECTED_ADDR };
-        let Internal_LookAt_Injected_addr = unsafe { INTERNAL_LOOKAT_INJECTED_ADDR };
-        let set_rotation_Injected_addr = unsafe { SET_ROTATION_INJECTED_ADDR };
-        let set_localRotation_Injected_addr = unsafe { SET_LOCALROTATION_INJECTED_ADDR };
-
-        new_hook!(set_position_Injected_addr, Transform_set_position_Injected);
-        new_hook!(set_localPosition_Injected_addr, Transform_set_localPosition_Injected);
-        new_hook!(Internal_LookAt_Injected_addr, Transform_Internal_LookAt_Injected);
-        new_hook!(set_rotation_Injected_addr, Transform_set_rotation_Injected);
-        new_hook!(set_localRotation_Injected_addr, Transform_set_localRotation_Injected);
-    }
+    let set_position_Injected_addr = unsafe { SET_POSITION_INJECTED_ADDR };
+    let set_localPosition_Injected_addr = unsafe { SET_LOCALPOSITION_INJECTED_ADDR };
+    let Internal_LookAt_Injected_addr = unsafe { INTERNAL_LOOKAT_INJECTED_ADDR };
+    let set_rotation_Injected_addr = unsafe { SET_ROTATION_INJECTED_ADDR };
+    let set_localRotation_Injected_addr = unsafe { SET_LOCALROTATION_INJECTED_ADDR };
+
+    new_hook!(set_position_Injected_addr, Transform_set_position_Injected);
+    new_hook!(set_localPosition_Injected_addr, Transform_set_localPosition_Injected);
+    new_hook!(Internal_LookAt_Injected_addr, Transform_Internal_LookAt_Injected);
+    new_hook!(set_rotation_Injected_addr, Transform_set_rotation_Injected);
+    new_hook!(set_localRotation_Injected_addr, Transform_set_localRotation_Injected);
 }
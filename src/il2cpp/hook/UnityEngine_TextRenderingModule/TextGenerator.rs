@@ -10,6 +10,7 @@ extern "C" fn PopulateWithErrors(
     this: *mut Il2CppObject, str_: *mut Il2CppString,
     mut settings: TextGenerationSettings_t, context: *mut Il2CppObject
 ) -> bool {
+    hook_scope!(PopulateWithErrors);
     let orig_fn = get_orig_fn!(PopulateWithErrors, PopulateWithErrorsFn);
     let localized_data = &Hachimi::instance().localized_data.load();
     let hashed_dict = &localized_data.hashed_dict;
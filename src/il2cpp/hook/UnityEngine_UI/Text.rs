@@ -68,6 +68,7 @@ pub static ACTIVE_TEXT_COMPONENTS: Lazy<Mutex<FnvHashMap<usize, String>>> = Lazy
 
 type SetTextFn = extern "C" fn(this: *mut Il2CppObject, value: *mut Il2CppString);
 pub extern "C" fn set_text_hook(this: *mut Il2CppObject, value: *mut Il2CppString) {
+    hook_scope!(set_text_hook);
     if value.is_null() {
         return get_orig_fn!(set_text_hook, SetTextFn)(this, value);
     }
@@ -3,20 +3,27 @@
 macro_rules! new_hook {
     ($orig:ident, $hook:ident) => (
         let hachimi = crate::core::Hachimi::instance();
+        let hook_addr = $hook as *const () as usize;
         if !hachimi.config.load().disabled_hooks.contains(stringify!($hook)) {
             info!("new_hook!: {}", stringify!($hook));
             if ($orig != 0) {
-                let res = hachimi.interceptor.hook($orig as usize, $hook as *const () as usize);
-                if let Err(e) = res {
-                    error!("{}", e);
+                let res = hachimi.interceptor.hook($orig as usize, hook_addr);
+                match res {
+                    Ok(_) => crate::core::hook_registry::record(stringify!($hook), $orig as usize, hook_addr, crate::core::hook_registry::HookStatus::Installed, None),
+                    Err(e) => {
+                        error!("{}", e);
+                        crate::core::hook_registry::record(stringify!($hook), $orig as usize, hook_addr, crate::core::hook_registry::HookStatus::Failed, Some(e.to_string()));
+                    }
                 }
             }
             else {
                 error!("{} is null", stringify!($orig));
+                crate::core::hook_registry::record(stringify!($hook), 0, hook_addr, crate::core::hook_registry::HookStatus::NullAddress, None);
             }
         }
         else {
             info!("[DISABLED] new_hook!: {}", stringify!($hook));
+            crate::core::hook_registry::record(stringify!($hook), $orig as usize, hook_addr, crate::core::hook_registry::HookStatus::Disabled, None);
         }
     )
 }
@@ -151,7 +158,6 @@ pub mod Unity_TextMeshPro;
 
 #[cfg(target_os = "windows")]
 pub mod UnityEngine_InputLegacyModule;
-#[cfg(target_os = "windows")]
 pub mod Unity_InputSystem;
 
 pub mod LibNative_Runtime;
@@ -182,11 +188,9 @@ pub fn init() {
     UnityEngine_UIModule::init();
     Unity_TextMeshPro::init();
 
+    Unity_InputSystem::init();
     #[cfg(target_os = "windows")]
-    {
-        UnityEngine_InputLegacyModule::init();
-        Unity_InputSystem::init();
-    }
+    UnityEngine_InputLegacyModule::init();
 
     // Umamusume
     LibNative_Runtime::init();
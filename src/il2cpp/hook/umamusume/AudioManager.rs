@@ -1,8 +1,14 @@
+use std::sync::Mutex;
+
 use crate::{
     core::{Hachimi, captions, live_utils::AudioPlayback},
     il2cpp::{
-        ext::Il2CppStringExt,
-        symbols::{get_method_addr, get_field_from_name, SingletonLike, Thread},
+        api::{
+            il2cpp_class_from_type, il2cpp_class_is_valuetype, il2cpp_class_value_size, il2cpp_field_get_flags,
+            il2cpp_field_get_offset, il2cpp_field_get_type, il2cpp_method_get_param
+        },
+        ext::{Il2CppStringExt, StringExt},
+        symbols::{get_method, get_method_addr, get_field_from_name, FieldsIter, SingletonLike, Thread},
         types::*
     }
 };
@@ -53,8 +59,13 @@ type PlayInternalFn = extern "C" fn(this: *mut Il2CppObject, group: SoundGroup,
 extern "C" fn PlayInternal(this: *mut Il2CppObject, group: SoundGroup,
     cue_info: *mut RequestCueInfo, play_param: *mut Il2CppObject, stop_type: i32
 ) -> AudioPlayback {
+    hook_scope!(PlayInternal);
     let result = get_orig_fn!(PlayInternal, PlayInternalFn)(this, group, cue_info, play_param, stop_type);
 
+    if group == SoundGroup::Voice {
+        save_voice_play_param(play_param);
+    }
+
     if group == SoundGroup::Voice && !cue_info.is_null() && Hachimi::instance().config.load().caption.caption_enable {
         let cue_sheet_ptr = unsafe { *cue_info }.CueSheetName;
         if !cue_sheet_ptr.is_null() {
@@ -82,6 +93,7 @@ extern "C" fn PlayInternal(this: *mut Il2CppObject, group: SoundGroup,
                         let caption_data = captions::CaptionData {
                             text: String::new(), 
                             cue_sheet: cue_sheet.clone(),
+                            cue_name: cue_name.clone(),
                             cue_id,
                             character_id: chara_id,
                             voice_id: 0,
@@ -105,14 +117,92 @@ extern "C" fn PlayInternal(this: *mut Il2CppObject, group: SoundGroup,
     result
 }
 
+const FIELD_ATTRIBUTE_STATIC: i32 = 0x0010;
+
+static mut PLAY_INTERNAL_ADDR: usize = 0;
+static mut PLAY_PARAM_SIZE: usize = 0;
+/// Offsets of the reference fields in PlayParameters. They're cleared in the saved copy so it
+/// doesn't hold on to objects the GC is free to collect.
+static PLAY_PARAM_REF_OFFSETS: Mutex<Vec<usize>> = Mutex::new(Vec::new());
+/// PlayParameters of the last voice the game played, reused for replays so they sound the same.
+static LAST_VOICE_PLAY_PARAM: Mutex<Option<Vec<u64>>> = Mutex::new(None);
+
+fn save_voice_play_param(play_param: *mut Il2CppObject) {
+    let param_size = unsafe { PLAY_PARAM_SIZE };
+    if play_param.is_null() || param_size == 0 {
+        return;
+    }
+
+    // u64s to keep the struct's fields aligned
+    let mut saved = vec![0u64; param_size.div_ceil(8)];
+    let bytes = unsafe { std::slice::from_raw_parts_mut(saved.as_mut_ptr() as *mut u8, param_size) };
+    bytes.copy_from_slice(unsafe { std::slice::from_raw_parts(play_param as *const u8, param_size) });
+    for &offset in PLAY_PARAM_REF_OFFSETS.lock().unwrap().iter() {
+        if let Some(field) = bytes.get_mut(offset..offset + std::mem::size_of::<usize>()) {
+            field.fill(0);
+        }
+    }
+    *LAST_VOICE_PLAY_PARAM.lock().unwrap() = Some(saved);
+}
+
+/// Plays a voice cue without triggering captions. Returns false if the voice couldn't be played.
+/// Call on the main thread, hooks are toggled there.
+pub fn play_voice(cue_sheet: &str, cue_name: &str, cue_id: i32) -> bool {
+    let this = instance();
+    if this.is_null() {
+        return false;
+    }
+    // Only known once the game has played a voice itself, which is also the only way there's
+    // anything to replay
+    let Some(mut play_param) = LAST_VOICE_PLAY_PARAM.lock().unwrap().clone() else {
+        return false;
+    };
+
+    // Without the hook in place (disabled, failed to install or turned off at runtime) there's no
+    // trampoline, but the original is untouched and can be called directly
+    let trampoline = Hachimi::instance().interceptor.get_trampoline_addr(PlayInternal as *const () as usize);
+    let addr = if trampoline != 0 { trampoline } else { unsafe { PLAY_INTERNAL_ADDR } };
+    if addr == 0 {
+        return false;
+    }
+    let play_internal: PlayInternalFn = unsafe { std::mem::transmute(addr) };
+
+    let mut cue_info = RequestCueInfo {
+        CueSheetName: cue_sheet.to_il2cpp_string(),
+        CueName: cue_name.to_il2cpp_string(),
+        CueId: cue_id
+    };
+    play_internal(this, SoundGroup::Voice, &mut cue_info, play_param.as_mut_ptr() as *mut Il2CppObject, 0);
+    true
+}
+
 pub fn init(umamusume: *const Il2CppImage) {
     get_class_or_return!(umamusume, Gallop, AudioManager);
 
     let play_internal_addr = get_method_addr(AudioManager, c"PlayInternal", 4);
     new_hook!(play_internal_addr, PlayInternal);
 
+    if let Ok(method) = get_method(AudioManager, c"PlayInternal", 4) {
+        let param_class = il2cpp_class_from_type(il2cpp_method_get_param(method, 2));
+        if !param_class.is_null() && il2cpp_class_is_valuetype(param_class) {
+            // Field offsets of value types include the object header that unboxed values don't have
+            let header_size = std::mem::size_of::<Il2CppObject>();
+            let ref_offsets = FieldsIter::new(param_class)
+                .filter(|&field| il2cpp_field_get_flags(field) & FIELD_ATTRIBUTE_STATIC == 0)
+                .filter(|&field| {
+                    let field_class = il2cpp_class_from_type(il2cpp_field_get_type(field));
+                    field_class.is_null() || !il2cpp_class_is_valuetype(field_class)
+                })
+                .filter_map(|field| il2cpp_field_get_offset(field).checked_sub(header_size))
+                .collect();
+            *PLAY_PARAM_REF_OFFSETS.lock().unwrap() = ref_offsets;
+            unsafe { PLAY_PARAM_SIZE = il2cpp_class_value_size(param_class, std::ptr::null_mut()) as usize; }
+        }
+    }
+
     unsafe {
         CLASS = AudioManager;
+        PLAY_INTERNAL_ADDR = play_internal_addr;
         GET_CRIAUDIOMANAGER_ADDR = get_method_addr(AudioManager, c"get_CriAudioManager", 0);
         GET_CUE_LENGTH_ADDR = get_method_addr(AudioManager, c"GetCueLength", 2);
         _SONGPLAYBACK_FIELD = get_field_from_name(AudioManager, c"_songPlayback");
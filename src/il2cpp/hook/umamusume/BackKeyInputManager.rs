@@ -3,7 +3,7 @@ use crate::{
         symbols::get_method_addr,
         types::*,
     },
-    windows::free_camera
+    core::free_camera
 };
 use std::sync::atomic::{AtomicU8, Ordering};
 
@@ -17,6 +17,7 @@ fn preserve_hook_identity(identity: &AtomicU8) {
 
 type IsTriggeredBackKeyFn = extern "C" fn() -> bool;
 extern "C" fn IsTriggeredBackKey() -> bool {
+    hook_scope!(IsTriggeredBackKey);
     preserve_hook_identity(&BACK_KEY_TRIGGER_HOOK_ID);
     if free_camera::is_game_input_capture_active() {
         false
@@ -27,6 +28,7 @@ extern "C" fn IsTriggeredBackKey() -> bool {
 
 type BackMouseTriggeredFn = extern "C" fn(this: *mut Il2CppObject) -> bool;
 extern "C" fn get_IsRightMouseButtonPressedForBack(this: *mut Il2CppObject) -> bool {
+    hook_scope!(get_IsRightMouseButtonPressedForBack);
     preserve_hook_identity(&BACK_MOUSE_TRIGGER_HOOK_ID);
     if free_camera::is_game_input_capture_active() {
         false
@@ -2,6 +2,7 @@ use crate::{core::Hachimi, il2cpp::{symbols::get_method_addr, types::*}};
 
 type GetCanvasSizeFn = extern "C" fn(this: *mut Il2CppObject) -> Vector2_t;
 extern "C" fn GetCanvasSize(this: *mut Il2CppObject) -> Vector2_t {
+    hook_scope!(GetCanvasSize);
     let mut size = get_orig_fn!(GetCanvasSize, GetCanvasSizeFn)(this);
     let mult = Hachimi::instance().config.load().virtual_res_mult;
     if mult != 1.0 {
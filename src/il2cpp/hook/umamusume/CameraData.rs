@@ -1,8 +1,9 @@
 use std::ptr::null_mut;
+use schemars::JsonSchema;
 use serde::{Serialize, Deserialize};
 use crate::il2cpp::{symbols::get_method_addr, types::*};
 
-#[derive(Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Default, Clone, Copy, Serialize, Deserialize, PartialEq, JsonSchema)]
 #[repr(i32)]
 pub enum ShadowResolution {
     #[default] Default,
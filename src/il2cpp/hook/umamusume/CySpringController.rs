@@ -1,8 +1,9 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{core::Hachimi, il2cpp::{symbols::{get_field_from_name, get_method_addr, set_field_value}, types::*}};
 
-#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
 #[repr(i32)]
 pub enum SpringUpdateMode {
     ModeNormal,
@@ -18,6 +19,7 @@ fn set_UpdateMode(this: *mut Il2CppObject, value: &SpringUpdateMode) {
 
 type InitFn = extern "C" fn(this: *mut Il2CppObject);
 extern "C" fn Init(this: *mut Il2CppObject) {
+    hook_scope!(Init);
     get_orig_fn!(Init, InitFn)(this);
 
     if let Some(mode) = Hachimi::instance().config.load().physics_update_mode.as_ref() {
@@ -18,6 +18,7 @@ const MISSION_TEXT_HEIGHT: f32 = 87.0;
 
 type SetupFn = extern "C" fn(this: *mut Il2CppObject, parameter: *mut Il2CppObject);
 pub fn Setup(this: *mut Il2CppObject, parameter: *mut Il2CppObject) {
+    hook_scope!(Setup);
     get_orig_fn!(Setup, SetupFn)(this, parameter);
     let text_obj = get_titleText(this);
     let text_transform = Component::get_transform(text_obj);
@@ -10,6 +10,7 @@ extern "C" fn SetupAndOpen(
     this: *mut Il2CppObject, dialog_data: *mut Il2CppObject, on_selected: *mut Il2CppObject,
     on_cancel: *mut Il2CppObject, is_special_unlock_race: bool, race_info: *mut Il2CppObject
 ) {
+    hook_scope!(SetupAndOpen);
     let force_allow_dynamic_camera = Hachimi::instance().config.load().force_allow_dynamic_camera;
     let mut orig_race_type = None;
     if force_allow_dynamic_camera {
@@ -1,6 +1,6 @@
 use crate::{
     il2cpp::{
-        symbols::get_method_addr,
+        symbols::{get_field_from_name, get_method_addr},
         types::*
     }
 };
@@ -11,11 +11,24 @@ impl_addr_wrapper_fn!(get_CharaId, GET_CHARAID_ADDR, i32, this: *mut Il2CppObjec
 static mut GET_MOBID_ADDR: usize = 0;
 impl_addr_wrapper_fn!(get_MobId, GET_MOBID_ADDR, i32, this: *mut Il2CppObject);
 
+def_field_value_accessors!(set set_CharaId, CHARAID_FIELD, i32);
+def_field_value_accessors!(set set_MobId, MOBID_FIELD, i32);
+def_field_value_accessors!(set set_DressId, DRESSID_FIELD, i32);
+
+/// Whether the backing fields used by live profiles were found.
+pub fn can_override() -> bool {
+    unsafe { !CHARAID_FIELD.is_null() && !MOBID_FIELD.is_null() && !DRESSID_FIELD.is_null() }
+}
+
 pub fn init(LiveLoadSettings: *mut Il2CppClass) {
     find_nested_class_or_return!(LiveLoadSettings, CharacterInfo);
 
     unsafe {
         GET_CHARAID_ADDR = get_method_addr(CharacterInfo, c"get_CharaId", 0);
         GET_MOBID_ADDR = get_method_addr(CharacterInfo, c"get_MobId", 0);
+
+        CHARAID_FIELD = get_field_from_name(CharacterInfo, c"<CharaId>k__BackingField");
+        MOBID_FIELD = get_field_from_name(CharacterInfo, c"<MobId>k__BackingField");
+        DRESSID_FIELD = get_field_from_name(CharacterInfo, c"<DressId>k__BackingField");
     }
 }
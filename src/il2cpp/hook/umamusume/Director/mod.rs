@@ -1,5 +1,5 @@
 use crate::{
-    core::{game::Region, gui::IS_LIVE_SCENE, Hachimi},
+    core::{game::Region, gui::IS_LIVE_SCENE, live_profiles, Hachimi},
     il2cpp::{
         ext::StringExt,
         hook::UnityEngine_CoreModule::Camera,
@@ -24,30 +24,21 @@ pub mod LiveLoadSettings;
 
 use LiveLoadSettings::{CharacterInfo, RaceInfo};
 
-#[cfg(target_os = "windows")]
 use super::{
     CharacterObject, LiveModelController, ModelController,
 };
-#[cfg(target_os = "windows")]
 use crate::{
-    windows::free_camera::{self, CameraScene},
-    il2cpp::{
-        hook::UnityEngine_CoreModule::{GameObject, Transform},
-    }
+    core::free_camera::{self, CameraScene},
+    il2cpp::hook::UnityEngine_CoreModule::{GameObject, Transform}
 };
 
-#[cfg(target_os = "windows")]
 static LIVE_DISABLED_HEADS: free_camera::DisabledHeadStore = once_cell::sync::Lazy::new(free_camera::new_disabled_head_store);
 
-#[cfg(target_os = "windows")]
 def_field_object_accessors!(get__multiCameraFinalCompositeArray, set__multiCameraFinalCompositeArray, MULTI_CAMERA_FINAL_COMPOSITE_ARRAY_FIELD, Il2CppArray);
 // MCFC = MultiCameraFinalComposite
-#[cfg(target_os = "windows")]
 static mut SET_MULTI_CAMERA_FADE_VALUE_ADDR: usize = 0;
-#[cfg(target_os = "windows")]
 impl_addr_wrapper_fn!(MCFC_set_fadeValue, SET_MULTI_CAMERA_FADE_VALUE_ADDR, (), this: *mut Il2CppObject, value: f32);
 
-#[cfg(target_os = "windows")]
 const LIVE_CHARACTER_POSITIONS: &[i32] = &[
     0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 20, 21,
 ];
@@ -86,6 +77,7 @@ impl_addr_wrapper_fn!(get_LiveTotalTime, GET_LIVETOTALTIME_ADDR, f32, this: *mut
 
 type PauseLiveFn = extern "C" fn(this: *mut Il2CppObject, is_pause: bool);
 pub extern "C" fn PauseLive(this: *mut Il2CppObject, is_pause: bool) {
+    hook_scope!(PauseLive);
     get_orig_fn!(PauseLive, PauseLiveFn)(this, is_pause);
     IS_LIVE_PAUSED.store(is_pause, Ordering::Release);
 }
@@ -102,9 +94,7 @@ impl_addr_wrapper_fn!(get_LiveTimeController, GET_LIVETIMECONTROLLER_ADDR, *mut
 static mut GET_MAIN_CAMERA_OBJECT_ADDR: usize = 0;
 impl_addr_wrapper_fn!(get_MainCameraObject, GET_MAIN_CAMERA_OBJECT_ADDR, *mut Il2CppObject, this: *mut Il2CppObject);
 
-#[cfg(target_os = "windows")]
 static mut GET_MAIN_CAMERA_TRANSFORM_ADDR: usize = 0;
-#[cfg(target_os = "windows")]
 impl_addr_wrapper_fn!(get_MainCameraTransform, GET_MAIN_CAMERA_TRANSFORM_ADDR, *mut Il2CppObject, this: *mut Il2CppObject);
 
 static mut SET_DISPLAYMODE_ADDR: usize = 0;
@@ -122,9 +112,7 @@ impl_addr_wrapper_fn!(
     extra_resource_id: i32
 );
 
-#[cfg(target_os = "windows")]
 static mut GET_CHARACTER_OBJECT_FROM_POSITION_ID_ADDR: usize = 0;
-#[cfg(target_os = "windows")]
 impl_addr_wrapper_fn!(
     GetCharacterObjectFromPositionId, GET_CHARACTER_OBJECT_FROM_POSITION_ID_ADDR,
     *mut Il2CppObject, this: *mut Il2CppObject, id: i32
@@ -139,12 +127,10 @@ def_field_object_accessors!(
     _TRAINERCAMERATARGETCAMERATRANSFORM_FIELD, Il2CppObject
 );
 
-#[cfg(target_os = "windows")]
 pub fn restore_live_disabled_heads(current_index: i32, force_all: bool) {
     free_camera::restore_disabled_heads(&LIVE_DISABLED_HEADS, current_index, force_all);
 }
 
-#[cfg(target_os = "windows")]
 fn for_each_live_model_controller(
     chara_object: *mut Il2CppObject,
     mut callback: impl FnMut(*mut Il2CppObject),
@@ -162,7 +148,6 @@ fn for_each_live_model_controller(
     }
 }
 
-#[cfg(target_os = "windows")]
 pub fn apply_live_character_options_to_character(chara_object: *mut Il2CppObject) {
     if chara_object.is_null() {
         return;
@@ -184,7 +169,6 @@ pub fn apply_live_character_options_to_character(chara_object: *mut Il2CppObject
     });
 }
 
-#[cfg(target_os = "windows")]
 pub fn apply_live_character_options_to_list(character_object_list: *mut Il2CppObject) {
     let Some(character_object_list) = IList::<*mut Il2CppObject>::new(character_object_list) else {
         return;
@@ -195,7 +179,6 @@ pub fn apply_live_character_options_to_list(character_object_list: *mut Il2CppOb
     }
 }
 
-#[cfg(target_os = "windows")]
 pub fn apply_live_character_options(this: *mut Il2CppObject) {
     if this.is_null() {
         return;
@@ -284,12 +267,53 @@ fn patch_champions_live(this: *mut Il2CppObject) {
     RaceInfo::set_TrainerNameArrayForChampionsText(race_info, null_mut());
 }
 
-#[cfg(target_os = "windows")]
+fn apply_live_profile(this: *mut Il2CppObject) {
+    let music_id = GetPlaySongId(this);
+    let Some(profile) = live_profiles::active_profile(music_id) else {
+        return;
+    };
+
+    if !CharacterInfo::can_override() {
+        warn!("Live profile '{}' not applied, CharacterInfo fields are unavailable", profile.name);
+        return;
+    }
+
+    let load_settings = get_LoadSettings(this);
+    if load_settings.is_null() {
+        return;
+    }
+
+    let chara_info_list = LiveLoadSettings::get_CharacterInfoList(load_settings);
+    let Some(ilist) = IList::<*mut Il2CppObject>::new(chara_info_list) else {
+        return;
+    };
+
+    for (i, member) in profile.members.iter().enumerate() {
+        let Some(info) = ilist.get(i as i32) else {
+            break;
+        };
+        if info.is_null() || member.is_empty() {
+            continue;
+        }
+
+        if member.chara_id != 0 {
+            CharacterInfo::set_CharaId(info, member.chara_id);
+        }
+        if member.mob_id != 0 && CharacterInfo::get_CharaId(info) == live_profiles::MOB_CHARA_ID {
+            CharacterInfo::set_MobId(info, member.mob_id);
+        }
+        if member.dress_id != 0 {
+            CharacterInfo::set_DressId(info, member.dress_id);
+        }
+    }
+
+    info!("Applied live profile '{}' to music id {}", profile.name, music_id);
+}
+
 fn update_free_camera_live_availability(director: *mut Il2CppObject) {
     free_camera::set_live_music_id(GetPlaySongId(director));
 }
 
-#[cfg(target_os = "windows")]
 fn force_free_camera_fullscreen(this: *mut Il2CppObject) {
     if this.is_null() || !free_camera::is_scene_enabled(CameraScene::Live) {
         return;
@@ -308,7 +332,6 @@ fn force_free_camera_fullscreen(this: *mut Il2CppObject) {
     }
 }
 
-#[cfg(target_os = "windows")]
 fn update_live_free_camera_target(this: *mut Il2CppObject) {
     let first_person = free_camera::is_live_first_person();
     let selfie_stick = free_camera::is_live_selfie_stick();
@@ -375,7 +398,6 @@ fn update_live_free_camera_target(this: *mut Il2CppObject) {
     }
 }
 
-#[cfg(target_os = "windows")]
 fn apply_free_camera_to_main_camera(director: *mut Il2CppObject) {
     if !free_camera::is_scene_enabled(CameraScene::Live) {
         return;
@@ -405,13 +427,11 @@ fn apply_free_camera_to_main_camera(director: *mut Il2CppObject) {
     }
 }
 
-#[cfg(target_os = "windows")]
 pub fn enforce_live_free_camera_output(director: *mut Il2CppObject) {
     force_free_camera_fullscreen(director);
     apply_free_camera_to_main_camera(director);
 }
 
-#[cfg(target_os = "windows")]
 pub fn apply_paused_free_camera() {
     if !is_live_paused() {
         return;
@@ -444,17 +464,19 @@ pub fn is_trainer_live() -> bool {
 
 type AwakeFn = extern "C" fn(this: *mut Il2CppObject);
 extern "C" fn Awake(this: *mut Il2CppObject) {
+    hook_scope!(Awake);
     get_orig_fn!(Awake, AwakeFn)(this);
     IS_LIVE_SCENE.store(true, Ordering::Release);
     IS_LIVE_PAUSED.store(IsPauseLive(this), Ordering::Release);
 
-    #[cfg(target_os = "windows")]
     update_free_camera_live_availability(this);
 
     if is_trainer_live() && Hachimi::instance().config.load().trainer_live_landscape {
         set_displayMode(this, DisplayMode::Landscape);
     }
 
+    apply_live_profile(this);
+
     if Hachimi::instance().config.load().champions_live_show_text {
         patch_champions_live(this);
     }
@@ -464,6 +486,7 @@ extern "C" fn Awake(this: *mut Il2CppObject) {
 type ApplyTrainerCameraFovFn = extern "C" fn(this: *mut Il2CppObject);
 #[cfg(target_os = "android")]
 extern "C" fn ApplyTrainerCameraFov(this: *mut Il2CppObject) {
+    hook_scope!(ApplyTrainerCameraFov);
     get_orig_fn!(ApplyTrainerCameraFov, ApplyTrainerCameraFovFn)(this);
 
     let config = Hachimi::instance().config.load();
@@ -484,10 +507,9 @@ extern "C" fn ApplyTrainerCameraFov(this: *mut Il2CppObject) {
     }
 }
 
-#[cfg(target_os = "windows")]
 type AlterUpdateFn = extern "C" fn(this: *mut Il2CppObject, delta_time: f32, is_update_delta_time: bool);
-#[cfg(target_os = "windows")]
 extern "C" fn AlterUpdate(this: *mut Il2CppObject, delta_time: f32, is_update_delta_time: bool) {
+    hook_scope!(AlterUpdate);
     free_camera::begin_live_director_update();
     get_orig_fn!(AlterUpdate, AlterUpdateFn)(this, delta_time, is_update_delta_time);
     free_camera::set_live_active();
@@ -501,6 +523,7 @@ extern "C" fn AlterUpdate(this: *mut Il2CppObject, delta_time: f32, is_update_de
 type SetupOrientationFn = extern "C" fn(this: *mut Il2CppObject, display_mode: DisplayMode);
 #[cfg(target_os = "windows")]
 extern "C" fn SetupOrientation(this: *mut Il2CppObject, display_mode: DisplayMode) {
+    hook_scope!(SetupOrientation);
     let config = Hachimi::instance().config.load();
     let mut target_display_mode = display_mode;
     if config.windows.freeform_window {
@@ -540,15 +563,12 @@ pub fn init(umamusume: *const Il2CppImage) {
         _TRAINERCAMERATARGETCAMERA_FIELD = get_field_from_name(Director, c"_trainerCameraTargetCamera");
         _TRAINERCAMERATARGETCAMERATRANSFORM_FIELD = get_field_from_name(Director, c"_trainerCameraTargetCameraTransform");
 
-        #[cfg(target_os = "windows")]
-        {
-            GET_MAIN_CAMERA_TRANSFORM_ADDR = get_method_addr(Director, c"get_MainCameraTransform", 0);
-            GET_CHARACTER_OBJECT_FROM_POSITION_ID_ADDR = get_method_addr(Director, c"GetCharacterObjectFromPositionId", 1);
-            MULTI_CAMERA_FINAL_COMPOSITE_ARRAY_FIELD = get_field_from_name(Director, c"_multiCameraFinalCompositeArray");
+        GET_MAIN_CAMERA_TRANSFORM_ADDR = get_method_addr(Director, c"get_MainCameraTransform", 0);
+        GET_CHARACTER_OBJECT_FROM_POSITION_ID_ADDR = get_method_addr(Director, c"GetCharacterObjectFromPositionId", 1);
+        MULTI_CAMERA_FINAL_COMPOSITE_ARRAY_FIELD = get_field_from_name(Director, c"_multiCameraFinalCompositeArray");
 
-            get_class_or_return!(umamusume, "Gallop.Live", MultiCameraFinalComposite);
-            SET_MULTI_CAMERA_FADE_VALUE_ADDR = get_method_addr(MultiCameraFinalComposite, c"set_fadeValue", 1);
-        }
+        get_class_or_return!(umamusume, "Gallop.Live", MultiCameraFinalComposite);
+        SET_MULTI_CAMERA_FADE_VALUE_ADDR = get_method_addr(MultiCameraFinalComposite, c"set_fadeValue", 1);
     }
 
     let awake_addr = get_method_addr(Director, c"Awake", 0);
@@ -572,8 +592,8 @@ pub fn init(umamusume: *const Il2CppImage) {
     {
         let setup_orientation_addr = get_method_addr(Director, c"SetupOrientation", 1);
         new_hook!(setup_orientation_addr, SetupOrientation);
-
-        let AlterUpdate_addr = get_method_addr(Director, c"AlterUpdate", 2);
-        new_hook!(AlterUpdate_addr, AlterUpdate);
     }
+
+    let AlterUpdate_addr = get_method_addr(Director, c"AlterUpdate", 2);
+    new_hook!(AlterUpdate_addr, AlterUpdate);
 }
@@ -2,18 +2,21 @@ use crate::{core::taskbar::{self, TBPF_NORMAL, TBPF_NOPROGRESS}, il2cpp::{symbol
 
 type ShowFn = extern "C" fn(this: *mut Il2CppObject);
 extern "C" fn Show(this: *mut Il2CppObject) {
+    hook_scope!(Show);
     taskbar::update_download_state(TBPF_NORMAL);
     get_orig_fn!(Show, ShowFn)(this);
 }
 
 type HideFn = extern "C" fn(this: *mut Il2CppObject, is_delay: bool);
 extern "C" fn Hide(this: *mut Il2CppObject, is_delay: bool) {
+    hook_scope!(Hide);
     taskbar::update_download_state(TBPF_NOPROGRESS);
     get_orig_fn!(Hide, HideFn)(this, is_delay);
 }
 
 type SetProgressFn = extern "C" fn(this: *mut Il2CppObject, progress: f32);
 extern "C" fn SetProgress(this: *mut Il2CppObject, progress: f32) {
+    hook_scope!(SetProgress);
     taskbar::update_download_value((progress * 10000.0) as u64, 10000);
     get_orig_fn!(SetProgress, SetProgressFn)(this, progress);
 }
@@ -2,6 +2,7 @@ use crate::{core::taskbar::{self, TBPF_NOPROGRESS}, il2cpp::{symbols::get_method
 
 type UpdateViewFn = extern "C" fn(this: *mut Il2CppObject, download_size: f32, all_download_size: f32);
 extern "C" fn UpdateView(this: *mut Il2CppObject, download_size: f32, all_download_size: f32) {
+    hook_scope!(UpdateView);
     let progress = download_size / all_download_size;
     if progress >= 1.0 {
         taskbar::update_download_state(TBPF_NOPROGRESS);
@@ -11,6 +11,7 @@ use super::WindowsGamepadControl;
 
 type MousePositionFn = extern "C" fn() -> Vector3_t;
 extern "C" fn mousePosition() -> Vector3_t {
+    hook_scope!(mousePosition);
     if !Hachimi::instance().config.load().windows.freeform_window {
         return get_orig_fn!(mousePosition, MousePositionFn)();
     }
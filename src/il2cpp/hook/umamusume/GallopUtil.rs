@@ -21,6 +21,7 @@ type LineHeadWrapCommonFnJP = extern "C" fn(
 extern "C" fn LineHeadWrapCommonJP(
     s: *mut Il2CppString, line_char_count: i32, handling_type: i32, is_match_delegate: *mut Il2CppDelegate
 ) -> *mut Il2CppString {
+    hook_scope!(LineHeadWrapCommonJP);
     // Don't wrap text if prewrapped or requested.
     if NO_WRAP.load(Ordering::Relaxed) || utils::game_str_has_newline(s) {
         return s;
@@ -38,6 +39,7 @@ type LineHeadWrapCommonFnGlobal = extern "C" fn(
 extern "C" fn LineHeadWrapCommonGlobal(
     s: *mut Il2CppString, line_char_count: i32, is_match_delegate: *mut Il2CppDelegate
 ) -> *mut Il2CppString {
+    hook_scope!(LineHeadWrapCommonGlobal);
     if utils::game_str_has_newline(s) {
         // assume prewrapped, let the game handle it
         return get_orig_fn!(LineHeadWrapCommonGlobal, LineHeadWrapCommonFnGlobal)(s, line_char_count, is_match_delegate);
@@ -55,6 +57,7 @@ type LineHeadWrapCommonWithColorTagFn = extern "C" fn(
 extern "C" fn LineHeadWrapCommonWithColorTag(
     str: *mut Il2CppString, line_char_count: i32, is_count_single_char: bool, is_match_delegate: *mut Il2CppDelegate
 ) -> *mut Il2CppString {
+    hook_scope!(LineHeadWrapCommonWithColorTag);
     if let Some(wrapped) = utils::wrap_text_il2cpp(str, line_char_count) {
         return wrapped;
     }
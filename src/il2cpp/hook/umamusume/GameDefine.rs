@@ -1,7 +1,8 @@
 use crate::il2cpp::types::*;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Default, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Default, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, JsonSchema)]
 #[repr(i32)]
 pub enum BgSeason {
     #[default] None = 0,
@@ -1,13 +1,8 @@
-use crate::{core::{Hachimi, game::Region}, il2cpp::{symbols::{IEnumerator, MoveNextFn, SingletonLike, get_method_addr}, types::*}};
-#[cfg(target_os = "windows")]
-use crate::windows::free_camera::{self, CameraScene};
-#[cfg(target_os = "windows")]
-use crate::core::live_utils;
-#[cfg(target_os = "windows")]
+use crate::{core::{free_camera::{self, CameraScene}, live_utils, Hachimi, game::Region}, il2cpp::{symbols::{IEnumerator, MoveNextFn, SingletonLike, get_method_addr}, types::*}};
 use super::Director;
-// use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, Ordering};
 use super::TextId;
-// pub static GAME_INITIALIZED: AtomicBool = AtomicBool::new(false);
+pub static GAME_INITIALIZED: AtomicBool = AtomicBool::new(false);
 
 static mut CLASS: *mut Il2CppClass = 0 as _;
 pub fn class() -> *mut Il2CppClass {
@@ -24,9 +19,7 @@ pub fn instance() -> *mut Il2CppObject {
 static mut SOFTWARERESET_ADDR: usize = 0;
 impl_addr_wrapper_fn!(SoftwareReset, SOFTWARERESET_ADDR, (), this: *mut Il2CppObject);
 
-#[cfg(target_os = "windows")]
 type GameSystemUpdateFn = extern "C" fn(this: *mut Il2CppObject);
-#[cfg(target_os = "windows")]
 fn apply_free_camera_live_pause_request() {
     if !free_camera::take_toggle_live_pause_request() {
         return;
@@ -34,8 +27,8 @@ fn apply_free_camera_live_pause_request() {
     live_utils::toggle_live_pause();
 }
 
-#[cfg(target_os = "windows")]
 extern "C" fn GameSystem_Update(this: *mut Il2CppObject) {
+    hook_scope!(GameSystem_Update);
     apply_free_camera_live_pause_request();
 
     // Live and race normally tick from their camera LateUpdate hooks. Keep the
@@ -47,10 +40,9 @@ extern "C" fn GameSystem_Update(this: *mut Il2CppObject) {
     get_orig_fn!(GameSystem_Update, GameSystemUpdateFn)(this);
 }
 
-#[cfg(target_os = "windows")]
 type GameSystemLateUpdateFn = extern "C" fn(this: *mut Il2CppObject);
-#[cfg(target_os = "windows")]
 extern "C" fn GameSystem_LateUpdate(this: *mut Il2CppObject) {
+    hook_scope!(GameSystem_LateUpdate);
     get_orig_fn!(GameSystem_LateUpdate, GameSystemLateUpdateFn)(this);
     Director::apply_paused_free_camera();
 }
@@ -58,7 +50,7 @@ extern "C" fn GameSystem_LateUpdate(this: *mut Il2CppObject) {
 // good hook for initializing values i guess
 pub fn on_game_initialized() {
     Hachimi::instance().init_character_data();
-    // GAME_INITIALIZED.store(true, Ordering::Relaxed);
+    GAME_INITIALIZED.store(true, Ordering::Relaxed);
     Hachimi::instance().init_skill_info();
 
     // For homescreen_bgseason options
@@ -83,6 +75,7 @@ pub fn on_game_initialized() {
 }
 
 extern "C" fn InitializeGame_MoveNext(enumerator: *mut Il2CppObject) -> bool {
+    hook_scope!(InitializeGame_MoveNext);
     let moved = get_orig_fn!(InitializeGame_MoveNext, MoveNextFn)(enumerator);
     if !moved {
         // Game has finished initializing
@@ -103,12 +96,14 @@ fn InitializeGameCommon(enumerator: IEnumerator) -> IEnumerator {
 
 type InitializeGameJpFn = extern "C" fn(this: *mut Il2CppObject, on_complete_initialize_ui: *mut Il2CppObject) -> IEnumerator;
 extern "C" fn InitializeGameJp(this: *mut Il2CppObject, on_complete_initialize_ui: *mut Il2CppObject) -> IEnumerator {
+    hook_scope!(InitializeGameJp);
     let enumerator = get_orig_fn!(InitializeGameJp, InitializeGameJpFn)(this, on_complete_initialize_ui);
     InitializeGameCommon(enumerator)
 }
 
 type InitializeGameOtherFn = extern "C" fn(this: *mut Il2CppObject) -> IEnumerator;
 extern "C" fn InitializeGameOther(this: *mut Il2CppObject) -> IEnumerator {
+    hook_scope!(InitializeGameOther);
     let enumerator = get_orig_fn!(InitializeGameOther, InitializeGameOtherFn)(this);
     InitializeGameCommon(enumerator)
 }
@@ -130,11 +125,8 @@ pub fn init(umamusume: *const Il2CppImage) {
         SOFTWARERESET_ADDR = get_method_addr(GameSystem, c"SoftwareReset", 0);
     }
 
-    #[cfg(target_os = "windows")]
-    {
-        let GameSystem_Update_addr = get_method_addr(GameSystem, c"Update", 0);
-        new_hook!(GameSystem_Update_addr, GameSystem_Update);
-        let GameSystem_LateUpdate_addr = get_method_addr(GameSystem, c"LateUpdate", 0);
-        new_hook!(GameSystem_LateUpdate_addr, GameSystem_LateUpdate);
-    }
+    let GameSystem_Update_addr = get_method_addr(GameSystem, c"Update", 0);
+    new_hook!(GameSystem_Update_addr, GameSystem_Update);
+    let GameSystem_LateUpdate_addr = get_method_addr(GameSystem, c"LateUpdate", 0);
+    new_hook!(GameSystem_LateUpdate_addr, GameSystem_LateUpdate);
 }
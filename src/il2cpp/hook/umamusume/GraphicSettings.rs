@@ -1,3 +1,4 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{core::Hachimi, il2cpp::{symbols::{get_method_addr}, types::*}};
@@ -21,6 +22,7 @@ pub fn instance() -> *mut Il2CppObject {
 
 type GetVirtualResolutionFn = extern "C" fn(this: *mut Il2CppObject) -> Vector2Int_t;
 extern "C" fn GetVirtualResolution(this: *mut Il2CppObject) -> Vector2Int_t {
+    hook_scope!(GetVirtualResolution);
     let mut res = get_orig_fn!(GetVirtualResolution, GetVirtualResolutionFn)(this);
     let mult = Hachimi::instance().config.load().virtual_res_mult;
     if mult != 1.0 {
@@ -31,6 +33,7 @@ extern "C" fn GetVirtualResolution(this: *mut Il2CppObject) -> Vector2Int_t {
 
 type GetVirtualResolution3DFn = extern "C" fn(this: *mut Il2CppObject, is_forced_wide_aspect: bool) -> Vector2Int_t;
 extern "C" fn GetVirtualResolution3D(this: *mut Il2CppObject, is_forced_wide_aspect: bool) -> Vector2Int_t {
+    hook_scope!(GetVirtualResolution3D);
     let mut res = get_orig_fn!(GetVirtualResolution3D, GetVirtualResolution3DFn)(this, is_forced_wide_aspect);
     let mult = Hachimi::instance().config.load().virtual_res_mult;
     if mult != 1.0 &&
@@ -44,6 +47,7 @@ extern "C" fn GetVirtualResolution3D(this: *mut Il2CppObject, is_forced_wide_asp
 
 type GetVirtualResolutionWidth3DFn = extern "C" fn(this: *mut Il2CppObject) -> i32;
 extern "C" fn GetVirtualResolutionWidth3D(this: *mut Il2CppObject) -> i32 {
+    hook_scope!(GetVirtualResolutionWidth3D);
     let mut width = get_orig_fn!(GetVirtualResolutionWidth3D, GetVirtualResolutionWidth3DFn)(this);
     let mult = Hachimi::instance().config.load().virtual_res_mult;
     if mult != 1.0 {
@@ -57,7 +61,7 @@ static mut UPDATE3DRENDERTEXTURE_ADDR: usize = 0;
 #[cfg(target_os = "windows")]
 impl_addr_wrapper_fn!(Update3DRenderTexture, UPDATE3DRENDERTEXTURE_ADDR, (), this: *mut Il2CppObject);
 
-#[derive(Default, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Default, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, JsonSchema)]
 #[repr(i32)]
 pub enum GraphicsQuality {
     #[default] Default = -1,
@@ -69,7 +73,7 @@ pub enum GraphicsQuality {
 }
 
 // UnityEngine.Rendering.Universal 
-#[derive(Default, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Default, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, JsonSchema)]
 #[repr(i32)]
 pub enum MsaaQuality {
     #[default] Disabled = 1,
@@ -80,6 +84,7 @@ pub enum MsaaQuality {
 
 type get_IsMSAAFn = extern "C" fn(this: *mut Il2CppObject) -> bool;
 pub extern "C" fn get_IsMSAA(this: *mut Il2CppObject) -> bool {
+    hook_scope!(get_IsMSAA);
     if Hachimi::instance().config.load().msaa != MsaaQuality::Disabled {
         return true;
     }
@@ -88,6 +93,7 @@ pub extern "C" fn get_IsMSAA(this: *mut Il2CppObject) -> bool {
 
 type set_ResolutionScaleFn = extern "C" fn(this: *mut Il2CppObject, value: f32);
 extern "C" fn set_ResolutionScale(this: *mut Il2CppObject, value: f32) {
+    hook_scope!(set_ResolutionScale);
     let render_scale = Hachimi::instance().config.load().render_scale;
     let target_value = if render_scale != 1.0 { render_scale } else { value };
     get_orig_fn!(set_ResolutionScale, set_ResolutionScaleFn)(this, target_value);
@@ -95,6 +101,7 @@ extern "C" fn set_ResolutionScale(this: *mut Il2CppObject, value: f32) {
 
 type set_ResolutionScale2DFn = extern "C" fn(this: *mut Il2CppObject, value: f32);
 pub extern "C" fn set_ResolutionScale2D(this: *mut Il2CppObject, value: f32) {
+    hook_scope!(set_ResolutionScale2D);
     let render_scale = Hachimi::instance().config.load().render_scale;
     let target_value = if render_scale != 1.0 { render_scale } else { value };
     get_orig_fn!(set_ResolutionScale2D, set_ResolutionScale2DFn)(this, target_value);
@@ -102,6 +109,7 @@ pub extern "C" fn set_ResolutionScale2D(this: *mut Il2CppObject, value: f32) {
 
 type Get3DAntiAliasingLevelFn = extern "C" fn(this: *mut Il2CppObject, allowMSAA: bool) -> i32;
 extern "C" fn Get3DAntiAliasingLevel(this: *mut Il2CppObject, allowMSAA: bool) -> i32 {
+    hook_scope!(Get3DAntiAliasingLevel);
     let msaa = Hachimi::instance().config.load().msaa;
     if allowMSAA && msaa != MsaaQuality::Disabled {
         return msaa as i32;
@@ -111,6 +119,7 @@ extern "C" fn Get3DAntiAliasingLevel(this: *mut Il2CppObject, allowMSAA: bool) -
 
 type ApplyGraphicsQualityFn = extern "C" fn(this: *mut Il2CppObject, quality: GraphicsQuality, force: bool);
 extern "C" fn ApplyGraphicsQuality(this: *mut Il2CppObject, quality: GraphicsQuality, force: bool) {
+    hook_scope!(ApplyGraphicsQuality);
     let custom_quality = Hachimi::instance().config.load().graphics_quality;
     if custom_quality != GraphicsQuality::Default {
         return get_orig_fn!(ApplyGraphicsQuality, ApplyGraphicsQualityFn)(this, custom_quality, true);
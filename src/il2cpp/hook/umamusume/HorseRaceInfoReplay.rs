@@ -6,12 +6,11 @@ use std::{
 use once_cell::sync::Lazy;
 
 use crate::{
-    core::Hachimi,
+    core::{free_camera, Hachimi},
     il2cpp::{
         symbols::get_method_addr,
         types::*,
     },
-    windows::free_camera,
 };
 
 use super::{HorseData, HorseRaceInfo};
@@ -33,6 +32,7 @@ extern "C" fn ctor(
     data: *mut Il2CppObject,
     reader: *mut Il2CppObject,
 ) {
+    hook_scope!(ctor);
     get_orig_fn!(ctor, HorseRaceInfoReplayCtorFn)(this, data, reader);
 
     if data.is_null() {
@@ -45,9 +45,10 @@ extern "C" fn ctor(
 
 type get_RunMotionSpeedFn = extern "C" fn(this: *mut Il2CppObject) -> f32;
 extern "C" fn get_RunMotionSpeed(this: *mut Il2CppObject) -> f32 {
+    hook_scope!(get_RunMotionSpeed);
     let result = get_orig_fn!(get_RunMotionSpeed, get_RunMotionSpeedFn)(this);
 
-    if !Hachimi::instance().config.load().windows.free_camera.enabled {
+    if !Hachimi::instance().config.load().free_camera.enabled {
         return result;
     }
 
@@ -1,7 +1,7 @@
 use std::sync::atomic::{AtomicU8, Ordering};
 
 use crate::{
-    windows::free_camera,
+    core::free_camera,
     il2cpp::{
         symbols::get_method_addr,
         types::*,
@@ -26,6 +26,7 @@ type InputButtonFn = extern "C" fn(this: *mut Il2CppObject, action_name: *mut Il
 macro_rules! block_input_button {
     ($hook:ident, $identity:ident) => {
         extern "C" fn $hook(this: *mut Il2CppObject, action_name: *mut Il2CppString) -> bool {
+            hook_scope!($hook);
             preserve_hook_identity(&$identity);
             if free_camera::is_game_input_capture_active() {
                 false
@@ -42,6 +43,7 @@ block_input_button!(GetButtonUp, GET_BUTTON_UP_HOOK_ID);
 
 type GetAxisFn = extern "C" fn(this: *mut Il2CppObject, action_name: *mut Il2CppString) -> f32;
 extern "C" fn GetAxis(this: *mut Il2CppObject, action_name: *mut Il2CppString) -> f32 {
+    hook_scope!(GetAxis);
     preserve_hook_identity(&GET_AXIS_HOOK_ID);
     if free_camera::is_game_input_capture_active() {
         0.0
@@ -52,6 +54,7 @@ extern "C" fn GetAxis(this: *mut Il2CppObject, action_name: *mut Il2CppString) -
 
 type GetVector2Fn = extern "C" fn(this: *mut Il2CppObject, action_name: *mut Il2CppString) -> Vector2_t;
 extern "C" fn GetVector2(this: *mut Il2CppObject, action_name: *mut Il2CppString) -> Vector2_t {
+    hook_scope!(GetVector2);
     preserve_hook_identity(&GET_VECTOR2_HOOK_ID);
     if free_camera::is_game_input_capture_active() {
         Vector2_t::default()
@@ -62,6 +65,7 @@ extern "C" fn GetVector2(this: *mut Il2CppObject, action_name: *mut Il2CppString
 
 type IsActionKeyTriggeredInKeyboardFn = extern "C" fn(this: *mut Il2CppObject) -> bool;
 extern "C" fn IsActionKeyTriggeredInKeyboard(this: *mut Il2CppObject) -> bool {
+    hook_scope!(IsActionKeyTriggeredInKeyboard);
     preserve_hook_identity(&KEYBOARD_TRIGGER_HOOK_ID);
     if free_camera::is_game_input_capture_active() {
         false
@@ -72,6 +76,7 @@ extern "C" fn IsActionKeyTriggeredInKeyboard(this: *mut Il2CppObject) -> bool {
 
 type IsActionButtonTriggeredInGamepadFn = extern "C" fn(this: *mut Il2CppObject) -> bool;
 extern "C" fn IsActionButtonTriggeredInGamepad(this: *mut Il2CppObject) -> bool {
+    hook_scope!(IsActionButtonTriggeredInGamepad);
     preserve_hook_identity(&GAMEPAD_TRIGGER_HOOK_ID);
     if free_camera::is_game_input_capture_active() {
         false
@@ -82,6 +87,7 @@ extern "C" fn IsActionButtonTriggeredInGamepad(this: *mut Il2CppObject) -> bool
 
 type get_IsAnyKeyTriggeredInKeyboardFn = extern "C" fn() -> bool;
 extern "C" fn get_IsAnyKeyTriggeredInKeyboard() -> bool {
+    hook_scope!(get_IsAnyKeyTriggeredInKeyboard);
     preserve_hook_identity(&ANY_KEY_TRIGGER_HOOK_ID);
     if free_camera::is_game_input_capture_active() {
         false
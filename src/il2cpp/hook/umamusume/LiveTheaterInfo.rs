@@ -11,6 +11,7 @@ use super::DownloadPathRegister;
 
 type RegisterDownloadForLiveDirectorFn = extern "C" fn(register: *mut Il2CppObject, id: i32);
 extern "C" fn RegisterDownloadForLiveDirector(register: *mut Il2CppObject, id: i32) {
+    hook_scope!(RegisterDownloadForLiveDirector);
     get_orig_fn!(RegisterDownloadForLiveDirector, RegisterDownloadForLiveDirectorFn)(register, id);
 
     let config = Hachimi::instance().config.load();
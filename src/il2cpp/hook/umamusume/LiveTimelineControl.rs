@@ -1,8 +1,7 @@
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 use crate::{
-    core::Hachimi,
-    windows::free_camera::{self, CameraScene},
+    core::{free_camera::{self, CameraScene}, Hachimi},
     il2cpp::{
         hook::UnityEngine_CoreModule::Camera,
         symbols::get_method_addr,
@@ -194,6 +193,7 @@ extern "C" fn AlterUpdate_CameraPos(
     sheet_index: i32,
     mut is_use_camera_motion: bool,
 ) {
+    hook_scope!(AlterUpdate_CameraPos);
     free_camera::set_live_active();
     clear_live_screen_effects(sheet);
     let free_camera_active = free_camera::is_scene_enabled(CameraScene::Live);
@@ -227,6 +227,7 @@ extern "C" fn AlterUpdate_CameraLookAt(
     current_time: f32,
     out_look_at: *mut Vector3_t,
 ) {
+    hook_scope!(AlterUpdate_CameraLookAt);
     free_camera::set_live_active();
     clear_live_screen_effects(sheet);
     get_orig_fn!(AlterUpdate_CameraLookAt, AlterUpdate_CameraLookAtFn)(
@@ -246,6 +247,7 @@ extern "C" fn AlterUpdate_CameraLookAt(
 }
 
 extern "C" fn LiveTimelineControl_AlterLateUpdate(this: *mut Il2CppObject) {
+    hook_scope!(LiveTimelineControl_AlterLateUpdate);
     free_camera::set_live_active();
     free_camera::tick();
     get_orig_fn!(LiveTimelineControl_AlterLateUpdate, NoArgsFn)(this);
@@ -257,6 +259,7 @@ extern "C" fn LiveTimelineControl_AlterLateUpdate(this: *mut Il2CppObject) {
 }
 
 extern "C" fn LiveTimelineControl_OnDestroy(this: *mut Il2CppObject) {
+    hook_scope!(LiveTimelineControl_OnDestroy);
     Director::restore_live_disabled_heads(0, true);
     clear_current();
     free_camera::end_scene(CameraScene::Live);
@@ -268,6 +271,7 @@ extern "C" fn AlterUpdate_RadialBlur(
     sheet: *mut Il2CppObject,
     current_frame: i32,
 ) {
+    hook_scope!(AlterUpdate_RadialBlur);
     if !should_remove_live_camera_effects() {
         get_orig_fn!(AlterUpdate_RadialBlur, LiveVoidFrameFn)(this, sheet, current_frame);
     }
@@ -287,6 +291,7 @@ extern "C" fn SetupPostFilmUpdateDataInfo(
     nextData: *mut Il2CppObject,
     currentFrame: i32,
 ) {
+    hook_scope!(SetupPostFilmUpdateDataInfo);
     get_orig_fn!(SetupPostFilmUpdateDataInfo, SetupPostFilmUpdateDataInfoFn)(
         this, updateInfo, curData, nextData, currentFrame,
     );
@@ -312,6 +317,7 @@ extern "C" fn SetupDOFUpdateInfo(
     current_frame: i32,
     camera_look_at: Vector3_t,
 ) {
+    hook_scope!(SetupDOFUpdateInfo);
     get_orig_fn!(SetupDOFUpdateInfo, SetupDOFUpdateInfoFn)(
         this,
         update_info,
@@ -344,6 +350,7 @@ extern "C" fn SetupRadialBlurInfo(
     next_data: *mut Il2CppObject,
     current_frame: i32,
 ) {
+    hook_scope!(SetupRadialBlurInfo);
     if should_remove_live_camera_effects() {
         return;
     }
@@ -359,6 +366,7 @@ extern "C" fn SetupRadialBlurInfo(
 macro_rules! live_skip_void_frame {
     ($hook:ident, $type:ty) => {
         extern "C" fn $hook(this: *mut Il2CppObject, sheet: *mut Il2CppObject, current_frame: i32) {
+            hook_scope!($hook);
             if should_remove_live_camera_effects() {
                 return;
             }
@@ -370,6 +378,7 @@ macro_rules! live_skip_void_frame {
 macro_rules! live_secondary_camera_void_frame {
     ($hook:ident, $type:ty) => {
         extern "C" fn $hook(this: *mut Il2CppObject, sheet: *mut Il2CppObject, current_frame: i32) {
+            hook_scope!($hook);
             let _guard = free_camera::begin_live_secondary_camera_update();
             get_orig_fn!($hook, $type)(this, sheet, current_frame);
         }
@@ -379,6 +388,7 @@ macro_rules! live_secondary_camera_void_frame {
 macro_rules! live_main_camera_void_frame {
     ($hook:ident, $type:ty) => {
         extern "C" fn $hook(this: *mut Il2CppObject, sheet: *mut Il2CppObject, current_frame: i32) {
+            hook_scope!($hook);
             if should_override_live_camera() {
                 return;
             }
@@ -395,6 +405,7 @@ macro_rules! live_secondary_camera_void_frame_time {
             current_frame: i32,
             current_time: f32,
         ) {
+            hook_scope!($hook);
             let _guard = free_camera::begin_live_secondary_camera_update();
             get_orig_fn!($hook, $type)(this, sheet, current_frame, current_time);
         }
@@ -420,6 +431,7 @@ extern "C" fn AlterLateUpdate_CameraMotion(
     sheet: *mut Il2CppObject,
     current_frame: i32,
 ) -> bool {
+    hook_scope!(AlterLateUpdate_CameraMotion);
     if should_override_live_camera() {
         return false;
     }
@@ -431,6 +443,7 @@ extern "C" fn AlterUpdate_CameraFov(
     sheet: *mut Il2CppObject,
     current_frame: i32,
 ) {
+    hook_scope!(AlterUpdate_CameraFov);
     if should_override_live_camera() {
         return;
     }
@@ -453,6 +466,7 @@ extern "C" fn AlterUpdate_CameraRoll(
     sheet: *mut Il2CppObject,
     current_frame: i32,
 ) {
+    hook_scope!(AlterUpdate_CameraRoll);
     if should_override_live_camera() {
         return;
     }
@@ -473,6 +487,7 @@ extern "C" fn AlterUpdate_FormationOffset(
     character_object_list: *mut Il2CppObject,
     mut change_visibility: bool,
 ) {
+    hook_scope!(AlterUpdate_FormationOffset);
     free_camera::set_live_active();
     let disable_teleport = free_camera::should_disable_live_character_teleport();
     let frame = if disable_teleport { 0 } else { current_frame };
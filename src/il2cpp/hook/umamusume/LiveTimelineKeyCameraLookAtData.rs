@@ -1,5 +1,5 @@
 use crate::{
-    windows::free_camera::{self, CameraScene, FreeCameraMode},
+    core::free_camera::{self, CameraScene, FreeCameraMode},
     il2cpp::{
         symbols::get_method_addr,
         types::*,
@@ -31,6 +31,7 @@ extern "C" fn GetCharacterWorldPos(
     mut props_index: i32,
     mut props_attach_node_index: i32,
 ) -> *mut Vector3_t {
+    hook_scope!(GetCharacterWorldPos);
     free_camera::set_live_active();
     LiveTimelineControl::set_current(timeline_control);
 
@@ -1,5 +1,5 @@
 use crate::{
-    windows::free_camera::{self, CameraScene, FreeCameraMode},
+    core::free_camera::{self, CameraScene, FreeCameraMode},
     il2cpp::{
         ext::Il2CppObjectExt,
         symbols::{get_field_from_name, get_method_addr},
@@ -36,6 +36,7 @@ extern "C" fn GetValue(
     this: *mut Il2CppObject,
     timeline_control: *mut Il2CppObject,
 ) -> *mut Vector3_t {
+    hook_scope!(GetValue);
     free_camera::set_live_active();
 
     if free_camera::is_live_secondary_camera_update() ||
@@ -69,6 +70,7 @@ extern "C" fn GetValue2(
     timeline_control: *mut Il2CppObject,
     set_type: LiveCameraPositionType,
 ) -> *mut Vector3_t {
+    hook_scope!(GetValue2);
     free_camera::set_live_active();
 
     if free_camera::is_live_secondary_camera_update() ||
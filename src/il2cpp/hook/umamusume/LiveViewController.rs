@@ -30,6 +30,7 @@ static mut SOCW_RET_ORIENTATION: *mut Il2CppObject = std::ptr::null_mut();
 
 type GetChangeViewOrientationFn = extern "C" fn(this: *mut Il2CppObject, retOrientation: *mut Il2CppObject) -> IEnumerator;
 extern "C" fn GetChangeViewOrientation(this: *mut Il2CppObject, retOrientation: *mut Il2CppObject) -> IEnumerator {
+    hook_scope!(GetChangeViewOrientation);
     let enumerator = get_orig_fn!(GetChangeViewOrientation, GetChangeViewOrientationFn)(this, retOrientation);
 
     unsafe { SOCW_RET_ORIENTATION = retOrientation; }
@@ -41,6 +42,7 @@ extern "C" fn GetChangeViewOrientation(this: *mut Il2CppObject, retOrientation:
 }
 
 extern "C" fn GetChangeViewOrientation_MoveNext(enumerator: *mut Il2CppObject) -> bool {
+    hook_scope!(GetChangeViewOrientation_MoveNext);
     let moved = get_orig_fn!(GetChangeViewOrientation_MoveNext, MoveNextFn)(enumerator);
     if !moved { // hasn't moved = enumerator just finished
         unsafe {
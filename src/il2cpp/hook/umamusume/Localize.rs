@@ -22,6 +22,7 @@ static mut TEXTID_NAME_CACHE: LazyCell<FnvHashMap<i32, String>> = LazyCell::new(
  */
 type GetFn = extern "C" fn(id: i32) -> *mut Il2CppString;
 pub extern "C" fn Get(id: i32) -> *mut Il2CppString {
+    hook_scope!(Get);
     let hachimi = Hachimi::instance();
     let localized_data = hachimi.localized_data.load();
     if localized_data.localize_dict.is_empty() {
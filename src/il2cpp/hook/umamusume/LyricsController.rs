@@ -3,7 +3,7 @@ use std::path::Path;
 use fnv::FnvHashMap;
 
 use crate::{
-    core::{ext::Utf16StringExt, Hachimi, game::Region},
+    core::{ext::Utf16StringExt, Hachimi, game::Region, live_subtitles::{self, SubtitleCue}},
     il2cpp::{
         ext::{Il2CppStringExt, StringExt},
         symbols::{get_field_from_name, get_field_object_value, get_method_addr, Array, Dictionary},
@@ -28,6 +28,14 @@ trait LyricsDataCommon {
     fn time(&self) -> f32;
     fn lyrics_mut(&mut self) -> &mut *mut Il2CppString;
 
+    fn lyrics_string(&mut self) -> String {
+        let lyrics = *self.lyrics_mut();
+        if lyrics.is_null() {
+            return String::new();
+        }
+        unsafe { (*lyrics).as_utf16str().to_string() }
+    }
+
     fn get_key(&self) -> i32 {
         f32::to_bits(self.time()).cast_signed()
     }
@@ -59,6 +67,7 @@ impl LyricsDataCommon for LyricsDataGlobal {
 
 type LoadLyricsFn = extern "C" fn(this: *mut Il2CppObject, id: i32, path: *mut Il2CppString) -> bool;
 extern "C" fn LoadLyrics(this: *mut Il2CppObject, id: i32, path: *mut Il2CppString) -> bool {
+    hook_scope!(LoadLyrics);
     if !get_orig_fn!(LoadLyrics, LoadLyricsFn)(this, id, path) {
         return false;
     }
@@ -66,12 +75,11 @@ extern "C" fn LoadLyrics(this: *mut Il2CppObject, id: i32, path: *mut Il2CppStri
     // Live/MusicScores/mXXXX/mXXXX_lyrics
     let path_str = unsafe { (*path).as_utf16str() };
 
-    let mut dict_path = Path::new("lyrics").join(path_str.path_filename().to_string());
+    let lyrics_name = path_str.path_filename().to_string();
+    let mut dict_path = Path::new("lyrics").join(&lyrics_name);
     dict_path.set_extension("json");
     let localized_data = Hachimi::instance().localized_data.load();
-    let Some(dict): Option<FnvHashMap<i32, String>> = localized_data.load_assets_dict(Some(&dict_path)) else {
-        return true;
-    };
+    let dict: FnvHashMap<i32, String> = localized_data.load_assets_dict(Some(&dict_path)).unwrap_or_default();
     // dont let pbork interactive know about this
     let secs_dict: FnvHashMap<i32, String> = dict.into_iter()
         .map(|(time, lyrics)| (f32::to_bits(time as f32 / 1000.0).cast_signed(), lyrics) )
@@ -82,11 +90,21 @@ extern "C" fn LoadLyrics(this: *mut Il2CppObject, id: i32, path: *mut Il2CppStri
         return true;
     };
 
-    let process_element = |data: &mut dyn LyricsDataCommon| {
+    let mut game_lyrics = Vec::new();
+    let mut translated = Vec::new();
+    let mut process_element = |data: &mut dyn LyricsDataCommon| {
+        let time = data.time();
+        let orig_text = data.lyrics_string();
         let time_key = data.get_key();
-        if let Some(text) = secs_dict.get(&time_key) {
+        let text = if let Some(text) = secs_dict.get(&time_key) {
             *data.lyrics_mut() = text.to_il2cpp_string();
+            text.clone()
         }
+        else {
+            orig_text.clone()
+        };
+        game_lyrics.push(SubtitleCue { start: time, end: None, text: orig_text });
+        translated.push(SubtitleCue { start: time, end: None, text });
     };
 
     unsafe {
@@ -126,6 +144,7 @@ extern "C" fn LoadLyrics(this: *mut Il2CppObject, id: i32, path: *mut Il2CppStri
         }
     }
 
+    live_subtitles::on_lyrics_loaded(&lyrics_name, game_lyrics, translated);
     true
 }
 
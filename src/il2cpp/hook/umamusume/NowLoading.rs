@@ -9,6 +9,7 @@ const COMIC_TITLE_LINE_WIDTH: usize = 23;
 
 type SetupLoadingTipsFn = extern "C" fn(this: *mut Il2CppObject);
 extern "C" fn SetupLoadingTips(this: *mut Il2CppObject) {
+    hook_scope!(SetupLoadingTips);
     get_orig_fn!(SetupLoadingTips, SetupLoadingTipsFn)(this);
 
     if Hachimi::instance().localized_data.load().config.now_loading_comic_title_ellipsis {
@@ -27,6 +28,7 @@ extern "C" fn SetupLoadingTips(this: *mut Il2CppObject) {
 
 type ShowFn = extern "C" fn(this: *mut Il2CppObject, type_: i32, onComplete: *mut Il2CppDelegate, overrideDuration: *mut Il2CppObject, easeType: i32, customInEffect: *mut Il2CppObject, customLoopEffect: *mut Il2CppObject, customOutEffect: *mut Il2CppObject, charaId: i32);
 extern "C" fn Show(this: *mut Il2CppObject, #[allow(unused_mut)] mut type_: i32, onComplete: *mut Il2CppDelegate, overrideDuration: *mut Il2CppObject, easeType: i32, customInEffect: *mut Il2CppObject, customLoopEffect: *mut Il2CppObject, customOutEffect: *mut Il2CppObject, charaId: i32) {
+    hook_scope!(Show);
     let config = crate::core::Hachimi::instance().config.load();
     #[cfg(target_os = "windows")]
     if type_ == 2 && !config.windows.ui_loading_show_orientation_guide {
@@ -45,6 +47,7 @@ extern "C" fn Show(this: *mut Il2CppObject, #[allow(unused_mut)] mut type_: i32,
 
 type HideFn = extern "C" fn(this: *mut Il2CppObject, onComplete: *mut Il2CppDelegate, overrideDuration: *mut Il2CppObject, easeType: i32, onUnloadCustomEffectResourcesComplete: *mut Il2CppDelegate);
 extern "C" fn Hide(this: *mut Il2CppObject, onComplete: *mut Il2CppDelegate, overrideDuration: *mut Il2CppObject, easeType: i32, onUnloadCustomEffectResourcesComplete: *mut Il2CppDelegate) {
+    hook_scope!(Hide);
     let config = crate::core::Hachimi::instance().config.load();
     if !config.hide_now_loading {
         get_orig_fn!(Hide, HideFn)(this, onComplete, overrideDuration, easeType, onUnloadCustomEffectResourcesComplete);
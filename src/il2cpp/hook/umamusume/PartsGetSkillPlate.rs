@@ -28,6 +28,7 @@ extern "C" fn SetUpCharacterLimitBreakSkill(
     nextCardRairtyData: *mut Il2CppObject,
     atlas: *mut Il2CppObject,
 ) {
+    hook_scope!(SetUpCharacterLimitBreakSkill);
     get_orig_fn!(SetUpCharacterLimitBreakSkill, SetUpCharacterLimitBreakSkillFn)(this, cardRairtyData, nextCardRairtyData, atlas);
 
     let text = get_nameText(this);
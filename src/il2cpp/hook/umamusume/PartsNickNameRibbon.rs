@@ -20,6 +20,7 @@ impl_addr_wrapper_fn!(get_Label, GET_LABEL_ADDR, *mut Il2CppObject, this: *mut I
 
 type InitFn = extern "C" fn(this: *mut Il2CppObject, nickname: *mut Il2CppObject, isLandscape: bool) -> *mut Il2CppObject;
 extern "C" fn Initialize(this: *mut Il2CppObject, nickname: *mut Il2CppObject, isLandscape: bool) -> *mut Il2CppObject {
+    hook_scope!(Initialize);
     let orig = get_orig_fn!(Initialize, InitFn)(this, nickname, isLandscape);
     fit_text(this);
     orig
@@ -11,6 +11,7 @@ pub fn get_skill_item(this: *mut Il2CppObject) -> *mut Il2CppObject {
 
 type SetupFn = extern "C" fn(this: *mut Il2CppObject, list_item_model: *mut Il2CppObject);
 fn Setup(this: *mut Il2CppObject, list_item_model: *mut Il2CppObject) {
+    hook_scope!(Setup);
     get_orig_fn!(Setup, SetupFn)(this, list_item_model);
     let skill_item = get_skill_item(this);
     if !skill_item.is_null() && !PartsSingleModeSkillListItem::get_info(skill_item).is_null() {
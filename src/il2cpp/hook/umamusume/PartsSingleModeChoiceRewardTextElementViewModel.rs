@@ -11,6 +11,7 @@ use crate::{
 type GetParameterValueTextFn =
     extern "C" fn(this: *mut Il2CppObject, param: i32) -> *mut Il2CppString;
 extern "C" fn GetParameterValueText(this: *mut Il2CppObject, param: i32) -> *mut Il2CppString {
+    hook_scope!(GetParameterValueText);
     let mut text = get_orig_fn!(GetParameterValueText, GetParameterValueTextFn)(this, param);
     let utf_str = unsafe { (*text).as_utf16str() };
     if utf_str.as_slice().contains(&36) {
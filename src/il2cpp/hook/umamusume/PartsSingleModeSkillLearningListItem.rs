@@ -11,6 +11,7 @@ fn get__descText(this: *mut Il2CppObject) -> *mut Il2CppObject {
 
 type UpdateCurrentFn = extern "C" fn(this: *mut Il2CppObject);
 extern "C" fn UpdateCurrent(this: *mut Il2CppObject) {
+    hook_scope!(UpdateCurrent);
     let name = get__nameText(this);
     let desc = get__descText(this);
 
@@ -1,5 +1,5 @@
 use crate::{
-    windows::free_camera::{self, CameraScene},
+    core::free_camera::{self, CameraScene},
     il2cpp::{
         symbols::get_method_addr,
         types::*,
@@ -8,6 +8,7 @@ use crate::{
 
 type CameraGetFloatFn = extern "C" fn(this: *mut Il2CppObject) -> f32;
 extern "C" fn get_CameraFov(this: *mut Il2CppObject) -> f32 {
+    hook_scope!(get_CameraFov);
     if let Some(fov) = free_camera::fov_for_scene(CameraScene::Race) {
         return fov;
     }
@@ -1,5 +1,5 @@
 use crate::{
-    windows::free_camera::{self, CameraScene},
+    core::free_camera::{self, CameraScene},
     il2cpp::{
         hook::UnityEngine_CoreModule::Transform,
         symbols::{get_method_addr, get_method_overload_addr},
@@ -9,6 +9,7 @@ use crate::{
 
 type NoArgsFn = extern "C" fn(this: *mut Il2CppObject);
 extern "C" fn RaceCameraManager_AlterLateUpdate(this: *mut Il2CppObject) {
+    hook_scope!(RaceCameraManager_AlterLateUpdate);
     free_camera::set_race_active();
     free_camera::tick();
 
@@ -20,6 +21,7 @@ extern "C" fn RaceCameraManager_AlterLateUpdate(this: *mut Il2CppObject) {
 
 type RaceChangeCameraModeFn = extern "C" fn(this: *mut Il2CppObject, mode: i32, is_skip: bool);
 extern "C" fn RaceCameraManager_ChangeCameraMode(this: *mut Il2CppObject, mode: i32, is_skip: bool) {
+    hook_scope!(RaceCameraManager_ChangeCameraMode);
     if free_camera::is_scene_enabled(CameraScene::Race) {
         return;
     }
@@ -43,6 +45,7 @@ extern "C" fn RaceCameraManager_PlayEventCamera(
     isForceInPlaying: bool,
     isForceUnPlayableArea: bool,
 ) -> bool {
+    hook_scope!(RaceCameraManager_PlayEventCamera);
     if free_camera::is_scene_enabled(CameraScene::Race) {
         return false;
     }
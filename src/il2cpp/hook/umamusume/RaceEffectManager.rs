@@ -1,5 +1,5 @@
 use crate::{
-    windows::free_camera::{self, CameraScene},
+    core::free_camera::{self, CameraScene},
     il2cpp::{
         symbols::get_method_addr,
         types::*,
@@ -10,6 +10,7 @@ use super::{HorseRaceInfoReplay, RaceViewBase};
 
 type OnDestroyFn = extern "C" fn(this: *mut Il2CppObject);
 extern "C" fn OnDestroy(this: *mut Il2CppObject) {
+    hook_scope!(OnDestroy);
     RaceViewBase::restore_race_disabled_heads(0, true);
     HorseRaceInfoReplay::clear_gate_no_cache();
     free_camera::end_scene(CameraScene::Race);
@@ -1,5 +1,5 @@
 use crate::{
-    windows::free_camera::{self, CameraScene},
+    core::free_camera::{self, CameraScene},
     il2cpp::{
         symbols::get_method_addr,
         types::*,
@@ -28,6 +28,7 @@ extern "C" fn RaceModelController_UpdateCameraDistanceBlendRate(
     p2: *mut Il2CppObject,
     p3: *mut Il2CppObject,
 ) {
+    hook_scope!(RaceModelController_UpdateCameraDistanceBlendRate);
     if free_camera::is_scene_enabled(CameraScene::Race) {
         return;
     }
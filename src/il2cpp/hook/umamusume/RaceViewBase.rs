@@ -1,5 +1,5 @@
 use crate::{
-    windows::free_camera::{self, CameraScene},
+    core::free_camera::{self, CameraScene},
     il2cpp::{
         ext::StringExt,
         hook::UnityEngine_CoreModule::Transform,
@@ -22,6 +22,7 @@ pub fn restore_race_disabled_heads(current_index: i32, force_all: bool) {
 
 type LateUpdateViewFn = extern "C" fn(this: *mut Il2CppObject);
 extern "C" fn LateUpdateView(this: *mut Il2CppObject) {
+    hook_scope!(LateUpdateView);
     let first_person = free_camera::is_race_first_person();
     let head_selfie = free_camera::is_race_head_selfie();
     if first_person || head_selfie {
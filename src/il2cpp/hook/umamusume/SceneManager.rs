@@ -1,4 +1,4 @@
-use std::sync::atomic::{self, AtomicBool};
+use std::sync::atomic::{self, AtomicBool, AtomicI32};
 use crate::{
     core::{Hachimi, game::Region},
     il2cpp::{
@@ -18,6 +18,12 @@ pub fn is_home_init() -> bool {
     HOME_INIT.load(atomic::Ordering::Acquire)
 }
 
+static CURRENT_VIEW_ID: AtomicI32 = AtomicI32::new(0);
+/// Last view the game changed to, safe to read from any thread.
+pub fn current_view_id() -> i32 {
+    CURRENT_VIEW_ID.load(atomic::Ordering::Relaxed)
+}
+
 static mut CLASS: *mut Il2CppClass = 0 as _;
 pub fn class() -> *mut Il2CppClass {
     unsafe { CLASS }
@@ -40,6 +46,7 @@ static mut GETCURRENTVIEWCONTROLLER_ADDR: usize = 0;
 impl_addr_wrapper_fn!(GetCurrentViewController, GETCURRENTVIEWCONTROLLER_ADDR, *mut Il2CppObject, this: *mut Il2CppObject);
 
 fn ChangeViewCommon(next_view_id: i32) {
+    CURRENT_VIEW_ID.store(next_view_id, atomic::Ordering::Relaxed);
     if next_view_id == ViewId::Splash {
         SPLASH_SHOWN.store(true, atomic::Ordering::Release);
         debug!("SPLASH_SHOWN: {}", SPLASH_SHOWN.load(atomic::Ordering::Acquire));
@@ -66,6 +73,7 @@ extern "C" fn ChangeViewJp(
     callback_on_change_view_cancel: *mut Il2CppObject, callback_on_change_view_accept: *mut Il2CppObject,
     force_change: bool, is_fast_destroy: bool, fade_in_duration: f32
 ) {
+    hook_scope!(ChangeViewJp);
     get_orig_fn!(ChangeViewJp, ChangeViewJpfn)(
         this, next_view_id, view_info, callback_on_change_view_cancel,
         callback_on_change_view_accept, force_change, is_fast_destroy,
@@ -84,6 +92,7 @@ extern "C" fn ChangeViewOther(
     callback_on_change_view_cancel: *mut Il2CppObject, callback_on_change_view_accept: *mut Il2CppObject,
     force_change: bool
 ) {
+    hook_scope!(ChangeViewOther);
     get_orig_fn!(ChangeViewOther, ChangeViewOtherfn)(
         this, next_view_id, view_info, callback_on_change_view_cancel,
         callback_on_change_view_accept, force_change
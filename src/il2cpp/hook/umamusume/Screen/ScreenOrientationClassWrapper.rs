@@ -10,6 +10,7 @@ impl_addr_wrapper_fn!(get_Orientation, GET_ORIENTATION_ADDR, ScreenOrientation,
 type set_OrientationHookFn = extern "C" fn(this: *mut Il2CppObject, value: i32);
 #[cfg(target_os = "android")]
 extern "C" fn set_OrientationHook(this: *mut Il2CppObject, value: i32) {
+    hook_scope!(set_OrientationHook);
     use crate::{
         core::Hachimi,
         il2cpp::hook::umamusume::Screen::should_force_orientation
@@ -22,6 +22,7 @@ use crate::{
 extern "C" fn ChangeScreenOrientationLandscapeAsync_MoveNext(
     enumerator: *mut Il2CppObject,
 ) -> bool {
+    hook_scope!(ChangeScreenOrientationLandscapeAsync_MoveNext);
     use crate::il2cpp::symbols::MoveNextFn;
     let moved =
         get_orig_fn!(ChangeScreenOrientationLandscapeAsync_MoveNext, MoveNextFn)(enumerator);
@@ -33,6 +34,7 @@ extern "C" fn ChangeScreenOrientationLandscapeAsync_MoveNext(
 
 #[cfg(target_os = "android")]
 extern "C" fn ChangeScreenOrientationPortraitAsync_MoveNext(enumerator: *mut Il2CppObject) -> bool {
+    hook_scope!(ChangeScreenOrientationPortraitAsync_MoveNext);
     use crate::il2cpp::symbols::MoveNextFn;
     let moved = get_orig_fn!(ChangeScreenOrientationPortraitAsync_MoveNext, MoveNextFn)(enumerator);
     if !moved {
@@ -46,6 +48,7 @@ type ChangeScreenOrientationLandscapeAsyncFn =
     extern "C" fn() -> crate::il2cpp::symbols::IEnumerator;
 #[cfg(target_os = "android")]
 extern "C" fn ChangeScreenOrientationLandscapeAsync() -> crate::il2cpp::symbols::IEnumerator {
+    hook_scope!(ChangeScreenOrientationLandscapeAsync);
     let enumerator = get_orig_fn!(
         ChangeScreenOrientationLandscapeAsync,
         ChangeScreenOrientationLandscapeAsyncFn
@@ -66,6 +69,7 @@ type ChangeScreenOrientationPortraitAsyncFn =
     extern "C" fn() -> crate::il2cpp::symbols::IEnumerator;
 #[cfg(target_os = "android")]
 extern "C" fn ChangeScreenOrientationPortraitAsync() -> crate::il2cpp::symbols::IEnumerator {
+    hook_scope!(ChangeScreenOrientationPortraitAsync);
     let enumerator = get_orig_fn!(
         ChangeScreenOrientationPortraitAsync,
         ChangeScreenOrientationPortraitAsyncFn
@@ -85,6 +89,7 @@ extern "C" fn ChangeScreenOrientationPortraitAsync() -> crate::il2cpp::symbols::
 type GetWidthFn = extern "C" fn() -> i32;
 #[cfg(target_os = "windows")]
 extern "C" fn get_Width() -> i32 {
+    hook_scope!(get_Width);
     if Hachimi::instance().config.load().windows.freeform_window {
         return UnityScreen::get_width();
     }
@@ -105,6 +110,7 @@ pub fn get_Width_orig() -> i32 {
 type GetHeightFn = extern "C" fn() -> i32;
 #[cfg(target_os = "windows")]
 extern "C" fn get_Height() -> i32 {
+    hook_scope!(get_Height);
     if Hachimi::instance().config.load().windows.freeform_window && Hachimi::instance().game.region != Region::Global {
         return UnityScreen::get_height();
     }
@@ -166,6 +172,7 @@ extern "C" fn SetResolution(
     force_update: bool,
     skip_keep_aspect: bool,
 ) {
+    hook_scope!(SetResolution);
     if !Hachimi::instance().config.load().windows.freeform_window || Hachimi::instance().game.region == Region::Global {
         get_orig_fn!(SetResolution, SetResolutionFn)(
             width,
@@ -181,6 +188,7 @@ extern "C" fn SetResolution(
 type IsCurrentOrientationFn = extern "C" fn(target: ScreenOrientation) -> bool;
 #[cfg(target_os = "windows")]
 extern "C" fn IsCurrentOrientation(target: ScreenOrientation) -> bool {
+    hook_scope!(IsCurrentOrientation);
     if Hachimi::instance().config.load().windows.freeform_window && Hachimi::instance().game.region != Region::Global {
         return true;
     }
@@ -192,6 +200,7 @@ extern "C" fn IsCurrentOrientation(target: ScreenOrientation) -> bool {
 type WaitDeviceOrientationFn = extern "C" fn(target: ScreenOrientation) -> IEnumerator;
 #[cfg(target_os = "windows")]
 extern "C" fn WaitDeviceOrientation(target: ScreenOrientation) -> IEnumerator {
+    hook_scope!(WaitDeviceOrientation);
     let enumerator = get_orig_fn!(WaitDeviceOrientation, WaitDeviceOrientationFn)(target);
     if Hachimi::instance().config.load().windows.freeform_window && Hachimi::instance().game.region != Region::Global {
         if let Err(e) = enumerator.hook_move_next(WaitDeviceOrientation_MoveNext) {
@@ -203,6 +212,7 @@ extern "C" fn WaitDeviceOrientation(target: ScreenOrientation) -> IEnumerator {
 
 #[cfg(target_os = "windows")]
 extern "C" fn WaitDeviceOrientation_MoveNext(_enumerator: *mut Il2CppObject) -> bool {
+    hook_scope!(WaitDeviceOrientation_MoveNext);
     if crate::windows::wnd_hook::close_freeform_window_for_landscape() {
         return get_orig_fn!(WaitDeviceOrientation_MoveNext, MoveNextFn)(_enumerator);
     }
@@ -216,6 +226,7 @@ extern "C" fn WaitDeviceOrientation_MoveNext(_enumerator: *mut Il2CppObject) ->
 
 type ChangeScreenOrientationFn = extern "C" fn(target: ScreenOrientation, force: bool) -> IEnumerator;
 extern "C" fn ChangeScreenOrientation(target: ScreenOrientation, force: bool) -> IEnumerator {
+    hook_scope!(ChangeScreenOrientation);
     #[cfg(target_os = "windows")]
     {
         let enumerator = get_orig_fn!(ChangeScreenOrientation, ChangeScreenOrientationFn)(target, force);
@@ -248,6 +259,7 @@ pub fn should_force_orientation() -> bool {
 
 #[cfg(target_os = "windows")]
 extern "C" fn ChangeScreenOrientation_MoveNext(_enumerator: *mut Il2CppObject) -> bool {
+    hook_scope!(ChangeScreenOrientation_MoveNext);
     if crate::windows::wnd_hook::close_freeform_window_for_landscape() {
         return get_orig_fn!(ChangeScreenOrientation_MoveNext, MoveNextFn)(_enumerator);
     }
@@ -263,6 +275,7 @@ extern "C" fn ChangeScreenOrientation_MoveNext(_enumerator: *mut Il2CppObject) -
 type ChangeScreenOrientationAsyncFn = extern "C" fn() -> IEnumerator;
 #[cfg(target_os = "windows")]
 extern "C" fn ChangeScreenOrientationLandscapeAsyncWindows() -> IEnumerator {
+    hook_scope!(ChangeScreenOrientationLandscapeAsyncWindows);
     let enumerator = get_orig_fn!(
         ChangeScreenOrientationLandscapeAsyncWindows,
         ChangeScreenOrientationAsyncFn
@@ -281,6 +294,7 @@ extern "C" fn ChangeScreenOrientationLandscapeAsyncWindows() -> IEnumerator {
 extern "C" fn ChangeScreenOrientationLandscapeAsyncWindows_MoveNext(
     _enumerator: *mut Il2CppObject,
 ) -> bool {
+    hook_scope!(ChangeScreenOrientationLandscapeAsyncWindows_MoveNext);
     if crate::windows::wnd_hook::close_freeform_window_for_landscape() {
         return get_orig_fn!(
             ChangeScreenOrientationLandscapeAsyncWindows_MoveNext,
@@ -300,6 +314,7 @@ extern "C" fn ChangeScreenOrientationLandscapeAsyncWindows_MoveNext(
 
 #[cfg(target_os = "windows")]
 extern "C" fn ChangeScreenOrientationPortraitAsyncWindows() -> IEnumerator {
+    hook_scope!(ChangeScreenOrientationPortraitAsyncWindows);
     let enumerator = get_orig_fn!(
         ChangeScreenOrientationPortraitAsyncWindows,
         ChangeScreenOrientationAsyncFn
@@ -318,6 +333,7 @@ extern "C" fn ChangeScreenOrientationPortraitAsyncWindows() -> IEnumerator {
 extern "C" fn ChangeScreenOrientationPortraitAsyncWindows_MoveNext(
     _enumerator: *mut Il2CppObject,
 ) -> bool {
+    hook_scope!(ChangeScreenOrientationPortraitAsyncWindows_MoveNext);
     if Hachimi::instance().config.load().windows.freeform_window {
         return false;
     }
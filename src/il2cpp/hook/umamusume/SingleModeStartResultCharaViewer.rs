@@ -9,6 +9,7 @@ pub fn setting_up_image_effect() -> bool {
 
 type SetupImageEffectFn = extern "C" fn(this: *mut Il2CppObject);
 extern "C" fn SetupImageEffect(this: *mut Il2CppObject) {
+    hook_scope!(SetupImageEffect);
     SETTING_UP_IMAGE_EFFECT.store(true, atomic::Ordering::Relaxed);
     get_orig_fn!(SetupImageEffect, SetupImageEffectFn)(this);
     SETTING_UP_IMAGE_EFFECT.store(false, atomic::Ordering::Relaxed);
@@ -38,6 +38,7 @@ fn freeform_enabled() -> bool {
 
 type GetLimitSizeFn = extern "C" fn() -> Vector2_t;
 extern "C" fn GetLimitSize() -> Vector2_t {
+    hook_scope!(GetLimitSize);
     preserve_hook_identity(&GET_LIMIT_SIZE_HOOK_ID);
     if freeform_enabled() {
         return Vector2_t { x: f32::MAX, y: f32::MAX };
@@ -48,6 +49,7 @@ extern "C" fn GetLimitSize() -> Vector2_t {
 
 type NoArgsFn = extern "C" fn();
 extern "C" fn DisableMaximizebox() {
+    hook_scope!(DisableMaximizebox);
     preserve_hook_identity(&DISABLE_MAXIMIZEBOX_HOOK_ID);
     if freeform_enabled() {
         crate::windows::wnd_hook::apply_freeform_window_style();
@@ -59,6 +61,7 @@ extern "C" fn DisableMaximizebox() {
 
 type ResizeFn = extern "C" fn(width: f32, height: f32);
 extern "C" fn ReshapeAspectRatio(width: f32, height: f32) {
+    hook_scope!(ReshapeAspectRatio);
     preserve_hook_identity(&RESHAPE_ASPECT_RATIO_HOOK_ID);
     if !freeform_enabled() {
         get_orig_fn!(ReshapeAspectRatio, ResizeFn)(width, height);
@@ -66,6 +69,7 @@ extern "C" fn ReshapeAspectRatio(width: f32, height: f32) {
 }
 
 extern "C" fn KeepAspectRatio(width: f32, height: f32) {
+    hook_scope!(KeepAspectRatio);
     preserve_hook_identity(&KEEP_ASPECT_RATIO_HOOK_ID);
     if freeform_enabled() {
         crate::windows::wnd_hook::apply_freeform_window_style();
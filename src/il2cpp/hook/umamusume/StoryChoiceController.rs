@@ -17,6 +17,7 @@ pub fn is_checking_choice_auto_tap() -> bool {
 
 type CheckChoiceAutoTapFn = extern "C" fn(this: *mut Il2CppObject);
 extern "C" fn CheckChoiceAutoTap(this: *mut Il2CppObject) {
+    hook_scope!(CheckChoiceAutoTap);
     IS_CHECKING_CHOICE_AUTO_TAP.store(true, atomic::Ordering::Relaxed);
 
     // Global has a different way of handling choice auto select delay in stories
@@ -25,6 +25,7 @@ impl_addr_wrapper_fn!(RebuildOutline, REBUILDOUTLINE_ADDR, (), this: *mut Il2Cpp
 
 type AwakeFn = extern "C" fn(this: *mut Il2CppObject);
 extern "C" fn Awake(this: *mut Il2CppObject) {
+    hook_scope!(Awake);
     get_orig_fn!(Awake, AwakeFn)(this);
 
     let localized_data = Hachimi::instance().localized_data.load();
@@ -63,6 +64,7 @@ extern "C" fn Awake(this: *mut Il2CppObject) {
 
 type SetSystemTextWithLineHeadWrapFn = extern "C" fn(this: *mut Il2CppObject, system_text: *mut CharacterSystemText, maxCharacter: i32);
 extern "C" fn SetSystemTextWithLineHeadWrap(this: *mut Il2CppObject, system_text: *mut CharacterSystemText, max_character: i32) {
+    hook_scope!(SetSystemTextWithLineHeadWrap);
     let ld = &Hachimi::instance().localized_data.load();
     let systext = unsafe {&*system_text};
 
@@ -4,6 +4,7 @@ use super::TextFormat;
 
 type GetChineseFontFn = extern "C" fn(this: *mut Il2CppObject) -> *mut Il2CppObject;
 extern "C" fn GetChineseFont(this: *mut Il2CppObject) -> *mut Il2CppObject {
+    hook_scope!(GetChineseFont);
     let font = Hachimi::instance().localized_data.load().load_replacement_font();
     if !font.is_null() {
         return font;
@@ -13,6 +14,7 @@ extern "C" fn GetChineseFont(this: *mut Il2CppObject) -> *mut Il2CppObject {
 
 type LoadResourcesFolderFontFn = extern "C" fn(this: *mut Il2CppObject, font_type: TextFormat::Font) -> *mut Il2CppObject;
 extern "C" fn LoadResourcesFolderFont(this: *mut Il2CppObject, font_type: TextFormat::Font) -> *mut Il2CppObject {
+    hook_scope!(LoadResourcesFolderFont);
     match font_type {
         TextFormat::Font::Dynamic01 | TextFormat::Font::Chinese_Font01 => {
             let font = Hachimi::instance().localized_data.load().load_replacement_font();
@@ -4,6 +4,7 @@ use super::GameDefine::BgSeason;
 // public static BgSeason GetSeasonForHome(DateTime dateTime) { }
 type GetSeasonForHomeFn = extern "C" fn(this: *mut Il2CppObject, dateTime: *mut Il2CppObject) -> BgSeason;
 extern "C" fn GetSeasonForHome(this: *mut Il2CppObject, dateTime: *mut Il2CppObject) -> BgSeason {
+    hook_scope!(GetSeasonForHome);
     let bg_season = Hachimi::instance().config.load().homescreen_bgseason;
     if bg_season != BgSeason::None {
         return bg_season;
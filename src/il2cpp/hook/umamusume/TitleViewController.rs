@@ -4,6 +4,7 @@ use super::MainGameInitializer;
 
 type UpdateViewFn = extern "C" fn(this: *mut Il2CppObject);
 extern "C" fn UpdateView(this: *mut Il2CppObject) {
+    hook_scope!(UpdateView);
     get_orig_fn!(UpdateView, UpdateViewFn)(this);
     if MainGameInitializer::GetBootProgress() != 0.0 {
         let progress = MainGameInitializer::GetBootProgress();
@@ -73,6 +73,7 @@ pub fn apply_ui_scale() {
 
 type SetHeaderTitleTextFn = extern "C" fn(this: *mut Il2CppObject, text: *mut Il2CppString, guide_id: i32);
 extern "C" fn SetHeaderTitleText(this: *mut Il2CppObject, text_: *mut Il2CppString, guide_id: i32) {
+    hook_scope!(SetHeaderTitleText);
     let text = unsafe { (*text_).as_utf16str() };
 
     // The title text (aka the purple ribbon on the top left of the screen) doesn't run
@@ -93,6 +94,7 @@ extern "C" fn SetHeaderTitleText(this: *mut Il2CppObject, text_: *mut Il2CppStri
 type ChangeResizeUIForPCFn = extern "C" fn(this: *mut Il2CppObject, width: i32, height: i32);
 #[cfg(target_os = "windows")]
 extern "C" fn ChangeResizeUIForPC(this: *mut Il2CppObject, width: i32, height: i32) {
+    hook_scope!(ChangeResizeUIForPC);
     use super::GraphicSettings;
 
     let windows_config = &Hachimi::instance().config.load().windows;
@@ -140,6 +142,7 @@ pub fn refresh_after_window_resize(width: i32, height: i32) {
 
 #[cfg(target_os = "android")]
 extern "C" fn WaitBootSetup_MoveNext(enumerator: *mut Il2CppObject) -> bool {
+    hook_scope!(WaitBootSetup_MoveNext);
     use crate::il2cpp::symbols::MoveNextFn;
     let moved = get_orig_fn!(WaitBootSetup_MoveNext, MoveNextFn)(enumerator);
     if !moved {
@@ -152,6 +155,7 @@ extern "C" fn WaitBootSetup_MoveNext(enumerator: *mut Il2CppObject) -> bool {
 type WaitBootSetupFn = extern "C" fn(this: *mut Il2CppObject) -> crate::il2cpp::symbols::IEnumerator;
 #[cfg(target_os = "android")]
 extern "C" fn WaitBootSetup(this: *mut Il2CppObject) -> crate::il2cpp::symbols::IEnumerator {
+    hook_scope!(WaitBootSetup);
     let enumerator = get_orig_fn!(WaitBootSetup, WaitBootSetupFn)(this);
     if Hachimi::instance().config.load().ui_scale == 1.0 { return enumerator; }
 
@@ -43,6 +43,7 @@ pub fn quick_open(dialog_title: &str, url: &str) {
 
 type GetUrlFn = extern "C" fn(this: *mut Il2CppObject, url_type: i32) -> *mut Il2CppString;
 extern "C" fn GetUrl(this: *mut Il2CppObject, url_type: i32) -> *mut Il2CppString {
+    hook_scope!(GetUrl);
     if url_type == WebViewDefine::Url_Update {
         if let Some(news_url) = &Hachimi::instance().localized_data.load().config.news_url {
             return news_url.to_il2cpp_string();
@@ -56,6 +57,7 @@ extern "C" fn GetUrl(this: *mut Il2CppObject, url_type: i32) -> *mut Il2CppStrin
 type GetGachaUrlFn = extern "C" fn(gacha_id: i32, stepup_id: i32) -> *mut Il2CppString;
 #[cfg(target_os = "windows")]
 extern "C" fn GetGachaUrl(gacha_id: i32, stepup_id: i32) -> *mut Il2CppString {
+    hook_scope!(GetGachaUrl);
     let url = get_orig_fn!(GetGachaUrl, GetGachaUrlFn)(gacha_id, stepup_id);
     crate::windows::webview::add_gacha_url(url, gacha_id);
     url
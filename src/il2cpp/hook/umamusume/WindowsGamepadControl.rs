@@ -1,5 +1,5 @@
 use crate::{
-    windows::free_camera,
+    core::free_camera,
     il2cpp::{
         symbols::{get_class, get_method_addr, SingletonLike},
         types::*,
@@ -26,6 +26,7 @@ impl_addr_wrapper_fn!(CreateRenderTextureFromScreen, CREATE_RENDER_TEXTURE_FROM_
 
 type CheckGamepadInputFn = extern "C" fn(this: *mut Il2CppObject) -> bool;
 extern "C" fn CheckGamepadInput(this: *mut Il2CppObject) -> bool {
+    hook_scope!(CheckGamepadInput);
     if free_camera::is_game_input_capture_active() {
         false
     } else {
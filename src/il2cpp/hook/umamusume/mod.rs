@@ -31,9 +31,7 @@ pub mod Screen;
 pub mod StandaloneWindowResize;
 #[cfg(target_os = "windows")]
 mod GallopInput;
-#[cfg(target_os = "windows")]
 mod InputSystemManager;
-#[cfg(target_os = "windows")]
 mod BackKeyInputManager;
 #[cfg(target_os = "windows")]
 pub mod WindowsGamepadControl;
@@ -81,39 +79,22 @@ mod LowResolutionCamera;
 
 #[cfg(target_os = "windows")]
 mod PaymentUtility;
-#[cfg(target_os = "windows")]
 mod LiveTimelineControl;
-#[cfg(target_os = "windows")]
 pub mod LiveTimelineWorkSheet;
-#[cfg(target_os = "windows")]
 pub mod LiveTimelineKeyPostFilmDataList;
-#[cfg(target_os = "windows")]
 pub mod LiveTimelineKeyCameraPositionData;
-#[cfg(target_os = "windows")]
 mod LiveTimelineKeyCameraLookAtData;
-#[cfg(target_os = "windows")]
 mod LiveTimelineKeyMultiCameraPositionData;
-#[cfg(target_os = "windows")]
 mod CharacterObject;
-#[cfg(target_os = "windows")]
 mod LiveModelController;
-#[cfg(target_os = "windows")]
 pub mod ModelController;
-#[cfg(target_os = "windows")]
 mod RaceCameraManager;
-#[cfg(target_os = "windows")]
 mod RaceCameraEventBase;
-#[cfg(target_os = "windows")]
 mod RaceModelController;
-#[cfg(target_os = "windows")]
 mod RaceViewBase;
-#[cfg(target_os = "windows")]
 mod RaceEffectManager;
-#[cfg(target_os = "windows")]
 mod HorseData;
-#[cfg(target_os = "windows")]
 pub mod HorseRaceInfo;
-#[cfg(target_os = "windows")]
 mod HorseRaceInfoReplay;
 pub mod TweenAnimationTimelineComponent;
 pub mod TweenAnimationTimelineData;
@@ -228,13 +209,30 @@ pub fn init() {
     PartsCharaMessageBase::init(image);
     SceneManager::init(image);
     LowResolutionCamera::init(image);
+    InputSystemManager::init(image);
+    BackKeyInputManager::init(image);
+    LiveTimelineControl::init(image);
+    LiveTimelineWorkSheet::init(image);
+    LiveTimelineKeyPostFilmDataList::init(image);
+    LiveTimelineKeyCameraPositionData::init(image);
+    LiveTimelineKeyCameraLookAtData::init(image);
+    LiveTimelineKeyMultiCameraPositionData::init(image);
+    CharacterObject::init(image);
+    LiveModelController::init(image);
+    ModelController::init(image);
+    RaceCameraManager::init(image);
+    RaceCameraEventBase::init(image);
+    RaceModelController::init(image);
+    RaceViewBase::init(image);
+    RaceEffectManager::init(image);
+    HorseData::init(image);
+    HorseRaceInfo::init(image);
+    HorseRaceInfoReplay::init(image);
 
     #[cfg(target_os = "windows")]
     {
         StandaloneWindowResize::init(image);
         GallopInput::init(image);
-        InputSystemManager::init(image);
-        BackKeyInputManager::init(image);
         WindowsGamepadControl::init(image);
         TapEffectController::init(image);
         PaymentUtility::init(image);
@@ -243,23 +241,6 @@ pub fn init() {
         DownloadView::init(image);
         DownloadErrorProcessor::init(image);
         MainGameInitializer::init(image);
-        LiveTimelineControl::init(image);
-        LiveTimelineWorkSheet::init(image);
-        LiveTimelineKeyPostFilmDataList::init(image);
-        LiveTimelineKeyCameraPositionData::init(image);
-        LiveTimelineKeyCameraLookAtData::init(image);
-        LiveTimelineKeyMultiCameraPositionData::init(image);
-        CharacterObject::init(image);
-        LiveModelController::init(image);
-        ModelController::init(image);
-        RaceCameraManager::init(image);
-        RaceCameraEventBase::init(image);
-        RaceModelController::init(image);
-        RaceViewBase::init(image);
-        RaceEffectManager::init(image);
-        HorseData::init(image);
-        HorseRaceInfo::init(image);
-        HorseRaceInfoReplay::init(image);
         TitleViewController::init(image);
     }
     CameraData::init(image);
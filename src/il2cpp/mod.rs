@@ -1,6 +1,7 @@
 pub mod types;
 pub mod api;
 pub mod symbols;
+pub mod symbol_report;
 pub mod hook;
 mod utils;
 pub mod ext;
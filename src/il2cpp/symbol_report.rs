@@ -0,0 +1,308 @@
+//! Records every assembly, class, method and field lookup made through `symbols` so lookups broken
+//! by a game update can be found from a single report instead of digging through the log.
+
+use std::{ffi::CStr, fs, os::raw::{c_char, c_void}, path::PathBuf, ptr::null_mut, sync::Mutex};
+
+use chrono::Local;
+use fnv::FnvHashMap;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+use crate::core::{Error, Hachimi};
+use super::{api::*, types::*};
+
+pub const REPORT_FILENAME: &str = "symbol_report.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolKind {
+    Assembly,
+    Class,
+    NestedClass,
+    Method,
+    Field
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolStatus {
+    Found,
+    Missing,
+    /// A method with the same name exists, but not with the expected number of parameters.
+    ParamCountChanged,
+    /// A method with the same name and parameter count exists, but the parameter types differ.
+    ParamTypesChanged
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SymbolEntry {
+    pub kind: SymbolKind,
+    /// Image name for classes, the declaring class for everything else.
+    pub owner: Option<String>,
+    pub name: String,
+    /// Expected parameter count for methods, -1 matches any.
+    pub param_count: Option<i32>,
+    /// Expected parameter types (Il2CppTypeEnum) for overload lookups.
+    pub param_types: Option<Vec<Il2CppTypeEnum>>,
+    pub status: SymbolStatus,
+    /// Parameter counts of the methods with the same name that do exist, for methods that weren't
+    /// found.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub available_param_counts: Vec<u32>,
+    /// Number of times this lookup was made.
+    pub lookups: u32
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+pub struct SymbolSummary {
+    pub total: usize,
+    pub found: usize,
+    pub missing: usize,
+    pub changed: usize
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SymbolReport {
+    pub hachimi_version: &'static str,
+    pub package_name: String,
+    pub region: String,
+    pub created_at: String,
+    pub summary: SymbolSummary,
+    /// Lookups that failed come first, then the rest in lookup order.
+    pub entries: Vec<SymbolEntry>
+}
+
+#[derive(Default)]
+struct Registry {
+    entries: Vec<SymbolEntry>,
+    index: FnvHashMap<(SymbolKind, Option<String>, String, Option<i32>, Option<Vec<Il2CppTypeEnum>>), usize>
+}
+
+static REGISTRY: Lazy<Mutex<Registry>> = Lazy::new(Mutex::default);
+
+fn record(entry: SymbolEntry) {
+    let mut registry = REGISTRY.lock().unwrap();
+    let key = (
+        entry.kind, entry.owner.clone(), entry.name.clone(), entry.param_count, entry.param_types.clone()
+    );
+    if let Some(&i) = registry.index.get(&key) {
+        let existing = &mut registry.entries[i];
+        existing.lookups += 1;
+        existing.status = entry.status;
+        existing.available_param_counts = entry.available_param_counts;
+        return;
+    }
+
+    let i = registry.entries.len();
+    registry.entries.push(entry);
+    registry.index.insert(key, i);
+}
+
+fn c_str_lossy(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        return String::new();
+    }
+    unsafe { CStr::from_ptr(ptr) }.to_string_lossy().into_owned()
+}
+
+fn class_name(class: *mut Il2CppClass) -> Option<String> {
+    if class.is_null() {
+        return None;
+    }
+    let namespace = c_str_lossy(unsafe { (*class).namespaze });
+    let name = c_str_lossy(unsafe { (*class).name });
+    Some(if namespace.is_empty() { name } else { format!("{}.{}", namespace, name) })
+}
+
+fn image_name(image: *const Il2CppImage) -> Option<String> {
+    if image.is_null() {
+        return None;
+    }
+    Some(c_str_lossy(il2cpp_image_get_name(image)))
+}
+
+/// Parameter counts of every method named `name` in the class and its parents.
+fn param_counts_for(class: *mut Il2CppClass, name: &CStr) -> Vec<u32> {
+    let mut counts = Vec::new();
+    let mut current = class;
+    while !current.is_null() {
+        let mut iter: *mut c_void = null_mut();
+        loop {
+            let method = il2cpp_class_get_methods(current, &mut iter);
+            if method.is_null() {
+                break;
+            }
+            if unsafe { CStr::from_ptr((*method).name) } != name {
+                continue;
+            }
+            let count = il2cpp_method_get_param_count(method);
+            if !counts.contains(&count) {
+                counts.push(count);
+            }
+        }
+        current = il2cpp_class_get_parent(current);
+    }
+    counts.sort_unstable();
+    counts
+}
+
+pub(super) fn record_assembly(name: &CStr, found: bool) {
+    record(SymbolEntry {
+        kind: SymbolKind::Assembly,
+        owner: None,
+        name: name.to_string_lossy().into_owned(),
+        param_count: None,
+        param_types: None,
+        status: if found { SymbolStatus::Found } else { SymbolStatus::Missing },
+        available_param_counts: Vec::new(),
+        lookups: 1
+    });
+}
+
+pub(super) fn record_class(image: *const Il2CppImage, namespace: &CStr, name: &CStr, found: bool) {
+    let namespace = namespace.to_string_lossy();
+    let name = name.to_string_lossy();
+    record(SymbolEntry {
+        kind: SymbolKind::Class,
+        owner: image_name(image),
+        name: if namespace.is_empty() { name.into_owned() } else { format!("{}.{}", namespace, name) },
+        param_count: None,
+        param_types: None,
+        status: if found { SymbolStatus::Found } else { SymbolStatus::Missing },
+        available_param_counts: Vec::new(),
+        lookups: 1
+    });
+}
+
+pub(super) fn record_nested_class(class: *mut Il2CppClass, name: &CStr, found: bool) {
+    record(SymbolEntry {
+        kind: SymbolKind::NestedClass,
+        owner: class_name(class),
+        name: name.to_string_lossy().into_owned(),
+        param_count: None,
+        param_types: None,
+        status: if found { SymbolStatus::Found } else { SymbolStatus::Missing },
+        available_param_counts: Vec::new(),
+        lookups: 1
+    });
+}
+
+pub(super) fn record_method(class: *mut Il2CppClass, name: &CStr, args_count: i32, found: bool) {
+    let (status, available_param_counts) = if found || class.is_null() {
+        (if found { SymbolStatus::Found } else { SymbolStatus::Missing }, Vec::new())
+    }
+    else {
+        let counts = param_counts_for(class, name);
+        (if counts.is_empty() { SymbolStatus::Missing } else { SymbolStatus::ParamCountChanged }, counts)
+    };
+
+    record(SymbolEntry {
+        kind: SymbolKind::Method,
+        owner: class_name(class),
+        name: name.to_string_lossy().into_owned(),
+        param_count: Some(args_count),
+        param_types: None,
+        status,
+        available_param_counts,
+        lookups: 1
+    });
+}
+
+pub(super) fn record_method_overload(class: *mut Il2CppClass, name: &str, params: &[Il2CppTypeEnum], found: bool) {
+    let (status, available_param_counts) = if found || class.is_null() {
+        (if found { SymbolStatus::Found } else { SymbolStatus::Missing }, Vec::new())
+    }
+    else {
+        let counts = std::ffi::CString::new(name)
+            .map(|name| param_counts_for(class, &name))
+            .unwrap_or_default();
+        let status = if counts.is_empty() {
+            SymbolStatus::Missing
+        }
+        else if counts.contains(&(params.len() as u32)) {
+            SymbolStatus::ParamTypesChanged
+        }
+        else {
+            SymbolStatus::ParamCountChanged
+        };
+        (status, counts)
+    };
+
+    record(SymbolEntry {
+        kind: SymbolKind::Method,
+        owner: class_name(class),
+        name: name.to_owned(),
+        param_count: Some(params.len() as i32),
+        param_types: Some(params.to_vec()),
+        status,
+        available_param_counts,
+        lookups: 1
+    });
+}
+
+pub(super) fn record_field(class: *mut Il2CppClass, name: &CStr, found: bool) {
+    record(SymbolEntry {
+        kind: SymbolKind::Field,
+        owner: class_name(class),
+        name: name.to_string_lossy().into_owned(),
+        param_count: None,
+        param_types: None,
+        status: if found { SymbolStatus::Found } else { SymbolStatus::Missing },
+        available_param_counts: Vec::new(),
+        lookups: 1
+    });
+}
+
+/// Builds a report from every lookup made so far. Lookups made lazily by hooks show up once the
+/// code that needs them has run.
+pub fn report() -> SymbolReport {
+    let hachimi = Hachimi::instance();
+    let mut entries = REGISTRY.lock().unwrap().entries.clone();
+
+    let mut summary = SymbolSummary { total: entries.len(), ..Default::default() };
+    for entry in entries.iter() {
+        match entry.status {
+            SymbolStatus::Found => summary.found += 1,
+            SymbolStatus::Missing => summary.missing += 1,
+            SymbolStatus::ParamCountChanged | SymbolStatus::ParamTypesChanged => summary.changed += 1
+        }
+    }
+    // Stable, so lookup order is kept within each group
+    entries.sort_by_key(|entry| entry.status == SymbolStatus::Found);
+
+    SymbolReport {
+        hachimi_version: env!("HACHIMI_DISPLAY_VERSION"),
+        package_name: hachimi.game.package_name.clone(),
+        region: hachimi.game.region.to_string(),
+        created_at: Local::now().to_rfc3339(),
+        summary,
+        entries
+    }
+}
+
+/// Writes the report to the data dir and returns it along with its path.
+pub fn write() -> Result<(SymbolReport, PathBuf), Error> {
+    let report = report();
+    let path = Hachimi::instance().get_data_path(REPORT_FILENAME);
+    fs::write(&path, serde_json::to_string_pretty(&report)?)?;
+    Ok((report, path))
+}
+
+/// Called once hooking has finished to flag anything the game update broke.
+pub fn write_startup_report() {
+    match write() {
+        Ok((report, path)) => {
+            let summary = report.summary;
+            if summary.missing != 0 || summary.changed != 0 {
+                warn!(
+                    "{} of {} symbols could not be resolved ({} missing, {} changed), see {}",
+                    summary.missing + summary.changed, summary.total, summary.missing, summary.changed, path.display()
+                );
+            }
+            else {
+                info!("All {} symbols resolved", summary.total);
+            }
+        },
+        Err(e) => error!("Failed to write symbol report: {}", e)
+    }
+}
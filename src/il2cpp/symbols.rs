@@ -15,6 +15,7 @@ use crate::core::Error;
 
 use super::api::*;
 use super::ext::Il2CppObjectExt;
+use super::symbol_report;
 use super::types::*;
 use super::types::Il2CppClass;
 use std::ptr::null_mut;
@@ -37,6 +38,7 @@ pub fn init() {
 pub fn get_assembly_image(assembly_name: &CStr) -> Result<*const Il2CppImage, Error> {
     let domain = unsafe { DOMAIN };
     let assembly = il2cpp_domain_assembly_open(domain, assembly_name.as_ptr());
+    symbol_report::record_assembly(assembly_name, !assembly.is_null());
     if assembly.is_null() {
         Err(Error::AssemblyNotFound(assembly_name.to_str().unwrap().to_owned()))
     }
@@ -47,6 +49,7 @@ pub fn get_assembly_image(assembly_name: &CStr) -> Result<*const Il2CppImage, Er
 
 pub fn get_class(image: *const Il2CppImage, namespace: &CStr, class_name: &CStr) -> Result<*mut Il2CppClass, Error> {
     let class = il2cpp_class_from_name(image, namespace.as_ptr(), class_name.as_ptr());
+    symbol_report::record_class(image, namespace, class_name, !class.is_null());
     if class.is_null() {
         Err(Error::ClassNotFound(namespace.to_str().unwrap().to_owned(), class_name.to_str().unwrap().to_owned()))
     }
@@ -57,6 +60,7 @@ pub fn get_class(image: *const Il2CppImage, namespace: &CStr, class_name: &CStr)
 
 pub fn get_method(class: *mut Il2CppClass, name: &CStr, args_count: i32) -> Result<*const MethodInfo, Error> {
     let method = il2cpp_class_get_method_from_name(class, name.as_ptr(), args_count);
+    symbol_report::record_method(class, name, args_count, !method.is_null());
     if method.is_null() {
         Err(Error::MethodNotFound(name.to_str().unwrap().to_owned()))
     }
@@ -96,10 +100,12 @@ pub fn get_method_overload(class: *mut Il2CppClass, name: &str, params: &[Il2Cpp
         }
 
         if ok {
+            symbol_report::record_method_overload(class, name, params, true);
             return Ok(method);
         }
     }
-    
+
+    symbol_report::record_method_overload(class, name, params, false);
     Err(Error::MethodNotFound(name.to_owned()))
 }
 
@@ -174,10 +180,13 @@ pub fn find_nested_class(class: *mut Il2CppClass, name: &CStr) -> Result<*mut Il
 
         let class_name = unsafe { CStr::from_ptr((*nested_class).name) };
         if class_name == name {
+            symbol_report::record_nested_class(class, name, true);
             return Ok(nested_class);
         }
     }
 
+    symbol_report::record_nested_class(class, name, false);
+
     let class_name = unsafe { CStr::from_ptr((*class).name).to_str().unwrap() };
     Err(Error::ClassNotFound(class_name.to_owned(), name.to_str().unwrap().to_owned()))
 }
@@ -206,6 +215,7 @@ pub fn set_field_object_value<T>(obj: *mut Il2CppObject, field: *mut FieldInfo,
 
 pub fn get_field_from_name(class: *mut Il2CppClass, name: &CStr) -> *mut FieldInfo {
     let field = il2cpp_class_get_field_from_name(class, name.as_ptr());
+    symbol_report::record_field(class, name, !field.is_null());
     if field.is_null() {
         warn!("get_field_from_name: {} = NULL", name.to_str().unwrap());
     }
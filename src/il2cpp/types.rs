@@ -1,6 +1,7 @@
 /* automatically generated by rust-bindgen 0.69.4 */
 #![allow(non_upper_case_globals, non_snake_case, non_camel_case_types, dead_code)]
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 #[repr(C)]
@@ -3020,7 +3021,7 @@ pub struct AndroidNotification {
     pub m_CustomTimestamp: bool,
 }
 #[repr(C)]
-#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default, JsonSchema)]
 pub struct Resolution {
     pub width: i32,
     pub height: i32,
@@ -1,5 +1,6 @@
 use std::sync::atomic;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -14,6 +15,8 @@ use crate::{
 
 use super::{utils, wnd_hook};
 
+pub use super::main::{load_plugin, unload_plugin, module_base};
+
 pub fn is_il2cpp_lib(filename: &str) -> bool {
     filename == "GameAssembly.dll"
 }
@@ -53,7 +56,7 @@ pub fn on_hooking_finished(hachimi: &Hachimi) {
     _ = std::fs::remove_file(utils::get_tmp_installer_path());
 }
 
-#[derive(Deserialize, Serialize, Clone)]
+#[derive(Deserialize, Serialize, Clone, JsonSchema)]
 pub struct Config {
     #[serde(default = "Config::default_vsync_count")]
     pub vsync_count: i32,
@@ -65,11 +68,11 @@ pub struct Config {
     pub hide_ingame_ui_hotkey_bind: u16,
     #[serde(default)]
     pub auto_full_screen: bool,
-    #[serde(default, alias = "freeFormWindow")]
+    #[serde(default)]
     pub freeform_window: bool,
-    #[serde(default = "Config::default_true", alias = "freeFormUiScaleAuto")]
+    #[serde(default = "Config::default_true")]
     pub freeform_ui_scale_auto: bool,
-    #[serde(default = "Config::default_freeform_ui_scale_auto_ratio", alias = "freeFormUiScaleAutoRatio")]
+    #[serde(default = "Config::default_freeform_ui_scale_auto_ratio")]
     pub freeform_ui_scale_auto_ratio: f32,
     #[serde(default)]
     pub full_screen_mode: FullScreenMode,
@@ -99,8 +102,6 @@ pub struct Config {
     pub custom_title_name: Option<String>,
     #[serde(default)]
     pub ingame_webview: bool,
-    #[serde(default)]
-    pub free_camera: super::free_camera::FreeCameraConfig,
 }
 
 impl Config {
@@ -112,14 +113,14 @@ impl Config {
     fn default_freeform_ui_scale_auto_ratio() -> f32 { 0.55 }
 }
 
-#[derive(Deserialize, Serialize, Copy, Clone, Default, Eq, PartialEq)]
+#[derive(Deserialize, Serialize, Copy, Clone, Default, Eq, PartialEq, JsonSchema)]
 #[repr(i32)]
 pub enum FullScreenMode {
     #[default] ExclusiveFullScreen = FullScreenMode_ExclusiveFullScreen,
     FullScreenWindow = FullScreenMode_FullScreenWindow
 }
 
-#[derive(Deserialize, Serialize, Copy, Clone, Default, Eq, PartialEq)]
+#[derive(Deserialize, Serialize, Copy, Clone, Default, Eq, PartialEq, JsonSchema)]
 pub enum ResolutionScaling {
     #[default] Default,
     ScaleToScreenSize,
@@ -1,25 +1,29 @@
-use simplelog::{ConfigBuilder, LevelFilter, WriteLogger};
-use std::fs::File;
+use std::path::PathBuf;
 
-pub fn init(filter_level: log::LevelFilter, file_logging: bool) {
-    if file_logging {
-        let mut path = super::utils::get_game_dir();
-        path.push("hachimi.log");
+use log::{Log, Metadata, Record};
+use windows::{core::HSTRING, Win32::System::Diagnostics::Debug::OutputDebugStringW};
 
-        if let Ok(file) = File::create(path) {
-            let config = ConfigBuilder::new()
-                .set_target_level(LevelFilter::Error)
-                .add_filter_ignore_str("sqlparser")
-                .set_time_format_rfc3339()
-                .build();
+pub fn get_log_dir() -> PathBuf {
+    super::utils::get_game_dir()
+}
 
-            if WriteLogger::init(filter_level, config, file).is_ok() {
-                return;
-            }
-        }
+/// Writes to the debugger output, viewable with DebugView when no debugger is attached.
+struct DebugOutputLogger;
+
+impl Log for DebugOutputLogger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        // Levels are filtered by core::log
+        true
     }
 
-    if let Some(level) = filter_level.to_level() {
-        windebug_logger::init_with_level(level).ok();
+    fn log(&self, record: &Record) {
+        let text = format!("{} [{}] {}\n", record.level(), record.target(), record.args());
+        unsafe { OutputDebugStringW(&HSTRING::from(text)); }
     }
-}
\ No newline at end of file
+
+    fn flush(&self) {}
+}
+
+pub fn platform_logger() -> Box<dyn Log> {
+    Box::new(DebugOutputLogger)
+}
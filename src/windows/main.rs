@@ -1,7 +1,10 @@
 use std::os::raw::{c_ulong, c_void};
 
 use widestring::U16CString;
-use windows::{core::PCWSTR, Win32::{Foundation::{HMODULE, TRUE}, System::LibraryLoader::LoadLibraryW}};
+use windows::{core::PCWSTR, Win32::{Foundation::{HMODULE, TRUE}, System::LibraryLoader::{
+    FreeLibrary, GetModuleHandleExW, LoadLibraryW, GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS,
+    GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT
+}}};
 
 use crate::{core::{plugin_api::Plugin, Hachimi}, windows::utils};
 
@@ -11,48 +14,71 @@ const DLL_PROCESS_ATTACH: c_ulong = 1;
 const DLL_PROCESS_DETACH: c_ulong = 0;
 
 pub fn load_libraries() -> Vec<Plugin> {
-    let mut plugins = Vec::new();
-    for name in Hachimi::instance().config.load().windows.load_libraries.iter() {
-        let Ok(name_cstr) = U16CString::from_str(name) else {
-            warn!("Invalid library name: {}", name);
-            continue;
-        };
-        let res = unsafe { LoadLibraryW(PCWSTR(name_cstr.as_ptr())) };
-
-        if let Ok(handle) = res {
-            if !handle.is_invalid() {
-                let init_enum = {
-                    let v3_addr = utils::get_proc_address(handle, c"hachimi_init_v3");
-                    if v3_addr != 0 {
-                        Some(crate::core::plugin_api::PluginInit::V3(unsafe { std::mem::transmute(v3_addr) }))
-                    } else {
-                        let v2_addr = utils::get_proc_address(handle, c"hachimi_init");
-                        if v2_addr != 0 {
-                            Some(crate::core::plugin_api::PluginInit::V2(unsafe { std::mem::transmute(v2_addr) }))
-                        } else {
-                            None
-                        }
-                    }
-                };
-
-                if let Some(init_fn) = init_enum {
-                    info!("Loaded library: {}", name);
-                    plugins.push(Plugin {
-                        name: name.clone(),
-                        init_fn,
-                    });
-                } else {
-                    warn!("Library loaded but missing hachimi_init: {}", name);
-                }
-
-                continue;
+    Hachimi::instance().config.load().windows.load_libraries.iter()
+        .filter_map(|name| load_plugin(name))
+        .collect()
+}
+
+pub fn load_plugin(name: &str) -> Option<Plugin> {
+    let Ok(name_cstr) = U16CString::from_str(name) else {
+        warn!("Invalid library name: {}", name);
+        return None;
+    };
+    let handle = match unsafe { LoadLibraryW(PCWSTR(name_cstr.as_ptr())) } {
+        Ok(handle) if !handle.is_invalid() => handle,
+        _ => {
+            warn!("Failed to load library: {}", name);
+            return None;
+        }
+    };
+
+    let init_enum = {
+        let v3_addr = utils::get_proc_address(handle, c"hachimi_init_v3");
+        if v3_addr != 0 {
+            Some(crate::core::plugin_api::PluginInit::V3(unsafe { std::mem::transmute(v3_addr) }))
+        } else {
+            let v2_addr = utils::get_proc_address(handle, c"hachimi_init");
+            if v2_addr != 0 {
+                Some(crate::core::plugin_api::PluginInit::V2(unsafe { std::mem::transmute(v2_addr) }))
+            } else {
+                None
             }
         }
+    };
 
-        warn!("Failed to load library: {}", name);
+    let Some(init_fn) = init_enum else {
+        warn!("Library loaded but missing hachimi_init: {}", name);
+        return None;
+    };
+
+    let shutdown_addr = utils::get_proc_address(handle, c"hachimi_shutdown");
+    info!("Loaded library: {}", name);
+    Some(Plugin {
+        name: name.to_owned(),
+        init_fn,
+        shutdown_fn: if shutdown_addr != 0 { Some(unsafe { std::mem::transmute(shutdown_addr) }) } else { None },
+        handle: handle.0 as usize,
+        base: handle.0 as usize
+    })
+}
+
+pub fn unload_plugin(plugin: &Plugin) {
+    if let Err(e) = unsafe { FreeLibrary(HMODULE(plugin.handle as _)) } {
+        error!("Failed to unload library {}: {}", plugin.name, e);
     }
+}
 
-    plugins
+/// Returns the base address of the module containing `addr`, which on Windows is its HMODULE.
+pub fn module_base(addr: usize) -> Option<usize> {
+    let mut hmodule = HMODULE::default();
+    let res = unsafe {
+        GetModuleHandleExW(
+            GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS | GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT,
+            PCWSTR(addr as *const u16),
+            &mut hmodule
+        )
+    };
+    res.ok().map(|_| hmodule.0 as usize)
 }
 
 pub static mut DLL_HMODULE: HMODULE = HMODULE(0 as _);
@@ -20,7 +20,7 @@ use windows::{core::{w, HSTRING}, Win32::{
     }
 }};
 
-use crate::{core::{game::Region, gui, Gui, Hachimi}, il2cpp::{hook::{umamusume, UnityEngine_CoreModule}, symbols::{create_delegate, get_assembly_image, get_class, get_method_addr, Thread}, types::{Il2CppDelegate, RefreshRate}}, windows::utils};
+use crate::{core::{game::Region, gui, live_utils, screenshot, Gui, Hachimi}, il2cpp::{hook::{umamusume, UnityEngine_CoreModule}, symbols::{create_delegate, get_assembly_image, get_class, get_method_addr, Thread}, types::{Il2CppDelegate, RefreshRate}}, windows::utils};
 
 use super::{free_camera, gui_impl::input, discord, smtc, taskbar, webview};
 
@@ -300,6 +300,7 @@ unsafe extern "system" fn set_window_long_ptr_w_hook(
     index: WINDOW_LONG_PTR_INDEX,
     new_long: isize
 ) -> isize {
+    hook_scope!(set_window_long_ptr_w_hook);
     std::hint::black_box(SET_WINDOW_LONG_PTR_W_HOOK_ID.load(atomic::Ordering::Relaxed));
     let orig_fn = get_orig_fn!(set_window_long_ptr_w_hook, SetWindowLongPtrFn);
     let target_hwnd = get_target_hwnd();
@@ -328,6 +329,7 @@ unsafe extern "system" fn set_window_long_ptr_a_hook(
     index: WINDOW_LONG_PTR_INDEX,
     new_long: isize
 ) -> isize {
+    hook_scope!(set_window_long_ptr_a_hook);
     std::hint::black_box(SET_WINDOW_LONG_PTR_A_HOOK_ID.load(atomic::Ordering::Relaxed));
     let orig_fn = get_orig_fn!(set_window_long_ptr_a_hook, SetWindowLongPtrFn);
     let target_hwnd = get_target_hwnd();
@@ -474,6 +476,14 @@ extern "system" fn wnd_proc(hwnd: HWND, umsg: c_uint, wparam: WPARAM, lparam: LP
                 Thread::main_thread().schedule(Gui::toggle_game_ui);
             }
 
+            if !repeat && !Gui::is_gui_input_active_atomic() && live_utils::on_hotkey(current_key) {
+                return LRESULT(0);
+            }
+
+            if !repeat && !Gui::is_gui_input_active_atomic() && screenshot::on_hotkey(current_key) {
+                return LRESULT(0);
+            }
+
             if !Gui::is_gui_input_active_atomic() {
                 free_camera::on_windows_key(current_key, true, repeat);
                 if free_camera::is_windows_key_bound(current_key) {
@@ -0,0 +1,64 @@
+//! Offline checker for Hachimi config files. Hachimi writes the schemas to `<data dir>/schemas`
+//! on startup.
+//!
+//! Usage: `hachimi-config-check <schema.json> <file.json>...`
+//!
+//! Exits with 1 if any file has warnings and 2 if any file couldn't be read or parsed.
+
+use std::{fs, path::Path, process::ExitCode};
+
+use serde_json::Value;
+
+mod validate;
+
+fn read_json(path: &Path) -> Result<Value, String> {
+    let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&contents).map_err(|e| e.to_string())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.len() < 2 {
+        eprintln!("Usage: hachimi-config-check <schema.json> <file.json>...");
+        return ExitCode::from(2);
+    }
+
+    let schema_path = Path::new(&args[0]);
+    let schema = match read_json(schema_path) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("error: {}: {}", schema_path.display(), e);
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut has_warnings = false;
+    let mut has_errors = false;
+    for file in &args[1..] {
+        let path = Path::new(file);
+        let value = match read_json(path) {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("error: {}: {}", path.display(), e);
+                has_errors = true;
+                continue;
+            }
+        };
+
+        let warnings = validate::validate(&value, &schema);
+        for warning in &warnings {
+            println!("warning: {}: {}", path.display(), warning);
+        }
+        has_warnings |= !warnings.is_empty();
+    }
+
+    if has_errors {
+        ExitCode::from(2)
+    }
+    else if has_warnings {
+        ExitCode::from(1)
+    }
+    else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,410 @@
+// Minimal JSON Schema (draft 7) validator covering the subset of keywords emitted by schemars.
+// This file is also included by the main crate, so it must only depend on serde_json.
+
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+/// Largest edit distance at which an unknown field is assumed to be a typo of a known one.
+const MAX_SUGGESTION_DISTANCE: usize = 3;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaWarning {
+    /// Location of the offending value, e.g. `caption.font_size` or `months[2]`. Empty for the root.
+    pub path: String,
+    pub message: String
+}
+
+impl fmt::Display for SchemaWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        }
+        else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+/// Validates `value` against `schema` and returns everything that doesn't match. Objects that list
+/// their `properties` without an `additionalProperties` schema are treated as closed, so misspelled
+/// field names are reported along with the closest known name.
+pub fn validate(value: &Value, schema: &Value) -> Vec<SchemaWarning> {
+    let validator = Validator { root: schema };
+    let mut warnings = Vec::new();
+    validator.validate(value, schema, "", &mut warnings);
+    warnings
+}
+
+struct Validator<'a> {
+    root: &'a Value
+}
+
+impl<'a> Validator<'a> {
+    fn resolve_ref(&self, reference: &str) -> Option<&'a Value> {
+        let name = reference.strip_prefix("#/definitions/")
+            .map(|n| ("definitions", n))
+            .or_else(|| reference.strip_prefix("#/$defs/").map(|n| ("$defs", n)));
+        match name {
+            Some((section, name)) => self.root.get(section)?.get(name),
+            None if reference == "#" => Some(self.root),
+            None => None
+        }
+    }
+
+    fn validate(&self, value: &Value, schema: &'a Value, path: &str, out: &mut Vec<SchemaWarning>) {
+        let schema = match schema {
+            Value::Bool(true) => return,
+            Value::Bool(false) => {
+                out.push(warning(path, "value is not allowed here".to_owned()));
+                return;
+            }
+            Value::Object(v) => v,
+            _ => return
+        };
+
+        if let Some(reference) = schema.get("$ref").and_then(|v| v.as_str()) {
+            match self.resolve_ref(reference) {
+                Some(resolved) => self.validate(value, resolved, path, out),
+                None => out.push(warning(path, format!("unresolved schema reference {}", reference)))
+            }
+            return;
+        }
+
+        if let Some(Value::Array(schemas)) = schema.get("allOf") {
+            for sub_schema in schemas {
+                self.validate(value, sub_schema, path, out);
+            }
+        }
+
+        for key in ["anyOf", "oneOf"] {
+            if let Some(Value::Array(schemas)) = schema.get(key) {
+                self.validate_any_of(value, schemas, path, out);
+            }
+        }
+
+        if let Some(expected) = schema.get("type") {
+            if !matches_type(value, expected) {
+                out.push(warning(path, format!("expected {}, found {}", type_description(expected), type_name(value))));
+                return;
+            }
+        }
+
+        if let Some(Value::Array(values)) = schema.get("enum") {
+            if !values.contains(value) {
+                let options: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+                out.push(warning(path, format!("expected one of {}, found {}", options.join(", "), value)));
+            }
+        }
+
+        if let Some(expected) = schema.get("const") {
+            if expected != value {
+                out.push(warning(path, format!("expected {}, found {}", expected, value)));
+            }
+        }
+
+        match value {
+            Value::Number(n) => {
+                let n = n.as_f64().unwrap_or_default();
+                if let Some(min) = schema.get("minimum").and_then(|v| v.as_f64()) {
+                    if n < min {
+                        out.push(warning(path, format!("{} is less than the minimum of {}", n, min)));
+                    }
+                }
+                if let Some(max) = schema.get("maximum").and_then(|v| v.as_f64()) {
+                    if n > max {
+                        out.push(warning(path, format!("{} is greater than the maximum of {}", n, max)));
+                    }
+                }
+            }
+            Value::Array(items) => self.validate_array(items, schema, path, out),
+            Value::Object(map) => self.validate_object(map, schema, path, out),
+            _ => ()
+        }
+    }
+
+    fn validate_any_of(&self, value: &Value, schemas: &'a [Value], path: &str, out: &mut Vec<SchemaWarning>) {
+        let mut best: Option<Vec<SchemaWarning>> = None;
+        for sub_schema in schemas {
+            let mut warnings = Vec::new();
+            self.validate(value, sub_schema, path, &mut warnings);
+            if warnings.is_empty() {
+                return;
+            }
+            if best.as_ref().is_none_or(|b| warnings.len() < b.len()) {
+                best = Some(warnings);
+            }
+        }
+        // Report the branch that came closest to matching, it's most likely the intended one
+        out.extend(best.unwrap_or_default());
+    }
+
+    fn validate_array(&self, items: &[Value], schema: &'a Map<String, Value>, path: &str, out: &mut Vec<SchemaWarning>) {
+        if let Some(min) = schema.get("minItems").and_then(|v| v.as_u64()) {
+            if (items.len() as u64) < min {
+                out.push(warning(path, format!("expected at least {} items, found {}", min, items.len())));
+            }
+        }
+        if let Some(max) = schema.get("maxItems").and_then(|v| v.as_u64()) {
+            if items.len() as u64 > max {
+                out.push(warning(path, format!("expected at most {} items, found {}", max, items.len())));
+            }
+        }
+
+        match schema.get("items") {
+            Some(Value::Array(tuple)) => {
+                for (i, (item, item_schema)) in items.iter().zip(tuple).enumerate() {
+                    self.validate(item, item_schema, &index_path(path, i), out);
+                }
+            }
+            Some(item_schema) => {
+                for (i, item) in items.iter().enumerate() {
+                    self.validate(item, item_schema, &index_path(path, i), out);
+                }
+            }
+            None => ()
+        }
+    }
+
+    fn validate_object(&self, map: &Map<String, Value>, schema: &'a Map<String, Value>, path: &str, out: &mut Vec<SchemaWarning>) {
+        let properties = schema.get("properties").and_then(|v| v.as_object());
+
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for name in required.iter().filter_map(|v| v.as_str()) {
+                if !map.contains_key(name) {
+                    out.push(warning(path, format!("missing required field `{}`", name)));
+                }
+            }
+        }
+
+        let additional = schema.get("additionalProperties");
+        for (key, value) in map {
+            let key_path = key_path(path, key);
+            if let Some(property_schema) = properties.and_then(|p| p.get(key)) {
+                self.validate(value, property_schema, &key_path, out);
+                continue;
+            }
+
+            match additional {
+                Some(Value::Bool(true)) => (),
+                Some(Value::Bool(false)) => out.push(unknown_field(path, key, properties)),
+                Some(additional_schema) => self.validate(value, additional_schema, &key_path, out),
+                None => {
+                    // Editors use the root "$schema" key to locate the schema
+                    if path.is_empty() && key == "$schema" {
+                        continue;
+                    }
+                    if properties.is_some() {
+                        out.push(unknown_field(path, key, properties));
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn warning(path: &str, message: String) -> SchemaWarning {
+    SchemaWarning { path: path.to_owned(), message }
+}
+
+fn unknown_field(path: &str, key: &str, properties: Option<&Map<String, Value>>) -> SchemaWarning {
+    let suggestion = properties.and_then(|p| closest_name(key, p.keys().map(|k| k.as_str())));
+    let message = match suggestion {
+        Some(name) => format!("unknown field `{}`, did you mean `{}`?", key, name),
+        None => format!("unknown field `{}`", key)
+    };
+    warning(path, message)
+}
+
+fn key_path(path: &str, key: &str) -> String {
+    if path.is_empty() { key.to_owned() } else { format!("{}.{}", path, key) }
+}
+
+fn index_path(path: &str, index: usize) -> String {
+    format!("{}[{}]", path, index)
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object"
+    }
+}
+
+fn matches_type_name(value: &Value, name: &str) -> bool {
+    match (name, value) {
+        ("null", Value::Null) |
+        ("boolean", Value::Bool(_)) |
+        ("number", Value::Number(_)) |
+        ("string", Value::String(_)) |
+        ("array", Value::Array(_)) |
+        ("object", Value::Object(_)) => true,
+        ("integer", Value::Number(n)) => n.is_i64() || n.is_u64() || n.as_f64().is_some_and(|f| f.fract() == 0.0),
+        _ => false
+    }
+}
+
+fn matches_type(value: &Value, expected: &Value) -> bool {
+    match expected {
+        Value::String(name) => matches_type_name(value, name),
+        Value::Array(names) => names.iter().filter_map(|n| n.as_str()).any(|n| matches_type_name(value, n)),
+        _ => true
+    }
+}
+
+fn type_description(expected: &Value) -> String {
+    match expected {
+        Value::Array(names) => names.iter().filter_map(|n| n.as_str()).collect::<Vec<_>>().join(" or "),
+        Value::String(name) => name.clone(),
+        _ => expected.to_string()
+    }
+}
+
+fn closest_name<'s>(key: &str, names: impl Iterator<Item = &'s str>) -> Option<&'s str> {
+    names
+        .map(|name| (levenshtein(key, name), name))
+        .filter(|&(distance, name)| distance <= MAX_SUGGESTION_DISTANCE && distance < name.len())
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, name)| name)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0; b.len() + 1];
+    for (i, ca) in a.chars().enumerate() {
+        cur[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn messages(value: Value, schema: Value) -> Vec<String> {
+        validate(&value, &schema).iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn unknown_field_suggests_closest_name() {
+        let schema = json!({
+            "type": "object",
+            "properties": { "font_size": { "type": "integer" }, "outline_size": { "type": "integer" } },
+            "additionalProperties": false
+        });
+        assert_eq!(
+            messages(json!({ "font_sise": 12 }), schema.clone()),
+            ["unknown field `font_sise`, did you mean `font_size`?"]
+        );
+        assert_eq!(messages(json!({ "unrelated": 12 }), schema), ["unknown field `unrelated`"]);
+    }
+
+    #[test]
+    fn properties_without_additional_properties_are_closed() {
+        let schema = json!({ "type": "object", "properties": { "enabled": { "type": "boolean" } } });
+        assert_eq!(messages(json!({ "enabld": true }), schema), ["unknown field `enabld`, did you mean `enabled`?"]);
+    }
+
+    #[test]
+    fn type_mismatch() {
+        let schema = json!({
+            "type": "object",
+            "properties": { "caption": { "type": "object", "properties": { "font_size": { "type": "integer" } } } }
+        });
+        assert_eq!(
+            messages(json!({ "caption": { "font_size": "big" } }), schema.clone()),
+            ["caption.font_size: expected integer, found string"]
+        );
+        assert!(messages(json!({ "caption": { "font_size": 12.0 } }), schema).is_empty());
+        assert_eq!(
+            messages(json!([1, "two"]), json!({ "type": "array", "items": { "type": ["integer", "null"] } })),
+            ["[1]: expected integer or null, found string"]
+        );
+    }
+
+    #[test]
+    fn any_of_option() {
+        // How schemars describes Option<T> when T is a definition
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "mode": { "anyOf": [{ "$ref": "#/definitions/Mode" }, { "type": "null" }] }
+            },
+            "definitions": { "Mode": { "type": "string", "enum": ["Disabled", "Silent"] } }
+        });
+        assert!(messages(json!({ "mode": null }), schema.clone()).is_empty());
+        assert!(messages(json!({ "mode": "Silent" }), schema.clone()).is_empty());
+        assert_eq!(
+            messages(json!({ "mode": "Loud" }), schema),
+            [r#"mode: expected one of "Disabled", "Silent", found "Loud""#]
+        );
+    }
+
+    #[test]
+    fn any_of_reports_closest_branch() {
+        let schema = json!({
+            "anyOf": [
+                { "type": "object", "properties": { "a": { "type": "integer" }, "b": { "type": "integer" } } },
+                { "type": "string" }
+            ]
+        });
+        assert_eq!(messages(json!({ "a": 1, "b": "x" }), schema), ["b: expected integer, found string"]);
+    }
+
+    #[test]
+    fn ref_resolution() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "old": { "$ref": "#/definitions/Inner" },
+                "new": { "$ref": "#/$defs/Inner" },
+                "broken": { "$ref": "#/definitions/Missing" }
+            },
+            "definitions": { "Inner": { "type": "object", "properties": { "value": { "type": "boolean" } } } },
+            "$defs": { "Inner": { "type": "object", "properties": { "value": { "type": "boolean" } } } }
+        });
+        assert_eq!(
+            messages(json!({ "old": { "value": 1 }, "new": { "value": 1 }, "broken": 1 }), schema),
+            [
+                "broken: unresolved schema reference #/definitions/Missing",
+                "new.value: expected boolean, found integer",
+                "old.value: expected boolean, found integer"
+            ]
+        );
+    }
+
+    #[test]
+    fn minimum_and_maximum() {
+        let schema = json!({ "type": "number", "minimum": 0.5, "maximum": 4 });
+        assert!(messages(json!(0.5), schema.clone()).is_empty());
+        assert!(messages(json!(4), schema.clone()).is_empty());
+        assert_eq!(messages(json!(0.25), schema.clone()), ["0.25 is less than the minimum of 0.5"]);
+        assert_eq!(messages(json!(5), schema), ["5 is greater than the maximum of 4"]);
+    }
+
+    #[test]
+    fn root_schema_key_is_exempt() {
+        let schema = json!({
+            "type": "object",
+            "properties": { "inner": { "type": "object", "properties": {} } }
+        });
+        assert!(messages(json!({ "$schema": "./schemas/config.schema.json" }), schema.clone()).is_empty());
+        assert_eq!(
+            messages(json!({ "inner": { "$schema": "x" } }), schema),
+            ["inner: unknown field `$schema`"]
+        );
+    }
+}
@@ -0,0 +1,391 @@
+//! Local HTTP server for testing resumable downloads. Serves one file with Range, If-Range and
+//! ETag support, cutting off responses and failing requests on demand.
+//!
+//! Usage: `hachimi-flaky-server <file> [--port 8000] [--drop-rate 0.3] [--fail-after <bytes>] [--etag <etag>]`
+
+use std::{
+    fs,
+    io::{BufRead, BufReader, Write},
+    net::{Shutdown, TcpListener, TcpStream},
+    process::ExitCode,
+    sync::{atomic::{AtomicU64, Ordering}, Arc},
+    thread,
+    time::UNIX_EPOCH
+};
+
+struct Options {
+    port: u16,
+    drop_rate: f64,
+    fail_after: Option<u64>,
+    etag: String
+}
+
+struct Server {
+    data: Vec<u8>,
+    options: Options,
+    /// Body bytes sent so far, for `--fail-after`.
+    sent: AtomicU64,
+    /// Seed for deciding which responses get dropped.
+    rng: AtomicU64
+}
+
+struct Request {
+    method: String,
+    path: String,
+    range: Option<String>,
+    if_range: Option<String>
+}
+
+fn read_request(reader: &mut impl BufRead) -> Option<Request> {
+    let mut line = String::new();
+    if reader.read_line(&mut line).ok()? == 0 {
+        return None;
+    }
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_owned();
+    let path = parts.next()?.to_owned();
+
+    let mut request = Request { method, path, range: None, if_range: None };
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).ok()?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let Some((name, value)) = header.split_once(':') else {
+            continue;
+        };
+        match name.trim().to_ascii_lowercase().as_str() {
+            "range" => request.range = Some(value.trim().to_owned()),
+            "if-range" => request.if_range = Some(value.trim().to_owned()),
+            _ => ()
+        }
+    }
+    Some(request)
+}
+
+/// Parses a single `bytes=start-end` range, open ended and suffix ranges included.
+fn parse_range(range: &str, len: u64) -> Option<(u64, u64)> {
+    let (start, end) = range.strip_prefix("bytes=")?.split_once('-')?;
+    let (start, end) = match (start.trim(), end.trim()) {
+        ("", suffix) => {
+            let suffix: u64 = suffix.parse().ok()?;
+            (len.checked_sub(suffix)?, len - 1)
+        },
+        (start, "") => (start.parse().ok()?, len - 1),
+        (start, end) => (start.parse().ok()?, end.parse::<u64>().ok()?.min(len - 1))
+    };
+    (start <= end && end < len).then_some((start, end))
+}
+
+impl Server {
+    fn new(data: Vec<u8>, options: Options) -> Server {
+        Server { data, options, sent: AtomicU64::new(0), rng: AtomicU64::new(0x2545_f491_4f6c_dd1d) }
+    }
+
+    /// xorshift, good enough for picking which responses to drop.
+    fn next_random(&self) -> f64 {
+        let mut x = self.rng.load(Ordering::Relaxed);
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.rng.store(x, Ordering::Relaxed);
+        (x >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn handle(&self, mut stream: TcpStream) {
+        let peer = stream.peer_addr().map(|a| a.to_string()).unwrap_or_default();
+        let Ok(read_stream) = stream.try_clone() else {
+            return;
+        };
+        let mut reader = BufReader::new(read_stream);
+
+        while let Some(request) = read_request(&mut reader) {
+            if !self.respond(&mut stream, &peer, &request) {
+                _ = stream.shutdown(Shutdown::Both);
+                return;
+            }
+        }
+    }
+
+    /// Returns false if the connection was cut off.
+    fn respond(&self, stream: &mut TcpStream, peer: &str, request: &Request) -> bool {
+        let len = self.data.len() as u64;
+        let is_head = request.method == "HEAD";
+        if !is_head && request.method != "GET" {
+            println!("{} {} {} -> 405", peer, request.method, request.path);
+            return stream.write_all(b"HTTP/1.1 405 Method Not Allowed\r\nContent-Length: 0\r\n\r\n").is_ok();
+        }
+
+        if !is_head && self.options.fail_after.is_some_and(|limit| self.sent.load(Ordering::Relaxed) >= limit) {
+            println!("{} {} {} -> 503", peer, request.method, request.path);
+            return stream.write_all(b"HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\n\r\n").is_ok();
+        }
+
+        // If-Range only allows the range if the file hasn't changed
+        let range_allowed = request.if_range.as_ref().is_none_or(|v| *v == self.options.etag);
+        let range = request.range.as_deref().filter(|_| range_allowed).map(|r| parse_range(r, len));
+        let (status, start, end) = match range {
+            Some(Some((start, end))) => ("206 Partial Content", start, end),
+            Some(None) => {
+                println!("{} {} {} ({:?}) -> 416", peer, request.method, request.path, request.range);
+                let response = format!("HTTP/1.1 416 Range Not Satisfiable\r\nContent-Range: bytes */{}\r\nContent-Length: 0\r\n\r\n", len);
+                return stream.write_all(response.as_bytes()).is_ok();
+            },
+            None => ("200 OK", 0, len - 1)
+        };
+
+        let body_len = end - start + 1;
+        let mut head = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nAccept-Ranges: bytes\r\nETag: {}\r\nContent-Type: application/octet-stream\r\n",
+            status, body_len, self.options.etag
+        );
+        if start != 0 || end != len - 1 {
+            head.push_str(&format!("Content-Range: bytes {}-{}/{}\r\n", start, end, len));
+        }
+        head.push_str("\r\n");
+        if stream.write_all(head.as_bytes()).is_err() {
+            return false;
+        }
+        if is_head {
+            println!("{} HEAD {} -> {}", peer, request.path, status);
+            return true;
+        }
+
+        let mut to_send = body_len;
+        if body_len > 1 && self.next_random() < self.options.drop_rate {
+            to_send = body_len / 2;
+        }
+        if let Some(limit) = self.options.fail_after {
+            to_send = to_send.min(limit.saturating_sub(self.sent.load(Ordering::Relaxed)));
+        }
+
+        let body = &self.data[start as usize..(start + to_send) as usize];
+        let ok = stream.write_all(body).is_ok();
+        self.sent.fetch_add(to_send, Ordering::Relaxed);
+
+        let dropped = to_send < body_len;
+        println!(
+            "{} GET {} ({}) -> {}, sent {}/{} bytes{}",
+            peer, request.path, request.range.as_deref().unwrap_or("full"), status, to_send, body_len,
+            if dropped { ", dropped" } else { "" }
+        );
+        ok && !dropped
+    }
+}
+
+fn parse_args() -> Result<(String, Options), String> {
+    let mut args = std::env::args().skip(1);
+    let mut file = None;
+    let mut options = Options { port: 8000, drop_rate: 0.0, fail_after: None, etag: String::new() };
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or_else(|| format!("{} needs a value", name));
+        match arg.as_str() {
+            "--port" => options.port = value("--port")?.parse().map_err(|e| format!("--port: {}", e))?,
+            "--drop-rate" => options.drop_rate = value("--drop-rate")?.parse().map_err(|e| format!("--drop-rate: {}", e))?,
+            "--fail-after" => options.fail_after = Some(value("--fail-after")?.parse().map_err(|e| format!("--fail-after: {}", e))?),
+            "--etag" => options.etag = value("--etag")?,
+            _ if arg.starts_with("--") => return Err(format!("Unknown option: {}", arg)),
+            _ if file.is_none() => file = Some(arg),
+            _ => return Err(format!("Unexpected argument: {}", arg))
+        }
+    }
+
+    let file = file.ok_or("No file given")?;
+    if options.etag.is_empty() {
+        let metadata = fs::metadata(&file).map_err(|e| format!("{}: {}", file, e))?;
+        let modified = metadata.modified().ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |d| d.as_secs());
+        options.etag = format!("\"{:x}-{:x}\"", metadata.len(), modified);
+    }
+    else if !options.etag.starts_with('"') {
+        options.etag = format!("\"{}\"", options.etag);
+    }
+    Ok((file, options))
+}
+
+fn main() -> ExitCode {
+    let (file, options) = match parse_args() {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            eprintln!("Usage: hachimi-flaky-server <file> [--port 8000] [--drop-rate 0.3] [--fail-after <bytes>] [--etag <etag>]");
+            return ExitCode::from(2);
+        }
+    };
+
+    let data = match fs::read(&file) {
+        Ok(v) if !v.is_empty() => v,
+        Ok(_) => {
+            eprintln!("error: {} is empty", file);
+            return ExitCode::from(2);
+        },
+        Err(e) => {
+            eprintln!("error: {}: {}", file, e);
+            return ExitCode::from(2);
+        }
+    };
+
+    let listener = match TcpListener::bind(("127.0.0.1", options.port)) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("error: Failed to listen on port {}: {}", options.port, e);
+            return ExitCode::from(2);
+        }
+    };
+    println!(
+        "Serving {} ({} bytes, ETag {}) on http://127.0.0.1:{}/",
+        file, data.len(), options.etag, options.port
+    );
+
+    serve(listener, Arc::new(Server::new(data, options)));
+    ExitCode::SUCCESS
+}
+
+fn serve(listener: TcpListener, server: Arc<Server>) {
+    for stream in listener.incoming().flatten() {
+        let server = Arc::clone(&server);
+        thread::spawn(move || server.handle(stream));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{io::Read, net::SocketAddr};
+
+    const ETAG: &str = "\"test\"";
+
+    struct Response {
+        status: u16,
+        content_range: Option<String>,
+        body: Vec<u8>
+    }
+
+    fn test_data() -> Vec<u8> {
+        (0..100_000u32).map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8).collect()
+    }
+
+    fn start(data: Vec<u8>, drop_rate: f64, fail_after: Option<u64>) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let options = Options { port: addr.port(), drop_rate, fail_after, etag: ETAG.to_owned() };
+        let server = Arc::new(Server::new(data, options));
+        thread::spawn(move || serve(listener, server));
+        addr
+    }
+
+    /// Sends a GET and reads the body until Content-Length or until the server hangs up.
+    fn get(addr: SocketAddr, from: u64, if_range: &str) -> Response {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        let mut request = "GET /file HTTP/1.1\r\nHost: 127.0.0.1\r\n".to_owned();
+        if from != 0 {
+            request.push_str(&format!("Range: bytes={}-\r\nIf-Range: {}\r\n", from, if_range));
+        }
+        request.push_str("\r\n");
+        stream.write_all(request.as_bytes()).unwrap();
+
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        let status = line.split_whitespace().nth(1).unwrap().parse().unwrap();
+
+        let mut content_length = 0;
+        let mut content_range = None;
+        loop {
+            line.clear();
+            reader.read_line(&mut line).unwrap();
+            let header = line.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            let (name, value) = header.split_once(':').unwrap();
+            match name.to_ascii_lowercase().as_str() {
+                "content-length" => content_length = value.trim().parse().unwrap(),
+                "content-range" => content_range = Some(value.trim().to_owned()),
+                _ => ()
+            }
+        }
+
+        let mut body = Vec::new();
+        reader.take(content_length).read_to_end(&mut body).unwrap();
+        Response { status, content_range, body }
+    }
+
+    /// Downloads into `out`, resuming from its length after every cut off response. Returns the
+    /// number of requests made, or None if the server started failing requests.
+    fn download(addr: SocketAddr, out: &mut Vec<u8>, len: usize, if_range: &str) -> Option<usize> {
+        for requests in 1..=64 {
+            let response = get(addr, out.len() as u64, if_range);
+            match response.status {
+                200 => *out = response.body,
+                206 => {
+                    let expected = format!("bytes {}-{}/{}", out.len(), len - 1, len);
+                    assert_eq!(response.content_range.as_deref(), Some(expected.as_str()));
+                    out.extend_from_slice(&response.body);
+                },
+                503 => return None,
+                status => panic!("Unexpected status {}", status)
+            }
+            if out.len() == len {
+                return Some(requests);
+            }
+        }
+        panic!("Download didn't finish");
+    }
+
+    #[test]
+    fn parse_range_forms() {
+        assert_eq!(parse_range("bytes=0-99", 1000), Some((0, 99)));
+        assert_eq!(parse_range("bytes=900-", 1000), Some((900, 999)));
+        assert_eq!(parse_range("bytes=-100", 1000), Some((900, 999)));
+        assert_eq!(parse_range("bytes=900-5000", 1000), Some((900, 999)));
+        assert_eq!(parse_range("bytes=1000-", 1000), None);
+        assert_eq!(parse_range("bytes=5-4", 1000), None);
+        assert_eq!(parse_range("items=0-1", 1000), None);
+    }
+
+    #[test]
+    fn resume_after_dropped_responses() {
+        let data = test_data();
+        // Every response is cut off halfway, so each resume has to pick up where the last one stopped
+        let addr = start(data.clone(), 1.0, None);
+
+        let mut out = Vec::new();
+        let requests = download(addr, &mut out, data.len(), ETAG).unwrap();
+        assert!(requests > 1);
+        assert!(out == data, "Resumed download differs from the served file");
+    }
+
+    #[test]
+    fn resume_after_restart() {
+        let data = test_data();
+        let half = data.len() as u64 / 2;
+        let failing = start(data.clone(), 0.0, Some(half));
+
+        let mut out = Vec::new();
+        assert_eq!(download(failing, &mut out, data.len(), ETAG), None);
+        assert_eq!(out.len() as u64, half);
+
+        let healthy = start(data.clone(), 0.0, None);
+        assert_eq!(download(healthy, &mut out, data.len(), ETAG), Some(1));
+        assert!(out == data, "Resumed download differs from the served file");
+    }
+
+    #[test]
+    fn stale_if_range_restarts_download() {
+        let data = test_data();
+        let addr = start(data.clone(), 0.0, None);
+
+        let mut out = data[..1000].to_vec();
+        let response = get(addr, out.len() as u64, "\"stale\"");
+        assert_eq!(response.status, 200);
+        assert_eq!(response.content_range, None);
+
+        assert_eq!(download(addr, &mut out, data.len(), "\"stale\""), Some(1));
+        assert!(out == data);
+    }
+}